@@ -0,0 +1,252 @@
+use crate::{
+    assets::materials::load::collect_material_files_recursive,
+    entities::{read_scene_file, EntitySaveReadyData},
+    shared::rel_asset_to_absolute,
+    GraniteType, GraniteTypes, StandardMaterialDef,
+};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+// Internal materials are created and referenced by code (class defaults), so they are never "unused"
+const INTERNAL_MATERIAL_DIR: &str = "materials/internal/";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AssetDependencyKind {
+    Scene,
+    Mesh,
+    Material,
+    Texture,
+}
+
+/// A single file a scene depends on, along with who references it
+/// Paths are relative to /assets
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AssetDependency {
+    pub path: String,
+    pub kind: AssetDependencyKind,
+    pub exists: bool,
+    /// Scene paths or material paths that reference this file
+    pub referenced_by: BTreeSet<String>,
+}
+
+/// Dependency graph for one or more scenes
+/// Scene -> mesh/material, material -> texture
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct AssetDependencyReport {
+    pub scenes: Vec<String>,
+    pub dependencies: BTreeMap<String, AssetDependency>,
+    pub unused_materials: Vec<String>,
+}
+
+impl AssetDependencyReport {
+    pub fn missing(&self) -> impl Iterator<Item = &AssetDependency> {
+        self.dependencies.values().filter(|dep| !dep.exists)
+    }
+
+    pub fn missing_count(&self) -> usize {
+        self.missing().count()
+    }
+
+    pub fn of_kind(&self, kind: AssetDependencyKind) -> impl Iterator<Item = &AssetDependency> {
+        self.dependencies
+            .values()
+            .filter(move |dep| dep.kind == kind)
+    }
+
+    /// Every file needed to ship the scenes, including the scenes themselves
+    pub fn manifest(&self) -> AssetManifest {
+        AssetManifest {
+            scenes: self.scenes.clone(),
+            files: self
+                .dependencies
+                .values()
+                .filter(|dep| dep.exists)
+                .map(|dep| dep.path.clone())
+                .collect(),
+            missing: self.missing().map(|dep| dep.path.clone()).collect(),
+        }
+    }
+
+    /// Writes the manifest as RON. Path is absolute or relative to /assets
+    pub fn export_manifest(&self, path: &str) -> Result<(), String> {
+        let abs_path = rel_asset_to_absolute(path);
+        let pretty_config = PrettyConfig::new()
+            .compact_arrays(false)
+            .indentor("\t".to_string());
+        let contents = to_string_pretty(&self.manifest(), pretty_config)
+            .map_err(|e| format!("Failed to serialize asset manifest: {}", e))?;
+
+        if let Some(parent) = Path::new(abs_path.as_ref()).parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+        }
+        std::fs::write(abs_path.as_ref(), contents)
+            .map_err(|e| format!("Failed to write asset manifest {}: {}", abs_path, e))?;
+
+        log!(
+            LogType::Editor,
+            LogLevel::OK,
+            LogCategory::Asset,
+            "Exported asset manifest: {}",
+            abs_path
+        );
+        Ok(())
+    }
+
+    fn add(&mut self, path: &str, kind: AssetDependencyKind, referenced_by: &str) {
+        let path = normalize_asset_path(path);
+        if path.is_empty() {
+            return;
+        }
+        let dependency = self
+            .dependencies
+            .entry(path.clone())
+            .or_insert_with(|| AssetDependency {
+                exists: asset_exists(&path),
+                path,
+                kind,
+                referenced_by: BTreeSet::new(),
+            });
+        dependency.referenced_by.insert(referenced_by.to_string());
+    }
+}
+
+/// Packaging list produced from an AssetDependencyReport
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct AssetManifest {
+    pub scenes: Vec<String>,
+    pub files: Vec<String>,
+    pub missing: Vec<String>,
+}
+
+/// Walks every given scene (relative to /assets) and gathers what it depends on:
+/// OBJ mesh paths, material paths, and the texture paths inside those .mat files.
+/// Also lists any .mat files in materials/ that none of the scenes use
+pub fn build_asset_dependency_report(scene_paths: &[String]) -> AssetDependencyReport {
+    let mut report = AssetDependencyReport::default();
+    let mut scenes: Vec<String> = scene_paths
+        .iter()
+        .map(|path| normalize_asset_path(path))
+        .collect();
+    scenes.sort();
+    scenes.dedup();
+
+    for scene in &scenes {
+        report.add(scene, AssetDependencyKind::Scene, scene);
+        let abs_path = rel_asset_to_absolute(scene);
+        for entity in read_scene_file(&abs_path) {
+            collect_entity_dependencies(&entity, scene, &mut report);
+        }
+    }
+
+    // Textures live inside the .mat files, so resolve them once all materials are known
+    let material_paths: Vec<String> = report
+        .of_kind(AssetDependencyKind::Material)
+        .filter(|dep| dep.exists)
+        .map(|dep| dep.path.clone())
+        .collect();
+    for material_path in &material_paths {
+        for texture_path in material_texture_paths(material_path) {
+            report.add(&texture_path, AssetDependencyKind::Texture, material_path);
+        }
+    }
+
+    let mut all_materials = Vec::new();
+    collect_material_files_recursive("assets/materials", &mut all_materials);
+    all_materials.sort();
+    report.unused_materials = all_materials
+        .into_iter()
+        .filter(|path| {
+            !path.starts_with(INTERNAL_MATERIAL_DIR) && !report.dependencies.contains_key(path)
+        })
+        .collect();
+    report.scenes = scenes;
+
+    let missing_count = report.missing_count();
+    log!(
+        LogType::Editor,
+        if missing_count > 0 {
+            LogLevel::Warning
+        } else {
+            LogLevel::OK
+        },
+        LogCategory::Asset,
+        "Asset report for {} scene(s): {} dependencies, {} missing, {} unused materials",
+        report.scenes.len(),
+        report.dependencies.len(),
+        missing_count,
+        report.unused_materials.len()
+    );
+
+    report
+}
+
+fn collect_entity_dependencies(
+    entity: &EntitySaveReadyData,
+    scene: &str,
+    report: &mut AssetDependencyReport,
+) {
+    let class = &entity.identity.class;
+    if let GraniteTypes::OBJ(obj) = class {
+        report.add(&obj.mesh_path, AssetDependencyKind::Mesh, scene);
+    }
+    if let GraniteTypes::RectBrush(brush) = class {
+        for path in brush.face_material_paths() {
+            report.add(&path, AssetDependencyKind::Material, scene);
+        }
+    }
+    if let Some(material) = class.get_material_data() {
+        report.add(material.path, AssetDependencyKind::Material, scene);
+    }
+}
+
+/// Reads a .mat file from disk and returns every texture path it references
+pub fn material_texture_paths(material_path: &str) -> Vec<String> {
+    let abs_path = rel_asset_to_absolute(material_path);
+    let contents = match std::fs::read_to_string(abs_path.as_ref()) {
+        Ok(contents) => contents,
+        Err(e) => {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Asset,
+                "Failed to read material file {}: {}",
+                abs_path,
+                e
+            );
+            return vec![];
+        }
+    };
+    let def: StandardMaterialDef = match ron::from_str(&contents) {
+        Ok(def) => def,
+        Err(e) => {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Asset,
+                "Failed to parse material definition from {}: {}",
+                abs_path,
+                e
+            );
+            return vec![];
+        }
+    };
+
+    def.texture_paths()
+}
+
+fn asset_exists(rel_path: &str) -> bool {
+    Path::new(rel_asset_to_absolute(rel_path).as_ref()).is_file()
+}
+
+fn normalize_asset_path(path: &str) -> String {
+    path.trim().replace('\\', "/")
+}
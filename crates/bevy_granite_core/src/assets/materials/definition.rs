@@ -873,6 +873,20 @@ impl EditableMaterial {
         self.path = path.clone();
     }
 
+    /// Point this material and its textures at a renamed/moved asset
+    /// Returns true if anything changed
+    pub fn rename_asset_references(&mut self, from: &str, to: &str) -> bool {
+        let mut changed = false;
+        if self.path == from {
+            self.path = to.to_string();
+            changed = true;
+        }
+        if let Some(def) = &mut self.def {
+            changed |= def.rename_asset_references(from, to);
+        }
+        changed
+    }
+
     /// Delete this material from disk and remove from available materials list
     pub fn delete_from_disk_and_memory(
         &self,
@@ -1061,3 +1075,45 @@ impl Default for StandardMaterialDef {
         }
     }
 }
+
+impl StandardMaterialDef {
+    fn texture_fields_mut(&mut self) -> [&mut Option<String>; 6] {
+        [
+            &mut self.base_color_texture,
+            &mut self.roughness_texture,
+            &mut self.metalness_texture,
+            &mut self.emissive_texture,
+            &mut self.normal_map_texture,
+            &mut self.occlusion_map,
+        ]
+    }
+
+    /// All non empty texture paths this definition references
+    pub fn texture_paths(&self) -> Vec<String> {
+        [
+            &self.base_color_texture,
+            &self.roughness_texture,
+            &self.metalness_texture,
+            &self.emissive_texture,
+            &self.normal_map_texture,
+            &self.occlusion_map,
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|path| !path.is_empty())
+        .cloned()
+        .collect()
+    }
+
+    /// Swap any texture path matching `from` with `to`. Returns true if anything changed
+    pub fn rename_asset_references(&mut self, from: &str, to: &str) -> bool {
+        let mut changed = false;
+        for texture in self.texture_fields_mut().into_iter().flatten() {
+            if *texture == from {
+                *texture = to.to_string();
+                changed = true;
+            }
+        }
+        changed
+    }
+}
@@ -351,7 +351,13 @@ pub fn materials_from_folder_into_scene(
 }
 
 /// Recursively collects all material .mat files in the given directory and its subdirectories
-fn collect_material_files_recursive(current_dir: &str, ron_files: &mut Vec<String>) {
+pub(crate) fn collect_material_files_recursive(current_dir: &str, ron_files: &mut Vec<String>) {
+    collect_files_recursive(current_dir, "mat", ron_files);
+}
+
+/// Recursively collects all files with the given extension in the directory and its subdirectories
+/// Pushed paths are relative to assets/
+pub(crate) fn collect_files_recursive(current_dir: &str, extension: &str, files: &mut Vec<String>) {
     if !std::path::Path::new(current_dir).exists() {
         log!(
             LogType::Editor,
@@ -397,13 +403,13 @@ fn collect_material_files_recursive(current_dir: &str, ron_files: &mut Vec<Strin
 
         if path.is_dir() {
             // Recursively process subdirectory
-            collect_material_files_recursive(&path.to_string_lossy(), ron_files);
-        } else if path.is_file() && path.extension().is_some_and(|ext| ext == "mat") {
+            collect_files_recursive(&path.to_string_lossy(), extension, files);
+        } else if path.is_file() && path.extension().is_some_and(|ext| ext == extension) {
             // Get the path relative to assets/
             let path_str = path.to_string_lossy();
             if let Some(assets_pos) = path_str.find("assets/") {
                 let relative_path = &path_str[assets_pos + 7..]; // Skip "assets/"
-                ron_files.push(relative_path.replace('\\', "/")); // Normalize slashes
+                files.push(relative_path.replace('\\', "/")); // Normalize slashes
             }
         }
     }
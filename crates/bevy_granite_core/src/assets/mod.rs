@@ -1,6 +1,12 @@
+pub mod dependencies;
 pub mod materials;
 pub mod plugin;
+pub mod rename;
 
+pub use dependencies::{
+    build_asset_dependency_report, material_texture_paths, AssetDependency, AssetDependencyKind,
+    AssetDependencyReport, AssetManifest,
+};
 pub use materials::{
     get_material_from_path, load_texture_with_repeat, material_from_path_into_scene,
     materials_from_folder_into_scene, AvailableEditableMaterials, EditableMaterial,
@@ -8,3 +14,7 @@ pub use materials::{
     NewEditableMaterial, RequiredMaterialData, RequiredMaterialDataMut, StandardMaterialDef,
 };
 pub use plugin::AssetPlugin;
+pub use rename::{
+    apply_asset_rename, asset_rename_system, plan_asset_rename, rename_asset, AssetRenameEdit,
+    AssetRenamePlan,
+};
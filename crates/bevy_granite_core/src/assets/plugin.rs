@@ -1,7 +1,7 @@
-use super::AvailableEditableMaterials;
+use super::{asset_rename_system, AvailableEditableMaterials};
 use crate::EditableMaterial;
 use bevy::{
-    app::{App, Plugin, PreStartup},
+    app::{App, Plugin, PreStartup, Update},
     asset::{AssetServer, Assets, Handle},
     ecs::system::{Res, ResMut},
     pbr::StandardMaterial,
@@ -50,6 +50,7 @@ impl Plugin for AssetPlugin {
             //
             // Schedule system
             //
-            .add_systems(PreStartup, preload_fallback_material);
+            .add_systems(PreStartup, preload_fallback_material)
+            .add_systems(Update, asset_rename_system);
     }
 }
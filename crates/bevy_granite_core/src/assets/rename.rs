@@ -0,0 +1,284 @@
+use crate::{
+    absolute_asset_to_rel,
+    assets::materials::load::collect_files_recursive,
+    events::{AssetRenameSuccessEvent, RequestAssetRenameEvent},
+    shared::rel_asset_to_absolute,
+    AvailableEditableMaterials, GraniteType, GraniteTypes, IdentityData, SpawnSource,
+};
+use bevy::prelude::{EventReader, EventWriter, Query, ResMut};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use std::path::Path;
+
+/// Serialized fields that hold an asset path in .scene and .mat files
+const ASSET_PATH_FIELDS: [&str; 8] = [
+    "path",
+    "mesh_path",
+    "base_color_texture",
+    "roughness_texture",
+    "metalness_texture",
+    "emissive_texture",
+    "normal_map_texture",
+    "occlusion_map",
+];
+
+/// A file that references the renamed asset, and how many times
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetRenameEdit {
+    pub path: String,
+    pub occurrences: usize,
+}
+
+/// Everything that will change when an asset is renamed/moved
+/// Paths are relative to /assets
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AssetRenamePlan {
+    pub from: String,
+    pub to: String,
+    pub edits: Vec<AssetRenameEdit>,
+}
+
+impl AssetRenamePlan {
+    pub fn total_references(&self) -> usize {
+        self.edits.iter().map(|edit| edit.occurrences).sum()
+    }
+}
+
+/// Dry run of a rename. Validates the paths and lists every .scene and .mat that would be rewritten
+/// Nothing on disk is touched
+pub fn plan_asset_rename(from: &str, to: &str) -> Result<AssetRenamePlan, String> {
+    let from = to_asset_rel(from)?;
+    let to = to_asset_rel(to)?;
+
+    if from == to {
+        return Err(format!("Source and destination are the same: {}", from));
+    }
+    if !Path::new(rel_asset_to_absolute(&from).as_ref()).is_file() {
+        return Err(format!("Asset does not exist: {}", from));
+    }
+    if Path::new(rel_asset_to_absolute(&to).as_ref()).exists() {
+        return Err(format!("Destination already exists: {}", to));
+    }
+
+    let mut files = Vec::new();
+    collect_files_recursive("assets", "scene", &mut files);
+    collect_files_recursive("assets", "mat", &mut files);
+    files.sort();
+
+    let edits = files
+        .into_iter()
+        .filter_map(|path| {
+            let contents = std::fs::read_to_string(rel_asset_to_absolute(&path).as_ref()).ok()?;
+            let occurrences = path_field_offsets(&contents, &from).len();
+            (occurrences > 0).then_some(AssetRenameEdit { path, occurrences })
+        })
+        .collect();
+
+    Ok(AssetRenamePlan { from, to, edits })
+}
+
+/// Moves the asset on disk then rewrites every file listed in the plan
+/// Only errors if the move itself fails. Files that could not be rewritten are returned
+pub fn apply_asset_rename(plan: &AssetRenamePlan) -> Result<Vec<String>, String> {
+    let abs_from = rel_asset_to_absolute(&plan.from);
+    let abs_to = rel_asset_to_absolute(&plan.to);
+
+    if let Some(parent) = Path::new(abs_to.as_ref()).parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+    std::fs::rename(abs_from.as_ref(), abs_to.as_ref())
+        .map_err(|e| format!("Failed to move {} to {}: {}", plan.from, plan.to, e))?;
+
+    let mut failed = Vec::new();
+    for edit in &plan.edits {
+        // The moved file could reference itself, it now lives at the new path
+        let path = if edit.path == plan.from {
+            &plan.to
+        } else {
+            &edit.path
+        };
+        let abs_path = rel_asset_to_absolute(path);
+        let result = std::fs::read_to_string(abs_path.as_ref()).and_then(|contents| {
+            std::fs::write(
+                abs_path.as_ref(),
+                rewrite_path_fields(&contents, &plan.from, &plan.to),
+            )
+        });
+        if let Err(e) = result {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Asset,
+                "Failed to rewrite references in {}: {}",
+                path,
+                e
+            );
+            failed.push(path.clone());
+        }
+    }
+
+    if !failed.is_empty() {
+        log!(
+            LogType::Editor,
+            LogLevel::Warning,
+            LogCategory::Asset,
+            "Moved {} -> {} but failed to update: {}",
+            plan.from,
+            plan.to,
+            failed.join(", ")
+        );
+        return Ok(failed);
+    }
+
+    log!(
+        LogType::Editor,
+        LogLevel::OK,
+        LogCategory::Asset,
+        "Renamed {} -> {} ({} references in {} files)",
+        plan.from,
+        plan.to,
+        plan.total_references(),
+        plan.edits.len()
+    );
+    Ok(failed)
+}
+
+/// Plan and apply in one go. Returns the plan and any files that could not be rewritten
+pub fn rename_asset(from: &str, to: &str) -> Result<(AssetRenamePlan, Vec<String>), String> {
+    let plan = plan_asset_rename(from, to)?;
+    let failed = apply_asset_rename(&plan)?;
+    Ok((plan, failed))
+}
+
+/// Watches for RequestAssetRenameEvent, renames on disk and keeps everything in memory pointing at the new path
+/// so the next save does not write the old path back. This still happens when some files failed to rewrite,
+/// as the asset has already moved
+pub fn asset_rename_system(
+    mut rename_reader: EventReader<RequestAssetRenameEvent>,
+    mut rename_success_writer: EventWriter<AssetRenameSuccessEvent>,
+    mut available_materials: ResMut<AvailableEditableMaterials>,
+    mut identity_query: Query<&mut IdentityData>,
+    mut source_query: Query<&mut SpawnSource>,
+) {
+    for RequestAssetRenameEvent(from, to) in rename_reader.read() {
+        let plan = match rename_asset(from, to) {
+            Ok((plan, _)) => plan,
+            Err(e) => {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Error,
+                    LogCategory::Asset,
+                    "Asset rename failed: {}",
+                    e
+                );
+                continue;
+            }
+        };
+
+        if let Some(materials) = &mut available_materials.materials {
+            for material in materials.iter_mut() {
+                material.rename_asset_references(&plan.from, &plan.to);
+            }
+        }
+        for texture_path in available_materials.image_paths.values_mut() {
+            if *texture_path == plan.from {
+                *texture_path = plan.to.clone();
+            }
+        }
+
+        for mut identity in identity_query.iter_mut() {
+            let mut class = identity.class.clone();
+            if rename_class_asset_references(&mut class, &plan.from, &plan.to) {
+                identity.class = class;
+            }
+        }
+
+        for mut source in source_query.iter_mut() {
+            if source.str_ref() == plan.from {
+                let save_settings = source.save_settings_ref().clone();
+                *source = SpawnSource::new(plan.to.clone(), save_settings);
+            }
+        }
+
+        rename_success_writer.write(AssetRenameSuccessEvent(plan));
+    }
+}
+
+fn rename_class_asset_references(class: &mut GraniteTypes, from: &str, to: &str) -> bool {
+    let mut changed = false;
+    if let GraniteTypes::OBJ(obj) = class {
+        if obj.mesh_path == from {
+            obj.mesh_path = to.to_string().into();
+            changed = true;
+        }
+    }
+    if let GraniteTypes::RectBrush(brush) = class {
+        for face in brush.faces.iter_mut() {
+            if face.material_path == from {
+                face.material_path = to.to_string();
+                changed = true;
+            }
+        }
+    }
+    if let Some(material) = class.get_mut_material_data() {
+        if *material.path == from {
+            *material.path = to.to_string();
+            changed = true;
+        }
+        changed |= material.current.rename_asset_references(from, to);
+        changed |= material.last.rename_asset_references(from, to);
+    }
+    changed
+}
+
+fn to_asset_rel(path: &str) -> Result<String, String> {
+    let rel = absolute_asset_to_rel(path.trim().replace('\\', "/")).to_string();
+    if rel.is_empty() || Path::new(&rel).is_absolute() || rel.split('/').any(|part| part == "..") {
+        return Err(format!("Path must be inside assets/: {}", path));
+    }
+    Ok(rel)
+}
+
+fn quoted(path: &str) -> String {
+    format!("\"{}\"", path)
+}
+
+/// Byte offsets of every quoted `path` that is the value of one of ASSET_PATH_FIELDS
+/// Matches `field: "path"` and `field: Some("path")`
+fn path_field_offsets(contents: &str, path: &str) -> Vec<usize> {
+    contents
+        .match_indices(quoted(path).as_str())
+        .map(|(offset, _)| offset)
+        .filter(|&offset| {
+            let line = &contents[..offset];
+            let line = &line[line.rfind('\n').map_or(0, |i| i + 1)..];
+            let line = line.trim_end();
+            let line = line.strip_suffix("Some(").unwrap_or(line).trim_end();
+            line.strip_suffix(':').is_some_and(|key| {
+                let field = key
+                    .trim_end()
+                    .rsplit(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .next()
+                    .unwrap_or_default();
+                ASSET_PATH_FIELDS.contains(&field)
+            })
+        })
+        .collect()
+}
+
+fn rewrite_path_fields(contents: &str, from: &str, to: &str) -> String {
+    let needle_len = quoted(from).len();
+    let replacement = quoted(to);
+    let mut rewritten = String::with_capacity(contents.len());
+    let mut last = 0;
+    for offset in path_field_offsets(contents, from) {
+        rewritten.push_str(&contents[last..offset]);
+        rewritten.push_str(&replacement);
+        last = offset + needle_len;
+    }
+    rewritten.push_str(&contents[last..]);
+    rewritten
+}
@@ -0,0 +1,78 @@
+use super::CsgOperation;
+use crate::entities::editable::types::convex_brush::{
+    hull_from_planes, planes_from_points, BrushPlane,
+};
+use bevy::math::Vec3;
+
+/// Combine a target solid with its operands. Everything is in the target's brush space
+/// Each operand is given as the points of a convex solid. The result is a list of convex pieces,
+/// as vertices and faces, that together make up the baked shape
+/// Unions are applied first, then intersections, then subtractions
+pub fn bake_csg(
+    target: &[Vec3],
+    operands: &[(CsgOperation, Vec<Vec3>)],
+) -> Vec<(Vec<Vec3>, Vec<Vec<u32>>)> {
+    let mut pieces: Vec<Vec<BrushPlane>> =
+        tighten(&planes_from_points(target)).into_iter().collect();
+
+    for operation in CsgOperation::all() {
+        for (_, points) in operands.iter().filter(|(other, _)| *other == operation) {
+            let Some(operand) = tighten(&planes_from_points(points)) else {
+                continue;
+            };
+            pieces = match operation {
+                CsgOperation::Union => {
+                    // Only add the parts of the operand that are not already covered
+                    let mut outside = vec![operand];
+                    for piece in &pieces {
+                        outside = outside
+                            .iter()
+                            .flat_map(|part| subtract_solid(part, piece))
+                            .collect();
+                    }
+                    pieces.extend(outside);
+                    pieces
+                }
+                CsgOperation::Subtract => pieces
+                    .iter()
+                    .flat_map(|piece| subtract_solid(piece, &operand))
+                    .collect(),
+                CsgOperation::Intersect => pieces
+                    .iter()
+                    .filter_map(|piece| tighten(&[piece.as_slice(), operand.as_slice()].concat()))
+                    .collect(),
+            };
+        }
+    }
+
+    pieces
+        .iter()
+        .filter_map(|piece| hull_from_planes(piece))
+        .collect()
+}
+
+/// Split the part of solid outside the cutter into convex pieces
+/// Every cutter plane peels off the slab in front of it, what is left behind all of them is inside the cutter
+fn subtract_solid(solid: &[BrushPlane], cutter: &[BrushPlane]) -> Vec<Vec<BrushPlane>> {
+    if tighten(&[solid, cutter].concat()).is_none() {
+        return vec![solid.to_vec()];
+    }
+
+    let mut pieces = Vec::new();
+    let mut remaining = solid.to_vec();
+    for plane in cutter {
+        let mut outside = remaining.clone();
+        outside.push(plane.flipped());
+        pieces.extend(tighten(&outside));
+        remaining.push(*plane);
+    }
+    pieces
+}
+
+/// Drop planes that do not touch the solid, None if the planes enclose no volume
+/// Keeps plane counts from growing with every operation
+fn tighten(planes: &[BrushPlane]) -> Option<Vec<BrushPlane>> {
+    let (vertices, _) = hull_from_planes(planes)?;
+    let planes = planes_from_points(&vertices);
+    (planes.len() >= 4).then_some(planes)
+}
@@ -0,0 +1,101 @@
+use crate::{entities::editable::types::convex_brush::hull_from_points, GraniteTypes, RectBrush};
+use bevy::{
+    ecs::component::Component,
+    math::{Vec2, Vec3},
+    prelude::Reflect,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod bake;
+pub mod system;
+pub mod ui;
+
+pub use bake::*;
+pub use system::*;
+pub use ui::*;
+
+/// Boolean operation a brush applies to its parent brush
+/// The operand stays in the scene file, the parent mesh is baked again whenever either of them changes
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsgOperation {
+    Union,
+    Subtract,
+    Intersect,
+}
+
+impl CsgOperation {
+    /// Also the order operations are applied in, so cutters always cut the merged result
+    pub fn all() -> [CsgOperation; 3] {
+        [
+            CsgOperation::Union,
+            CsgOperation::Intersect,
+            CsgOperation::Subtract,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CsgOperation::Union => "Union",
+            CsgOperation::Subtract => "Subtract",
+            CsgOperation::Intersect => "Intersect",
+        }
+    }
+
+    /// Short marker for the node tree
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CsgOperation::Union => "+",
+            CsgOperation::Subtract => "-",
+            CsgOperation::Intersect => "&",
+        }
+    }
+}
+
+/// Brush whose mesh is the baked result of its CSG operand children
+/// Not saved, the bake is redone from the operands on load
+#[derive(Component)]
+pub struct CsgBaked;
+
+/// Brush that is hidden because it is an operand of its parent brush
+/// Not saved, the editor draws these as wireframes
+#[derive(Component)]
+pub struct CsgOperand {
+    pub operation: CsgOperation,
+}
+
+/// The CSG operation set on a brush class, None for classes that are not brushes
+pub fn brush_csg_operation(class: &GraniteTypes) -> Option<CsgOperation> {
+    match class {
+        GraniteTypes::RectBrush(brush) => brush.csg,
+        GraniteTypes::ConvexBrush(brush) => brush.csg,
+        _ => None,
+    }
+}
+
+/// Brush space convex solid of a brush class, None for classes that can not take part in CSG
+pub fn brush_csg_solid(class: &GraniteTypes) -> Option<(Vec<Vec3>, Vec<Vec<u32>>)> {
+    match class {
+        GraniteTypes::RectBrush(brush) => {
+            let corners: Vec<Vec3> =
+                RectBrush::get_vertices(brush.size.x, brush.size.y, brush.size.z)
+                    .into_iter()
+                    .take(8)
+                    .map(Vec3::from)
+                    .collect();
+            hull_from_points(&corners)
+        }
+        GraniteTypes::ConvexBrush(brush) if brush.is_valid() => {
+            Some((brush.vertices.clone(), brush.faces.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// UV scale used for the baked mesh of a brush
+pub fn brush_csg_uv_scale(class: &GraniteTypes) -> Vec2 {
+    match class {
+        GraniteTypes::RectBrush(brush) => brush.uv_scale,
+        GraniteTypes::ConvexBrush(brush) => brush.uv_scale,
+        _ => Vec2::ONE,
+    }
+}
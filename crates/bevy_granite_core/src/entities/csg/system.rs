@@ -0,0 +1,215 @@
+use super::{
+    bake_csg, brush_csg_operation, brush_csg_solid, brush_csg_uv_scale, CsgBaked, CsgOperand,
+};
+use crate::{
+    entities::{
+        editable::types::convex_brush::mesh_from_solids, RectBrushFaceMesh,
+        UserUpdatedConvexBrushEvent, UserUpdatedRectBrushEvent,
+    },
+    GraniteTypes, IdentityData, NeedsTangents,
+};
+use bevy::{
+    asset::Assets,
+    ecs::{
+        entity::Entity,
+        event::EventReader,
+        hierarchy::{ChildOf, Children},
+        query::{Changed, Has, Or, With},
+        removal_detection::RemovedComponents,
+        resource::Resource,
+        system::{Commands, Query, ResMut, SystemParam},
+    },
+    math::Vec3,
+    render::{
+        mesh::{Mesh, Mesh3d, MeshAabb},
+        primitives::Aabb,
+        view::Visibility,
+    },
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+use std::collections::{HashMap, HashSet};
+
+/// Which brush each operand was last baked into, so moving or deleting an operand also rebakes its old parent
+#[derive(Resource, Default)]
+pub struct CsgBakeState {
+    operand_parents: HashMap<Entity, Entity>,
+}
+
+type ChangedEntityQuery<'w, 's> = Query<
+    'w,
+    's,
+    Entity,
+    (
+        With<IdentityData>,
+        Or<(Changed<IdentityData>, Changed<ChildOf>)>,
+    ),
+>;
+type CsgBrushQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static IdentityData,
+        &'static Transform,
+        Option<&'static ChildOf>,
+        Option<&'static Children>,
+        Has<CsgBaked>,
+        Has<CsgOperand>,
+    ),
+>;
+type CsgTargetQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Mesh3d,
+        Option<&'static GlobalTransform>,
+        Option<&'static mut Aabb>,
+    ),
+>;
+
+/// Everything that can make a brush need a rebake
+#[derive(SystemParam)]
+pub struct CsgChanges<'w, 's> {
+    rect_events: EventReader<'w, 's, UserUpdatedRectBrushEvent>,
+    convex_events: EventReader<'w, 's, UserUpdatedConvexBrushEvent>,
+    changed_query: ChangedEntityQuery<'w, 's>,
+    moved_operands: Query<'w, 's, Entity, (With<CsgOperand>, Changed<Transform>)>,
+    removed_identities: RemovedComponents<'w, 's, IdentityData>,
+    removed_parents: RemovedComponents<'w, 's, ChildOf>,
+}
+
+impl CsgChanges<'_, '_> {
+    /// Every entity touched since the last run
+    fn touched(&mut self) -> HashSet<Entity> {
+        let mut touched: HashSet<Entity> = HashSet::new();
+        touched.extend(self.rect_events.read().map(|event| event.entity));
+        touched.extend(self.convex_events.read().map(|event| event.entity));
+        touched.extend(self.changed_query.iter());
+        touched.extend(self.moved_operands.iter());
+        touched.extend(self.removed_identities.read());
+        touched.extend(self.removed_parents.read());
+        touched
+    }
+}
+
+/// Bakes brushes with CSG operand children into a single mesh
+/// Runs after the brush update systems, so a class edit rebuilds the plain mesh first and the bake replaces it
+/// Spawning a brush counts as a change, so scenes are baked on load
+pub fn bake_csg_system(
+    mut commands: Commands,
+    mut state: ResMut<CsgBakeState>,
+    mut changes: CsgChanges,
+    brush_query: CsgBrushQuery,
+    mut target_query: CsgTargetQuery,
+    face_query: Query<(), With<RectBrushFaceMesh>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    let touched = changes.touched();
+    if touched.is_empty() {
+        return;
+    }
+
+    // Operands bake into their parent, and whatever they were attached to before
+    let mut targets: HashSet<Entity> = HashSet::new();
+    for entity in touched {
+        targets.insert(entity);
+        if let Some(old_parent) = state.operand_parents.remove(&entity) {
+            targets.insert(old_parent);
+        }
+        let Ok((identity, _, child_of, _, _, is_operand)) = brush_query.get(entity) else {
+            continue;
+        };
+
+        let parent = child_of.map(|child_of| child_of.parent()).filter(|parent| {
+            brush_query.get(*parent).is_ok_and(|(parent_identity, ..)| {
+                brush_csg_solid(&parent_identity.class).is_some()
+            })
+        });
+        match (brush_csg_operation(&identity.class), parent) {
+            (Some(operation), Some(parent)) => {
+                targets.insert(parent);
+                state.operand_parents.insert(entity, parent);
+                commands
+                    .entity(entity)
+                    .insert((CsgOperand { operation }, Visibility::Hidden));
+            }
+            _ if is_operand => {
+                commands
+                    .entity(entity)
+                    .remove::<CsgOperand>()
+                    .insert(Visibility::Inherited);
+            }
+            _ => {}
+        }
+    }
+
+    for target in targets {
+        let Ok((identity, _, _, children, is_baked, _)) = brush_query.get(target) else {
+            continue;
+        };
+        let Some((target_vertices, _)) = brush_csg_solid(&identity.class) else {
+            continue;
+        };
+
+        let operands: Vec<_> = children
+            .iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| {
+                let (child_identity, child_transform, ..) = brush_query.get(*child).ok()?;
+                let operation = brush_csg_operation(&child_identity.class)?;
+                let (vertices, _) = brush_csg_solid(&child_identity.class)?;
+                let points: Vec<Vec3> = vertices
+                    .iter()
+                    .map(|vertex| child_transform.transform_point(*vertex))
+                    .collect();
+                Some((operation, points))
+            })
+            .collect();
+        if operands.is_empty() && !is_baked {
+            continue;
+        }
+
+        let Ok((mesh_handle, global_transform, aabb)) = target_query.get_mut(target) else {
+            continue;
+        };
+        let Some(mesh) = meshes.get_mut(mesh_handle) else {
+            continue;
+        };
+
+        // Face override meshes can not follow the cut, the baked mesh uses the brush material everywhere
+        let face_visibility = if operands.is_empty() {
+            *mesh = match &identity.class {
+                GraniteTypes::RectBrush(brush) => brush.build_face_mesh("", global_transform),
+                GraniteTypes::ConvexBrush(brush) => brush.create_mesh(),
+                _ => continue,
+            };
+            commands.entity(target).remove::<CsgBaked>();
+            Visibility::Inherited
+        } else {
+            let pieces = bake_csg(&target_vertices, &operands);
+            *mesh = mesh_from_solids(&pieces, brush_csg_uv_scale(&identity.class));
+            commands.entity(target).insert(CsgBaked);
+            Visibility::Hidden
+        };
+        for child in children.iter().flat_map(|children| children.iter()) {
+            if face_query.get(*child).is_ok() {
+                commands.entity(*child).insert(face_visibility);
+            }
+        }
+
+        if let (Some(new_mesh_aabb), Some(mut entity_aabb)) = (mesh.compute_aabb(), aabb) {
+            *entity_aabb = new_mesh_aabb;
+        }
+        // Replacing the mesh drops any generated tangents
+        commands.entity(target).insert(NeedsTangents);
+
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Baked CSG for brush {} with {} operand(s)",
+            target,
+            operands.len()
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use super::CsgOperation;
+use bevy_egui::egui;
+
+/// CSG row for brush class UIs. Returns true when the operation was changed
+pub fn edit_csg_operation(
+    ui: &mut egui::Ui,
+    operation: &mut Option<CsgOperation>,
+    id_salt: &str,
+) -> bool {
+    let mut changed = false;
+    let selected_text = operation.map_or("None", |operation| operation.name());
+    egui::ComboBox::from_id_salt(id_salt)
+        .selected_text(selected_text)
+        .show_ui(ui, |ui| {
+            changed |= ui.selectable_value(operation, None, "None").changed();
+            for option in CsgOperation::all() {
+                changed |= ui
+                    .selectable_value(operation, Some(option), option.name())
+                    .changed();
+            }
+        })
+        .response
+        .on_hover_text(
+            "Union, subtract or intersect this brush with its parent brush. The operand is hidden and the parent is baked, per face materials on the parent are not kept",
+        );
+    changed
+}
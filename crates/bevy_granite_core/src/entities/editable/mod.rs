@@ -36,8 +36,13 @@ pub struct RequestEntityUpdateFromClass<'w> {
     pub camera_3d: EventWriter<'w, UserUpdatedCamera3DEvent>,
     pub directional_light: EventWriter<'w, UserUpdatedDirectionalLightEvent>,
     pub point_light: EventWriter<'w, UserUpdatedPointLightEvent>,
+    pub spot_light: EventWriter<'w, UserUpdatedSpotLightEvent>,
     pub rectangle_brush: EventWriter<'w, UserUpdatedRectBrushEvent>,
+    pub convex_brush: EventWriter<'w, UserUpdatedConvexBrushEvent>,
+    pub primitive: EventWriter<'w, UserUpdatedPrimitiveEvent>,
     pub obj: EventWriter<'w, UserUpdatedOBJEvent>,
+    pub spline: EventWriter<'w, UserUpdatedSplineEvent>,
+    pub volume: EventWriter<'w, UserUpdatedVolumeEvent>,
     pub empty: EventWriter<'w, UserUpdatedEmptyEvent>,
 }
 
@@ -53,9 +58,14 @@ pub enum GraniteTypes {
     OBJ(OBJ),
     Empty(Empty),
     PointLightData(PointLightData),
+    SpotLightData(SpotLightData),
     DirLight(DirLight),
     Camera3D(Camera3D),
     RectBrush(RectBrush),
+    ConvexBrush(ConvexBrush),
+    Primitive(Primitive),
+    Spline(Spline),
+    Volume(Volume),
     Unknown(Unknown), // Holds no real data
 }
 impl GraniteTypes {
@@ -66,9 +76,14 @@ impl GraniteTypes {
             GraniteTypes::OBJ(Default::default()),
             GraniteTypes::Empty(Default::default()),
             GraniteTypes::PointLightData(Default::default()),
+            GraniteTypes::SpotLightData(Default::default()),
             GraniteTypes::DirLight(Default::default()),
             GraniteTypes::Camera3D(Default::default()),
             GraniteTypes::RectBrush(Default::default()),
+            GraniteTypes::ConvexBrush(Default::default()),
+            GraniteTypes::Primitive(Default::default()),
+            GraniteTypes::Spline(Default::default()),
+            GraniteTypes::Volume(Default::default()),
             GraniteTypes::Unknown(Default::default()),
         ]
     }
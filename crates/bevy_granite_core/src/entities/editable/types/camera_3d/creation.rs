@@ -1,11 +1,16 @@
-use super::Camera3D;
+use super::{apply_camera_environment, apply_camera_settings, apply_volumetric_fog, Camera3D};
 use crate::{
     entities::EntitySaveReadyData, GraniteEditorSerdeEntity, GraniteType, GraniteTypes,
     HasRuntimeData, IdentityData,
 };
 use bevy::{
+    asset::AssetServer,
     core_pipeline::core_3d::Camera3d,
-    ecs::{bundle::Bundle, entity::Entity, system::Commands},
+    ecs::{
+        bundle::Bundle,
+        entity::Entity,
+        system::{Commands, Res},
+    },
     prelude::Name,
     render::camera::Camera,
     transform::components::Transform,
@@ -17,11 +22,12 @@ impl Camera3D {
     pub fn spawn_from_save_data(
         save_data: &EntitySaveReadyData,
         commands: &mut Commands,
+        asset_server: &Res<AssetServer>,
     ) -> Entity {
         let identity = &save_data.identity;
         let save_transform = &save_data.transform;
 
-        Self::spawn_from_identity(commands, identity, save_transform.to_bevy())
+        Self::spawn_from_identity(commands, identity, save_transform.to_bevy(), asset_server)
     }
 
     /// Take the name and class from identity to spawn
@@ -29,20 +35,26 @@ impl Camera3D {
         commands: &mut Commands,
         identity: &IdentityData,
         transform: Transform,
+        asset_server: &Res<AssetServer>,
     ) -> Entity {
         let class = Self::extract_class(&identity);
 
-        class.spawn(identity, commands, transform)
+        class.spawn(identity, commands, transform, asset_server)
     }
 
     /// Generally to be used from UI popups as it gives default name
-    pub fn spawn_from_new_identity(&self, commands: &mut Commands, transform: Transform) -> Entity {
+    pub fn spawn_from_new_identity(
+        &self,
+        commands: &mut Commands,
+        transform: Transform,
+        asset_server: &Res<AssetServer>,
+    ) -> Entity {
         let identity = IdentityData {
             name: self.type_name(),
             uuid: Uuid::new_v4(),
             class: GraniteTypes::Camera3D(self.clone()),
         };
-        self.spawn(&identity, commands, transform)
+        self.spawn(&identity, commands, transform, asset_server)
     }
 
     /// Private core logic
@@ -51,34 +63,27 @@ impl Camera3D {
         identity: &IdentityData,
         commands: &mut Commands,
         transform: Transform,
+        asset_server: &Res<AssetServer>,
     ) -> Entity {
         let mut entity =
             commands.spawn(Self::get_bundle(self.clone(), identity.clone(), transform));
 
         if self.has_volumetric_fog {
-            let mut fog = bevy::pbr::VolumetricFog::default();
-            let mut fog_volume = bevy::pbr::FogVolume::default();
-
-            if let Some(fog_settings) = &self.volumetric_fog_settings {
-                fog.ambient_color = fog_settings.ambient_color;
-                fog.ambient_intensity = fog_settings.ambient_intensity;
-                fog.step_count = fog_settings.step_count;
-                fog_volume.fog_color = fog_settings.fog_color;
-                fog_volume.absorption = fog_settings.absorption;
-                fog_volume.light_intensity = fog_settings.light_intensity;
-                fog_volume.light_tint = fog_settings.light_tint;
-                fog_volume.density_factor = fog_settings.density;
-                fog_volume.scattering = fog_settings.scattering;
-                fog_volume.scattering_asymmetry = fog_settings.scattering_asymmetry;
+            apply_volumetric_fog(
+                &mut entity,
+                &self.volumetric_fog_settings.clone().unwrap_or_default(),
+            );
+        }
 
-                // TODO: work out the bevy 0.16 equivalent for max_depth
-                // entity.insert(VolumetricFogSettings {
-                //     max_depth: fog_settings.max_depth,
-                // });
-            }
-            //I don't know if the fog volume should be attached to the camera or its own entity
-            entity.insert((fog, fog_volume));
+        if self.has_environment {
+            apply_camera_environment(
+                &mut entity,
+                self.environment_settings.as_ref(),
+                asset_server,
+            );
         }
+
+        apply_camera_settings(&mut entity, self);
         entity.id()
     }
 
@@ -92,6 +97,7 @@ impl Camera3D {
             Camera3d::default(),
             Camera {
                 is_active: camera_3d.is_active,
+                hdr: camera_3d.wants_hdr(),
                 ..Default::default()
             },
             transform,
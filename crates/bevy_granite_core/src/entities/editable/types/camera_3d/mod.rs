@@ -8,9 +8,10 @@ use bevy::{
         event::Event,
         system::{Commands, Res, ResMut},
     },
+    math::{EulerRot, Quat, Vec3},
     pbr::StandardMaterial,
     prelude::Reflect,
-    render::mesh::Mesh,
+    render::{camera::Exposure, mesh::Mesh},
     transform::components::Transform,
 };
 use bevy_egui::egui;
@@ -20,10 +21,12 @@ use serde::{Deserialize, Serialize};
 
 pub mod creation;
 pub mod plugin;
+pub mod settings;
 pub mod ui;
 pub mod update_event;
 
 pub use plugin::*;
+pub use settings::*;
 pub use update_event::*;
 
 /// Internal event thats called when user edits UI camera variable
@@ -36,13 +39,39 @@ pub struct UserUpdatedCamera3DEvent {
 /// Actual serialized class data thats stored inside IdentityData
 /// is_active is Bevy Camera3D data
 /// has_volumetric_fog and counterpart settings are custom to inject volumetrics easier
+/// has_environment works the same way for the skybox and image based lighting
+/// Projection, exposure and tonemapping are always applied. Post processing is optional, None removes it
+/// exposure is EV100
 #[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
 pub struct Camera3D {
     pub is_active: bool,
     pub has_volumetric_fog: bool, // if true, our next update even will insert volumetric fog settings
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub volumetric_fog_settings: Option<VolumetricFog>,
+
+    pub has_environment: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment_settings: Option<CameraEnvironment>,
+
+    pub projection: CameraProjection,
+    pub exposure: f32,
+    pub tonemapping: CameraTonemapping,
+    pub hdr: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bloom: Option<CameraBloom>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssao: Option<CameraSsao>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_of_field: Option<CameraDepthOfField>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_fog: Option<CameraDistanceFog>,
 }
 impl Default for Camera3D {
     fn default() -> Self {
@@ -50,12 +79,67 @@ impl Default for Camera3D {
             is_active: true,
             has_volumetric_fog: false,
             volumetric_fog_settings: None,
+            has_environment: false,
+            environment_settings: None,
+            projection: CameraProjection::default(),
+            exposure: Exposure::default().ev100,
+            tonemapping: CameraTonemapping::default(),
+            hdr: false,
+            bloom: None,
+            ssao: None,
+            depth_of_field: None,
+            distance_fog: None,
         }
     }
 }
 
+/// Skybox and environment map lighting for a camera
+/// Paths are relative to /assets and should point at cubemaps (.ktx2). Empty paths are skipped
+/// If no diffuse map is given the specular map is used for both
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct CameraEnvironment {
+    pub skybox_path: String,
+    pub skybox_brightness: f32,
+    pub diffuse_map_path: String,
+    pub specular_map_path: String,
+    pub intensity: f32,
+    /// Euler angles in degrees (YXZ), applied to both the skybox and the lighting
+    pub rotation: Vec3,
+}
+impl Default for CameraEnvironment {
+    fn default() -> Self {
+        Self {
+            skybox_path: String::new(),
+            skybox_brightness: 1000.0,
+            diffuse_map_path: String::new(),
+            specular_map_path: String::new(),
+            intensity: 1000.0,
+            rotation: Vec3::ZERO,
+        }
+    }
+}
+impl Camera3D {
+    /// Bloom only works on an HDR camera, so it turns HDR on by itself
+    pub fn wants_hdr(&self) -> bool {
+        self.hdr || self.bloom.is_some()
+    }
+}
+
+impl CameraEnvironment {
+    pub fn rotation_quat(&self) -> Quat {
+        Quat::from_euler(
+            EulerRot::YXZ,
+            self.rotation.y.to_radians(),
+            self.rotation.x.to_radians(),
+            self.rotation.z.to_radians(),
+        )
+    }
+}
+
 /// Wrapper for bevy volumetric fog thats serializable and optional
 /// Will need to keep in parity if Bevy changes how it stores these settings
+/// max_depth is how far the fog reaches from the camera, it sizes the fog volume that follows the camera
 #[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
 pub struct VolumetricFog {
     pub fog_color: Color,
@@ -116,10 +200,10 @@ impl GraniteType for Camera3D {
         _standard_materials: ResMut<Assets<StandardMaterial>>,
         _meshes: ResMut<Assets<Mesh>>,
         _available_materials: ResMut<AvailableEditableMaterials>,
-        _asset_server: Res<AssetServer>,
+        asset_server: Res<AssetServer>,
         _maybe_prompt_data: Option<PromptData>,
     ) -> Entity {
-        Camera3D::spawn_from_new_identity(self, commands, transform)
+        Camera3D::spawn_from_new_identity(self, commands, transform, &asset_server)
     }
 
     fn spawn_from_save_data(
@@ -129,9 +213,9 @@ impl GraniteType for Camera3D {
         _standard_materials: &mut ResMut<Assets<StandardMaterial>>,
         _meshes: &mut ResMut<Assets<Mesh>>,
         _available_materials: &mut ResMut<AvailableEditableMaterials>,
-        _asset_server: &Res<AssetServer>,
+        asset_server: &Res<AssetServer>,
     ) -> Entity {
-        Camera3D::spawn_from_save_data(save_data, commands)
+        Camera3D::spawn_from_save_data(save_data, commands, asset_server)
     }
 
     fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
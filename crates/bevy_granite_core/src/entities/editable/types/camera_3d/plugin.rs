@@ -1,5 +1,8 @@
-use super::{update_camera_3d_system, UserUpdatedCamera3DEvent};
-use crate::Camera3D;
+use super::{
+    update_camera_3d_system, CameraBloom, CameraDepthOfField, CameraDistanceFog, CameraProjection,
+    CameraSsao, CameraTonemapping, UserUpdatedCamera3DEvent,
+};
+use crate::{Camera3D, CameraEnvironment};
 use bevy::app::{App, Plugin, Update};
 
 pub struct Camera3DPlugin;
@@ -14,6 +17,13 @@ impl Plugin for Camera3DPlugin {
             // Register
             //
             .register_type::<Camera3D>()
+            .register_type::<CameraEnvironment>()
+            .register_type::<CameraProjection>()
+            .register_type::<CameraTonemapping>()
+            .register_type::<CameraBloom>()
+            .register_type::<CameraSsao>()
+            .register_type::<CameraDepthOfField>()
+            .register_type::<CameraDistanceFog>()
             //
             // Schedule system
             //
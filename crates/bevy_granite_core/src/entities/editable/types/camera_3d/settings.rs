@@ -0,0 +1,341 @@
+use bevy::{
+    color::Color,
+    core_pipeline::{
+        bloom::{Bloom, BloomCompositeMode, BloomPrefilter},
+        dof::{DepthOfField, DepthOfFieldMode},
+        tonemapping::Tonemapping,
+    },
+    pbr::{
+        DistanceFog, FogFalloff, ScreenSpaceAmbientOcclusion,
+        ScreenSpaceAmbientOcclusionQualityLevel,
+    },
+    prelude::Reflect,
+    render::camera::{OrthographicProjection, PerspectiveProjection, Projection, ScalingMode},
+};
+use serde::{Deserialize, Serialize};
+
+// Serializable wrappers for bevy camera components
+// Will need to keep in parity if Bevy changes how it stores these settings
+
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProjectionMode {
+    #[default]
+    Perspective,
+    Orthographic,
+}
+
+/// Both modes are stored so switching back and forth keeps the values
+/// fov is vertical and in degrees. ortho_scale is the vertical size of the view in world units
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct CameraProjection {
+    pub mode: ProjectionMode,
+    pub fov: f32,
+    pub near: f32,
+    pub far: f32,
+    pub ortho_scale: f32,
+}
+impl Default for CameraProjection {
+    fn default() -> Self {
+        Self {
+            mode: ProjectionMode::Perspective,
+            fov: 45.0,
+            near: 0.1,
+            far: 1000.0,
+            ortho_scale: 10.0,
+        }
+    }
+}
+impl CameraProjection {
+    pub fn to_bevy(&self) -> Projection {
+        let near = self.near.max(0.001);
+        let far = self.far.max(near + 0.001);
+        match self.mode {
+            ProjectionMode::Perspective => Projection::Perspective(PerspectiveProjection {
+                fov: self.fov.clamp(1.0, 179.0).to_radians(),
+                near,
+                far,
+                ..Default::default()
+            }),
+            ProjectionMode::Orthographic => Projection::Orthographic(OrthographicProjection {
+                near,
+                far,
+                scaling_mode: ScalingMode::FixedVertical {
+                    viewport_height: self.ortho_scale.max(0.001),
+                },
+                ..OrthographicProjection::default_3d()
+            }),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CameraTonemapping {
+    None,
+    Reinhard,
+    ReinhardLuminance,
+    AcesFitted,
+    AgX,
+    SomewhatBoringDisplayTransform,
+    #[default]
+    TonyMcMapface,
+    BlenderFilmic,
+}
+impl CameraTonemapping {
+    pub fn all() -> [CameraTonemapping; 8] {
+        [
+            CameraTonemapping::None,
+            CameraTonemapping::Reinhard,
+            CameraTonemapping::ReinhardLuminance,
+            CameraTonemapping::AcesFitted,
+            CameraTonemapping::AgX,
+            CameraTonemapping::SomewhatBoringDisplayTransform,
+            CameraTonemapping::TonyMcMapface,
+            CameraTonemapping::BlenderFilmic,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CameraTonemapping::None => "None",
+            CameraTonemapping::Reinhard => "Reinhard",
+            CameraTonemapping::ReinhardLuminance => "Reinhard Luminance",
+            CameraTonemapping::AcesFitted => "ACES Fitted",
+            CameraTonemapping::AgX => "AgX",
+            CameraTonemapping::SomewhatBoringDisplayTransform => "Somewhat Boring",
+            CameraTonemapping::TonyMcMapface => "Tony McMapface",
+            CameraTonemapping::BlenderFilmic => "Blender Filmic",
+        }
+    }
+
+    pub fn to_bevy(&self) -> Tonemapping {
+        match self {
+            CameraTonemapping::None => Tonemapping::None,
+            CameraTonemapping::Reinhard => Tonemapping::Reinhard,
+            CameraTonemapping::ReinhardLuminance => Tonemapping::ReinhardLuminance,
+            CameraTonemapping::AcesFitted => Tonemapping::AcesFitted,
+            CameraTonemapping::AgX => Tonemapping::AgX,
+            CameraTonemapping::SomewhatBoringDisplayTransform => {
+                Tonemapping::SomewhatBoringDisplayTransform
+            }
+            CameraTonemapping::TonyMcMapface => Tonemapping::TonyMcMapface,
+            CameraTonemapping::BlenderFilmic => Tonemapping::BlenderFilmic,
+        }
+    }
+}
+
+/// Bloom forces the camera into HDR
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct CameraBloom {
+    pub intensity: f32,
+    pub low_frequency_boost: f32,
+    pub low_frequency_boost_curvature: f32,
+    pub high_pass_frequency: f32,
+    pub threshold: f32,
+    pub threshold_softness: f32,
+    pub additive: bool,
+}
+impl Default for CameraBloom {
+    fn default() -> Self {
+        let natural = Bloom::NATURAL;
+        Self {
+            intensity: natural.intensity,
+            low_frequency_boost: natural.low_frequency_boost,
+            low_frequency_boost_curvature: natural.low_frequency_boost_curvature,
+            high_pass_frequency: natural.high_pass_frequency,
+            threshold: natural.prefilter.threshold,
+            threshold_softness: natural.prefilter.threshold_softness,
+            additive: false,
+        }
+    }
+}
+impl CameraBloom {
+    pub fn to_bevy(&self) -> Bloom {
+        Bloom {
+            intensity: self.intensity,
+            low_frequency_boost: self.low_frequency_boost,
+            low_frequency_boost_curvature: self.low_frequency_boost_curvature,
+            high_pass_frequency: self.high_pass_frequency,
+            prefilter: BloomPrefilter {
+                threshold: self.threshold,
+                threshold_softness: self.threshold_softness,
+            },
+            composite_mode: if self.additive {
+                BloomCompositeMode::Additive
+            } else {
+                BloomCompositeMode::EnergyConserving
+            },
+            ..Bloom::NATURAL
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SsaoQuality {
+    Low,
+    Medium,
+    #[default]
+    High,
+    Ultra,
+}
+impl SsaoQuality {
+    pub fn all() -> [SsaoQuality; 4] {
+        [
+            SsaoQuality::Low,
+            SsaoQuality::Medium,
+            SsaoQuality::High,
+            SsaoQuality::Ultra,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SsaoQuality::Low => "Low",
+            SsaoQuality::Medium => "Medium",
+            SsaoQuality::High => "High",
+            SsaoQuality::Ultra => "Ultra",
+        }
+    }
+}
+
+/// Screen space ambient occlusion. Bevy requires MSAA to be off while this is enabled
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct CameraSsao {
+    pub quality: SsaoQuality,
+    pub constant_object_thickness: f32,
+}
+impl Default for CameraSsao {
+    fn default() -> Self {
+        Self {
+            quality: SsaoQuality::High,
+            constant_object_thickness: ScreenSpaceAmbientOcclusion::default()
+                .constant_object_thickness,
+        }
+    }
+}
+impl CameraSsao {
+    pub fn to_bevy(&self) -> ScreenSpaceAmbientOcclusion {
+        ScreenSpaceAmbientOcclusion {
+            quality_level: match self.quality {
+                SsaoQuality::Low => ScreenSpaceAmbientOcclusionQualityLevel::Low,
+                SsaoQuality::Medium => ScreenSpaceAmbientOcclusionQualityLevel::Medium,
+                SsaoQuality::High => ScreenSpaceAmbientOcclusionQualityLevel::High,
+                SsaoQuality::Ultra => ScreenSpaceAmbientOcclusionQualityLevel::Ultra,
+            },
+            constant_object_thickness: self.constant_object_thickness,
+        }
+    }
+}
+
+/// Physically based depth of field. Bokeh looks better, gaussian is cheaper
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct CameraDepthOfField {
+    pub bokeh: bool,
+    pub focal_distance: f32,
+    pub sensor_height: f32,
+    pub aperture_f_stops: f32,
+    pub max_circle_of_confusion_diameter: f32,
+}
+impl Default for CameraDepthOfField {
+    fn default() -> Self {
+        let dof = DepthOfField::default();
+        Self {
+            bokeh: matches!(dof.mode, DepthOfFieldMode::Bokeh),
+            focal_distance: dof.focal_distance,
+            sensor_height: dof.sensor_height,
+            aperture_f_stops: dof.aperture_f_stops,
+            max_circle_of_confusion_diameter: dof.max_circle_of_confusion_diameter,
+        }
+    }
+}
+impl CameraDepthOfField {
+    pub fn to_bevy(&self) -> DepthOfField {
+        DepthOfField {
+            mode: if self.bokeh {
+                DepthOfFieldMode::Bokeh
+            } else {
+                DepthOfFieldMode::Gaussian
+            },
+            focal_distance: self.focal_distance.max(0.001),
+            sensor_height: self.sensor_height.max(0.0001),
+            aperture_f_stops: self.aperture_f_stops.max(0.01),
+            max_circle_of_confusion_diameter: self.max_circle_of_confusion_diameter.max(0.0),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FogFalloffMode {
+    #[default]
+    Linear,
+    Exponential,
+    ExponentialSquared,
+}
+impl FogFalloffMode {
+    pub fn all() -> [FogFalloffMode; 3] {
+        [
+            FogFalloffMode::Linear,
+            FogFalloffMode::Exponential,
+            FogFalloffMode::ExponentialSquared,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FogFalloffMode::Linear => "Linear",
+            FogFalloffMode::Exponential => "Exponential",
+            FogFalloffMode::ExponentialSquared => "Exponential Squared",
+        }
+    }
+}
+
+/// Bevy DistanceFog. start and end are used by Linear, density by the exponential modes
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct CameraDistanceFog {
+    pub color: Color,
+    pub directional_light_color: Color,
+    pub directional_light_exponent: f32,
+    pub falloff: FogFalloffMode,
+    pub start: f32,
+    pub end: f32,
+    pub density: f32,
+}
+impl Default for CameraDistanceFog {
+    fn default() -> Self {
+        Self {
+            color: Color::srgba(0.35, 0.48, 0.66, 1.0),
+            directional_light_color: Color::NONE,
+            directional_light_exponent: 8.0,
+            falloff: FogFalloffMode::Linear,
+            start: 5.0,
+            end: 50.0,
+            density: 0.05,
+        }
+    }
+}
+impl CameraDistanceFog {
+    pub fn to_bevy(&self) -> DistanceFog {
+        DistanceFog {
+            color: self.color,
+            directional_light_color: self.directional_light_color,
+            directional_light_exponent: self.directional_light_exponent,
+            falloff: match self.falloff {
+                FogFalloffMode::Linear => FogFalloff::Linear {
+                    start: self.start,
+                    end: self.end.max(self.start + 0.001),
+                },
+                FogFalloffMode::Exponential => FogFalloff::Exponential {
+                    density: self.density,
+                },
+                FogFalloffMode::ExponentialSquared => FogFalloff::ExponentialSquared {
+                    density: self.density,
+                },
+            },
+        }
+    }
+}
@@ -1,5 +1,9 @@
-use crate::GraniteType;
-use super::Camera3D;
+use super::{
+    Camera3D, CameraBloom, CameraDepthOfField, CameraDistanceFog, CameraEnvironment,
+    CameraProjection, CameraSsao, CameraTonemapping, FogFalloffMode, ProjectionMode, SsaoQuality,
+};
+use crate::{absolute_asset_to_rel, shared::asset_file_browser, GraniteType};
+use bevy::color::Color;
 use bevy_egui::egui;
 
 impl Camera3D {
@@ -33,6 +37,9 @@ impl Camera3D {
                     ui.label("Volumetric Fog:");
                     changed |= ui.checkbox(&mut fog_enabled, "").changed();
                     ui.end_row();
+                    ui.label("Environment:");
+                    changed |= ui.checkbox(&mut data.has_environment, "").changed();
+                    ui.end_row();
                 });
             ui.add_space(large_spacing);
             if *fog_enabled {
@@ -181,7 +188,424 @@ impl Camera3D {
                         });
                 });
             };
+            if data.has_environment {
+                let environment = data
+                    .environment_settings
+                    .get_or_insert_with(CameraEnvironment::default);
+                ui.collapsing("Environment", |ui| {
+                    changed |= environment_ui(ui, environment, large_spacing);
+                });
+            }
+
+            ui.collapsing("Projection", |ui| {
+                changed |= projection_ui(ui, &mut data.projection, large_spacing);
+            });
+            ui.collapsing("Exposure & Tonemapping", |ui| {
+                changed |= exposure_ui(
+                    ui,
+                    &mut data.exposure,
+                    &mut data.tonemapping,
+                    &mut data.hdr,
+                    large_spacing,
+                );
+            });
+            changed |= optional_section(ui, "Bloom", &mut data.bloom, |ui, bloom| {
+                bloom_ui(ui, bloom, large_spacing)
+            });
+            changed |= optional_section(ui, "Ambient Occlusion", &mut data.ssao, |ui, ssao| {
+                ssao_ui(ui, ssao, large_spacing)
+            });
+            changed |= optional_section(
+                ui,
+                "Depth of Field",
+                &mut data.depth_of_field,
+                |ui, depth_of_field| depth_of_field_ui(ui, depth_of_field, large_spacing),
+            );
+            changed |= optional_section(ui, "Distance Fog", &mut data.distance_fog, |ui, fog| {
+                distance_fog_ui(ui, fog, large_spacing)
+            });
         });
         changed
     }
 }
+
+/// Collapsing section with an enabled toggle. Disabling sets the settings to None so they are removed
+fn optional_section<T: Default>(
+    ui: &mut egui::Ui,
+    title: &str,
+    value: &mut Option<T>,
+    body: impl FnOnce(&mut egui::Ui, &mut T) -> bool,
+) -> bool {
+    let mut changed = false;
+    ui.collapsing(title, |ui| {
+        let mut enabled = value.is_some();
+        if ui.checkbox(&mut enabled, "Enabled").changed() {
+            *value = enabled.then(T::default);
+            changed = true;
+        }
+        if let Some(settings) = value {
+            changed |= body(ui, settings);
+        }
+    });
+    changed
+}
+
+fn drag(
+    ui: &mut egui::Ui,
+    value: &mut f32,
+    range: std::ops::RangeInclusive<f32>,
+    speed: f32,
+) -> bool {
+    ui.add(egui::DragValue::new(value).range(range).speed(speed))
+        .changed()
+}
+
+fn color_edit(ui: &mut egui::Ui, color: &mut Color) -> bool {
+    let srgba = color.to_srgba();
+    let mut rgba = [srgba.red, srgba.green, srgba.blue, srgba.alpha];
+    if ui.color_edit_button_rgba_unmultiplied(&mut rgba).changed() {
+        *color = Color::srgba(rgba[0], rgba[1], rgba[2], rgba[3]);
+        return true;
+    }
+    false
+}
+
+fn projection_ui(ui: &mut egui::Ui, projection: &mut CameraProjection, spacing: f32) -> bool {
+    let mut changed = false;
+    egui::Grid::new("camera_projection_grid")
+        .num_columns(2)
+        .spacing([spacing, spacing])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("Mode:");
+            ui.horizontal(|ui| {
+                changed |= ui
+                    .radio_value(
+                        &mut projection.mode,
+                        ProjectionMode::Perspective,
+                        "Perspective",
+                    )
+                    .changed();
+                changed |= ui
+                    .radio_value(
+                        &mut projection.mode,
+                        ProjectionMode::Orthographic,
+                        "Orthographic",
+                    )
+                    .changed();
+            });
+            ui.end_row();
+
+            match projection.mode {
+                ProjectionMode::Perspective => {
+                    ui.label("FOV:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut projection.fov)
+                                .range(1.0..=179.0)
+                                .speed(0.5)
+                                .suffix("°"),
+                        )
+                        .changed();
+                }
+                ProjectionMode::Orthographic => {
+                    ui.label("Ortho Scale:");
+                    changed |= drag(ui, &mut projection.ortho_scale, 0.001..=10_000.0, 0.1);
+                }
+            }
+            ui.end_row();
+
+            ui.label("Near:");
+            changed |= drag(ui, &mut projection.near, 0.001..=10_000.0, 0.01);
+            ui.end_row();
+
+            ui.label("Far:");
+            changed |= drag(ui, &mut projection.far, 0.01..=100_000.0, 1.0);
+            ui.end_row();
+        });
+    changed
+}
+
+fn exposure_ui(
+    ui: &mut egui::Ui,
+    exposure: &mut f32,
+    tonemapping: &mut CameraTonemapping,
+    hdr: &mut bool,
+    spacing: f32,
+) -> bool {
+    let mut changed = false;
+    egui::Grid::new("camera_exposure_grid")
+        .num_columns(2)
+        .spacing([spacing, spacing])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("Exposure (EV100):");
+            changed |= drag(ui, exposure, -8.0..=20.0, 0.05);
+            ui.end_row();
+
+            ui.label("Tonemapping:");
+            egui::ComboBox::from_id_salt("camera_tonemapping_dropdown")
+                .selected_text(tonemapping.name())
+                .show_ui(ui, |ui| {
+                    for mode in CameraTonemapping::all() {
+                        changed |= ui
+                            .selectable_value(tonemapping, mode, mode.name())
+                            .changed();
+                    }
+                });
+            ui.end_row();
+
+            ui.label("HDR:");
+            changed |= ui.checkbox(hdr, "").changed();
+            ui.end_row();
+        });
+    changed
+}
+
+fn bloom_ui(ui: &mut egui::Ui, bloom: &mut CameraBloom, spacing: f32) -> bool {
+    let mut changed = false;
+    ui.weak("Bloom turns on HDR for this camera");
+    egui::Grid::new("camera_bloom_grid")
+        .num_columns(2)
+        .spacing([spacing, spacing])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("Intensity:");
+            changed |= drag(ui, &mut bloom.intensity, 0.0..=1.0, 0.005);
+            ui.end_row();
+
+            ui.label("Low Frequency Boost:");
+            changed |= drag(ui, &mut bloom.low_frequency_boost, 0.0..=1.0, 0.005);
+            ui.end_row();
+
+            ui.label("Boost Curvature:");
+            changed |= drag(
+                ui,
+                &mut bloom.low_frequency_boost_curvature,
+                0.0..=1.0,
+                0.005,
+            );
+            ui.end_row();
+
+            ui.label("High Pass Frequency:");
+            changed |= drag(ui, &mut bloom.high_pass_frequency, 0.0..=1.0, 0.005);
+            ui.end_row();
+
+            ui.label("Threshold:");
+            changed |= drag(ui, &mut bloom.threshold, 0.0..=100.0, 0.01);
+            ui.end_row();
+
+            ui.label("Threshold Softness:");
+            changed |= drag(ui, &mut bloom.threshold_softness, 0.0..=1.0, 0.005);
+            ui.end_row();
+
+            ui.label("Additive:");
+            changed |= ui.checkbox(&mut bloom.additive, "").changed();
+            ui.end_row();
+        });
+    changed
+}
+
+fn ssao_ui(ui: &mut egui::Ui, ssao: &mut CameraSsao, spacing: f32) -> bool {
+    let mut changed = false;
+    ui.weak("MSAA is turned off for this camera while enabled");
+    egui::Grid::new("camera_ssao_grid")
+        .num_columns(2)
+        .spacing([spacing, spacing])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("Quality:");
+            egui::ComboBox::from_id_salt("camera_ssao_quality_dropdown")
+                .selected_text(ssao.quality.name())
+                .show_ui(ui, |ui| {
+                    for quality in SsaoQuality::all() {
+                        changed |= ui
+                            .selectable_value(&mut ssao.quality, quality, quality.name())
+                            .changed();
+                    }
+                });
+            ui.end_row();
+
+            ui.label("Object Thickness:");
+            changed |= drag(ui, &mut ssao.constant_object_thickness, 0.0..=10.0, 0.01);
+            ui.end_row();
+        });
+    changed
+}
+
+fn depth_of_field_ui(
+    ui: &mut egui::Ui,
+    depth_of_field: &mut CameraDepthOfField,
+    spacing: f32,
+) -> bool {
+    let mut changed = false;
+    egui::Grid::new("camera_depth_of_field_grid")
+        .num_columns(2)
+        .spacing([spacing, spacing])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("Bokeh:");
+            changed |= ui.checkbox(&mut depth_of_field.bokeh, "").changed();
+            ui.end_row();
+
+            ui.label("Focal Distance:");
+            changed |= drag(
+                ui,
+                &mut depth_of_field.focal_distance,
+                0.001..=10_000.0,
+                0.1,
+            );
+            ui.end_row();
+
+            ui.label("Aperture (f-stops):");
+            changed |= drag(ui, &mut depth_of_field.aperture_f_stops, 0.01..=64.0, 0.01);
+            ui.end_row();
+
+            ui.label("Sensor Height:");
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut depth_of_field.sensor_height)
+                        .range(0.0001..=1.0)
+                        .speed(0.0001)
+                        .max_decimals(5),
+                )
+                .changed();
+            ui.end_row();
+
+            ui.label("Max CoC Diameter:");
+            changed |= drag(
+                ui,
+                &mut depth_of_field.max_circle_of_confusion_diameter,
+                0.0..=256.0,
+                0.5,
+            );
+            ui.end_row();
+        });
+    changed
+}
+
+fn distance_fog_ui(ui: &mut egui::Ui, fog: &mut CameraDistanceFog, spacing: f32) -> bool {
+    let mut changed = false;
+    egui::Grid::new("camera_distance_fog_grid")
+        .num_columns(2)
+        .spacing([spacing, spacing])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("Color:");
+            changed |= color_edit(ui, &mut fog.color);
+            ui.end_row();
+
+            ui.label("Sun Color:");
+            changed |= color_edit(ui, &mut fog.directional_light_color);
+            ui.end_row();
+
+            ui.label("Sun Exponent:");
+            changed |= drag(ui, &mut fog.directional_light_exponent, 0.0..=100.0, 0.1);
+            ui.end_row();
+
+            ui.label("Falloff:");
+            egui::ComboBox::from_id_salt("camera_distance_fog_falloff_dropdown")
+                .selected_text(fog.falloff.name())
+                .show_ui(ui, |ui| {
+                    for falloff in FogFalloffMode::all() {
+                        changed |= ui
+                            .selectable_value(&mut fog.falloff, falloff, falloff.name())
+                            .changed();
+                    }
+                });
+            ui.end_row();
+
+            match fog.falloff {
+                FogFalloffMode::Linear => {
+                    ui.label("Start:");
+                    changed |= drag(ui, &mut fog.start, 0.0..=100_000.0, 0.1);
+                    ui.end_row();
+
+                    ui.label("End:");
+                    changed |= drag(ui, &mut fog.end, 0.0..=100_000.0, 0.1);
+                    ui.end_row();
+                }
+                FogFalloffMode::Exponential | FogFalloffMode::ExponentialSquared => {
+                    ui.label("Density:");
+                    changed |= drag(ui, &mut fog.density, 0.0..=10.0, 0.001);
+                    ui.end_row();
+                }
+            }
+        });
+    changed
+}
+
+/// Skybox and environment map lighting paths, brightness and rotation
+fn environment_ui(ui: &mut egui::Ui, environment: &mut CameraEnvironment, spacing: f32) -> bool {
+    let mut changed = false;
+    egui::Grid::new("camera_environment_grid")
+        .num_columns(2)
+        .spacing([spacing, spacing])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("Skybox:");
+            changed |= cubemap_path_ui(ui, &mut environment.skybox_path);
+            ui.end_row();
+
+            ui.label("Skybox Brightness:");
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut environment.skybox_brightness)
+                        .range(0.0..=100_000.0)
+                        .speed(10.0),
+                )
+                .changed();
+            ui.end_row();
+
+            ui.label("Specular Map:");
+            changed |= cubemap_path_ui(ui, &mut environment.specular_map_path);
+            ui.end_row();
+
+            ui.label("Diffuse Map:");
+            changed |= cubemap_path_ui(ui, &mut environment.diffuse_map_path);
+            ui.end_row();
+
+            ui.label("Light Intensity:");
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut environment.intensity)
+                        .range(0.0..=100_000.0)
+                        .speed(10.0),
+                )
+                .changed();
+            ui.end_row();
+
+            ui.label("Rotation:");
+            ui.horizontal(|ui| {
+                for axis in [
+                    &mut environment.rotation.x,
+                    &mut environment.rotation.y,
+                    &mut environment.rotation.z,
+                ] {
+                    changed |= ui
+                        .add(egui::DragValue::new(axis).speed(1.0).suffix("°"))
+                        .changed();
+                }
+            });
+            ui.end_row();
+        });
+    changed
+}
+
+fn cubemap_path_ui(ui: &mut egui::Ui, path: &mut String) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        changed |= ui.text_edit_singleline(path).lost_focus();
+        if ui.button("Browse").clicked() {
+            if let Some(selected) = asset_file_browser("textures".to_string(), vec!["ktx2"]) {
+                *path = absolute_asset_to_rel(selected).to_string();
+                changed = true;
+            }
+        }
+        if !path.is_empty() && ui.small_button("X").clicked() {
+            path.clear();
+            changed = true;
+        }
+    });
+    changed
+}
@@ -1,15 +1,32 @@
-use super::{UserUpdatedCamera3DEvent, VolumetricFog};
+use super::{CameraEnvironment, UserUpdatedCamera3DEvent, VolumetricFog};
 use crate::{
     entities::editable::RequestEntityUpdateFromClass, Camera3D, GraniteTypes, IdentityData,
+    TreeHiddenEntity,
 };
 use bevy::{
+    asset::{AssetServer, Handle},
+    core_pipeline::{bloom::Bloom, dof::DepthOfField, Skybox},
     ecs::{
+        component::Component,
         entity::Entity,
         event::EventReader,
-        system::{Commands, Query},
+        hierarchy::ChildOf,
+        query::With,
+        system::{Commands, EntityCommands, Query, Res},
+        world::EntityWorldMut,
     },
-    pbr::{FogVolume, VolumetricFog as VolumetricFogSettings},
-    render::camera::Camera,
+    image::Image,
+    math::Vec3,
+    pbr::{
+        environment_map::EnvironmentMapLight, DistanceFog, FogVolume, ScreenSpaceAmbientOcclusion,
+        VolumetricFog as VolumetricFogSettings,
+    },
+    prelude::Name,
+    render::{
+        camera::{Camera, Exposure},
+        view::Msaa,
+    },
+    transform::components::Transform,
 };
 
 use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
@@ -35,12 +52,22 @@ impl Camera3D {
     }
 }
 
+/// Marks the fog volume child that follows a camera with volumetric fog
+/// The volume is what limits how far the fog reaches, so max_depth scales it
+#[derive(Component)]
+pub struct CameraFogVolume;
+
+/// The Msaa a camera had before SSAO forced it off, restored once SSAO is removed
+#[derive(Component)]
+pub struct MsaaBeforeSsao(Msaa);
+
 /// Actually update the specific entity with the class data
-/// In the future im sure we will have FOV and what not
 pub fn update_camera_3d_system(
     mut reader: EventReader<UserUpdatedCamera3DEvent>,
     mut query: Query<(Entity, &mut Camera, &mut IdentityData)>,
+    fog_volume_query: Query<(Entity, &ChildOf), With<CameraFogVolume>>,
     mut commands: Commands,
+    asset_server: Res<AssetServer>,
 ) {
     for UserUpdatedCamera3DEvent {
         entity: requested_entity,
@@ -55,38 +82,38 @@ pub fn update_camera_3d_system(
             requested_entity
         );
         if let Ok((entity, mut camera, mut identity_data)) = query.get_mut(*requested_entity) {
-            if new.is_active {
-                camera.is_active = true;
-            } else {
-                camera.is_active = false;
+            camera.is_active = new.is_active;
+            camera.hdr = new.wants_hdr();
+
+            // Fog volume is rebuilt from scratch so max_depth changes apply
+            for (fog_volume, child_of) in fog_volume_query.iter() {
+                if child_of.parent() == entity {
+                    commands.entity(fog_volume).despawn();
+                }
             }
 
+            let mut entity_commands = commands.entity(entity);
             if new.has_volumetric_fog {
-                let fog_config = new.volumetric_fog_settings.clone().unwrap_or_default();
-                let mut fog = VolumetricFogSettings::default();
-                let mut fog_volume = FogVolume::default();
-                fog.ambient_color = fog_config.ambient_color;
-                fog.ambient_intensity = fog_config.ambient_intensity;
-                fog_volume.fog_color = fog_config.fog_color;
-                fog_volume.absorption = fog_config.absorption;
-                fog.step_count = fog_config.step_count;
-                fog_volume.light_intensity = fog_config.light_intensity;
-                fog_volume.light_tint = fog_config.light_tint;
-                fog_volume.density_factor = fog_config.density;
-                fog_volume.scattering = fog_config.scattering;
-                fog_volume.scattering_asymmetry = fog_config.scattering_asymmetry;
-
-                //TODO: work out the bevy 0.16 equivalent for max_depth
-                // commands.entity(entity).insert(VolumetricFogSettings {
-                //     max_depth: new_fog.max_depth,
-                // });
-                commands.entity(entity).insert((fog, fog_volume));
+                apply_volumetric_fog(
+                    &mut entity_commands,
+                    &new.volumetric_fog_settings.clone().unwrap_or_default(),
+                );
             } else {
-                commands
-                    .entity(entity)
-                    .remove::<(VolumetricFogSettings, FogVolume)>();
+                entity_commands.remove::<VolumetricFogSettings>();
             }
 
+            if new.has_environment {
+                apply_camera_environment(
+                    &mut entity_commands,
+                    new.environment_settings.as_ref(),
+                    &asset_server,
+                );
+            } else {
+                entity_commands.remove::<(Skybox, EnvironmentMapLight)>();
+            }
+
+            apply_camera_settings(&mut entity_commands, new);
+
             // Update the IdentityData to match new changes
             if let GraniteTypes::Camera3D(ref mut camera_data) = identity_data.class {
                 camera_data.is_active = new.is_active;
@@ -100,6 +127,24 @@ pub fn update_camera_3d_system(
                 } else {
                     camera_data.volumetric_fog_settings = None;
                 }
+
+                camera_data.has_environment = new.has_environment;
+                if new.has_environment {
+                    if camera_data.environment_settings.is_none() {
+                        camera_data.environment_settings = Some(CameraEnvironment::default());
+                    }
+                } else {
+                    camera_data.environment_settings = None;
+                }
+
+                camera_data.projection = new.projection.clone();
+                camera_data.exposure = new.exposure;
+                camera_data.tonemapping = new.tonemapping;
+                camera_data.hdr = new.hdr;
+                camera_data.bloom = new.bloom.clone();
+                camera_data.ssao = new.ssao.clone();
+                camera_data.depth_of_field = new.depth_of_field.clone();
+                camera_data.distance_fog = new.distance_fog.clone();
             }
         } else {
             log!(
@@ -112,3 +157,150 @@ pub fn update_camera_3d_system(
         }
     }
 }
+
+/// Insert the camera side volumetric fog settings and spawn a fog volume that follows the camera
+/// The volume is a cube centered on the camera, max_depth out in every direction
+pub fn apply_volumetric_fog(entity: &mut EntityCommands, fog_settings: &VolumetricFog) {
+    let fog = VolumetricFogSettings {
+        ambient_color: fog_settings.ambient_color,
+        ambient_intensity: fog_settings.ambient_intensity,
+        step_count: fog_settings.step_count,
+        ..Default::default()
+    };
+    let fog_volume = FogVolume {
+        fog_color: fog_settings.fog_color,
+        absorption: fog_settings.absorption,
+        light_intensity: fog_settings.light_intensity,
+        light_tint: fog_settings.light_tint,
+        density_factor: fog_settings.density,
+        scattering: fog_settings.scattering,
+        scattering_asymmetry: fog_settings.scattering_asymmetry,
+        ..Default::default()
+    };
+
+    entity.insert(fog).with_child((
+        fog_volume,
+        Transform::from_scale(Vec3::splat(fog_settings.max_depth.max(0.1) * 2.0)),
+        Name::new("Volumetric Fog Volume"),
+        CameraFogVolume,
+        TreeHiddenEntity,
+    ));
+}
+
+/// Projection, exposure, tonemapping and the optional post processing
+/// Anything set to None is removed from the camera
+pub fn apply_camera_settings(entity: &mut EntityCommands, camera_3d: &Camera3D) {
+    entity.insert((
+        camera_3d.projection.to_bevy(),
+        Exposure {
+            ev100: camera_3d.exposure,
+        },
+        camera_3d.tonemapping.to_bevy(),
+    ));
+
+    match &camera_3d.bloom {
+        Some(bloom) => {
+            entity.insert(bloom.to_bevy());
+        }
+        None => {
+            entity.remove::<Bloom>();
+        }
+    }
+
+    // SSAO does not support MSAA
+    match &camera_3d.ssao {
+        Some(ssao) => {
+            entity.insert(ssao.to_bevy()).queue(disable_msaa_for_ssao);
+        }
+        None => {
+            entity
+                .remove::<ScreenSpaceAmbientOcclusion>()
+                .queue(restore_msaa_after_ssao);
+        }
+    }
+
+    match &camera_3d.depth_of_field {
+        Some(depth_of_field) => {
+            entity.insert(depth_of_field.to_bevy());
+        }
+        None => {
+            entity.remove::<DepthOfField>();
+        }
+    }
+
+    match &camera_3d.distance_fog {
+        Some(distance_fog) => {
+            entity.insert(distance_fog.to_bevy());
+        }
+        None => {
+            entity.remove::<DistanceFog>();
+        }
+    }
+}
+
+/// Insert the skybox and environment map light described by the settings
+/// Anything without a path is removed so clearing a path in the UI clears it in the world
+pub fn apply_camera_environment(
+    entity: &mut EntityCommands,
+    settings: Option<&CameraEnvironment>,
+    asset_server: &AssetServer,
+) {
+    let default_settings = CameraEnvironment::default();
+    let settings = settings.unwrap_or(&default_settings);
+    let rotation = settings.rotation_quat();
+
+    if settings.skybox_path.is_empty() {
+        entity.remove::<Skybox>();
+    } else {
+        entity.insert(Skybox {
+            image: asset_server.load(settings.skybox_path.clone()),
+            brightness: settings.skybox_brightness,
+            rotation,
+        });
+    }
+
+    let specular_path = settings.specular_map_path.as_str();
+    let diffuse_path = if settings.diffuse_map_path.is_empty() {
+        specular_path
+    } else {
+        settings.diffuse_map_path.as_str()
+    };
+    if specular_path.is_empty() {
+        entity.remove::<EnvironmentMapLight>();
+        return;
+    }
+
+    let specular_map: Handle<Image> = asset_server.load(specular_path.to_string());
+    let diffuse_map: Handle<Image> = asset_server.load(diffuse_path.to_string());
+    entity.insert(EnvironmentMapLight {
+        diffuse_map,
+        specular_map,
+        intensity: settings.intensity,
+        rotation,
+        ..Default::default()
+    });
+
+    log!(
+        LogType::Game,
+        LogLevel::Info,
+        LogCategory::Entity,
+        "Applied environment map: {}",
+        specular_path
+    );
+}
+
+fn disable_msaa_for_ssao(mut entity: EntityWorldMut) {
+    // Already off from an earlier update, keep the original value
+    if entity.contains::<MsaaBeforeSsao>() {
+        return;
+    }
+    let previous = entity.get::<Msaa>().copied().unwrap_or_default();
+    entity.insert((MsaaBeforeSsao(previous), Msaa::Off));
+}
+
+/// Leaves Msaa alone if SSAO never turned it off
+fn restore_msaa_after_ssao(mut entity: EntityWorldMut) {
+    if let Some(MsaaBeforeSsao(previous)) = entity.take::<MsaaBeforeSsao>() {
+        entity.insert(previous);
+    }
+}
@@ -0,0 +1,166 @@
+use super::ConvexBrush;
+use crate::{
+    entities::EntitySaveReadyData, AvailableEditableMaterials, GraniteEditorSerdeEntity,
+    GraniteType, GraniteTypes, HasRuntimeData, IdentityData, NeedsTangents,
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        bundle::Bundle,
+        entity::Entity,
+        system::{Commands, Res, ResMut},
+    },
+    pbr::{MeshMaterial3d, StandardMaterial},
+    prelude::Name,
+    render::mesh::{Mesh, Mesh3d},
+    transform::components::Transform,
+};
+use uuid::Uuid;
+
+impl ConvexBrush {
+    /// Extract needed info to spawn this entity via save data
+    pub fn spawn_from_save_data(
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> Entity {
+        let identity = &save_data.identity;
+        let save_transform = &save_data.transform;
+
+        Self::spawn_from_identity(
+            commands,
+            identity,
+            save_transform.to_bevy(),
+            standard_materials,
+            available_materials,
+            asset_server,
+            meshes,
+        )
+    }
+
+    /// Take the name and class from identity to spawn
+    pub fn spawn_from_identity(
+        commands: &mut Commands,
+        identity: &IdentityData,
+        transform: Transform,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> Entity {
+        let mut class = Self::extract_class(identity);
+
+        // Load and configure the material
+        class.load_and_configure_material(available_materials, standard_materials, asset_server);
+
+        commands
+            .spawn(Self::get_bundle(class, identity.clone(), transform, meshes))
+            .id()
+    }
+
+    /// Generally to be used from UI popups as it gives default name
+    pub fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> Entity {
+        let identity = IdentityData {
+            name: self.type_name(),
+            uuid: Uuid::new_v4(),
+            class: GraniteTypes::ConvexBrush(self.clone()),
+        };
+        Self::spawn_from_identity(
+            commands,
+            &identity,
+            transform,
+            standard_materials,
+            available_materials,
+            asset_server,
+            meshes,
+        )
+    }
+
+    /// Build a bundle that is ready to spawn from a convex brush
+    fn get_bundle(
+        convex_brush: ConvexBrush,
+        identity: IdentityData,
+        transform: Transform,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> impl Bundle {
+        // Every brush owns its mesh so vertex edits only affect this entity
+        let mesh_handle = meshes.add(convex_brush.create_mesh());
+
+        (
+            transform,
+            Mesh3d(mesh_handle),
+            MeshMaterial3d(
+                convex_brush
+                    .material
+                    .current
+                    .handle
+                    .clone()
+                    .expect("Default material should always have a handle"),
+            ),
+            Name::new(identity.name.clone()),
+            HasRuntimeData,
+            GraniteEditorSerdeEntity,
+            NeedsTangents,
+            IdentityData {
+                name: identity.name.clone(),
+                uuid: identity.uuid,
+                class: GraniteTypes::ConvexBrush(convex_brush.clone()),
+            },
+        )
+    }
+
+    fn extract_class(identity: &IdentityData) -> ConvexBrush {
+        match &identity.class {
+            GraniteTypes::ConvexBrush(brush_data) => brush_data.clone(),
+            _ => panic!("Expected ConvexBrush class data, got different type from save data"),
+        }
+    }
+
+    /// Load and configure material with proper metadata
+    /// Uses a static friendly name and internal material as fallback as default convex brushes share the same material
+    fn load_and_configure_material(
+        &mut self,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        asset_server: &Res<AssetServer>,
+    ) {
+        let fallback_path = Self::internal_material_path();
+
+        // Use saved material path if available, otherwise use fallback
+        let material_path = if !self.material.path.is_empty() {
+            &self.material.path
+        } else {
+            &fallback_path
+        };
+
+        self.material.current.path = material_path.to_string();
+
+        let _created_new = self.material.current.material_exists_and_load(
+            available_materials,
+            standard_materials,
+            asset_server,
+            &self.type_name(),
+            material_path,
+        );
+
+        // Fix the material metadata after loading (since loaded materials have "None" path)
+        self.material.current.path = material_path.to_string();
+        self.material.current.friendly_name = self.type_name();
+        self.material.last = self.material.current.clone();
+
+        if self.material.path.is_empty() {
+            self.material.path = fallback_path.clone();
+        }
+    }
+}
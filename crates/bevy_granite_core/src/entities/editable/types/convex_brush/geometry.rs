@@ -0,0 +1,538 @@
+use super::ConvexBrush;
+use crate::RectBrush;
+use bevy::{
+    math::{Vec2, Vec3},
+    prelude::Reflect,
+    render::{
+        mesh::{Indices, Mesh, PrimitiveTopology},
+        render_asset::RenderAssetUsages,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+// Points closer than this are merged, and count as lying on a plane
+const EPSILON: f32 = 1e-4;
+// Looser tolerance for user edited brushes, dragging is never exact
+const CONVEX_TOLERANCE: f32 = 1e-3;
+
+/// Plane facing out of a brush. Points on the plane satisfy normal.dot(point) == distance
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct BrushPlane {
+    pub normal: Vec3,
+    pub distance: f32,
+}
+
+impl BrushPlane {
+    /// Normal does not need to be normalized, distance is scaled with it
+    pub fn new(normal: Vec3, distance: f32) -> Self {
+        let length = normal.length().max(f32::EPSILON);
+        Self {
+            normal: normal / length,
+            distance: distance / length,
+        }
+    }
+
+    pub fn from_point(normal: Vec3, point: Vec3) -> Self {
+        let normal = normal.normalize_or_zero();
+        Self {
+            normal,
+            distance: normal.dot(point),
+        }
+    }
+
+    /// Positive outside the brush, negative inside
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        self.normal.dot(point) - self.distance
+    }
+
+    pub fn flipped(&self) -> Self {
+        Self {
+            normal: -self.normal,
+            distance: -self.distance,
+        }
+    }
+
+    fn approx_eq(&self, other: &BrushPlane) -> bool {
+        self.normal.dot(other.normal) > 1.0 - EPSILON
+            && (self.distance - other.distance).abs() < EPSILON
+    }
+}
+
+/// A part of a convex brush that can be selected and moved in the editor
+/// Edges store their vertex indices lowest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConvexBrushElement {
+    Vertex(u32),
+    Edge(u32, u32),
+    Face(usize),
+}
+
+/// Which kind of element the editor is picking
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConvexBrushElementKind {
+    #[default]
+    Vertex,
+    Edge,
+    Face,
+}
+
+impl ConvexBrushElementKind {
+    pub fn all() -> [ConvexBrushElementKind; 3] {
+        [
+            ConvexBrushElementKind::Vertex,
+            ConvexBrushElementKind::Edge,
+            ConvexBrushElementKind::Face,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConvexBrushElementKind::Vertex => "Vertex",
+            ConvexBrushElementKind::Edge => "Edge",
+            ConvexBrushElementKind::Face => "Face",
+        }
+    }
+}
+
+/// Starting shapes for a convex brush, all fit inside a unit cube centered on the origin
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConvexBrushPreset {
+    #[default]
+    Cube,
+    Wedge,
+    Pyramid,
+    Prism,
+}
+
+impl ConvexBrushPreset {
+    pub fn all() -> [ConvexBrushPreset; 4] {
+        [
+            ConvexBrushPreset::Cube,
+            ConvexBrushPreset::Wedge,
+            ConvexBrushPreset::Pyramid,
+            ConvexBrushPreset::Prism,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConvexBrushPreset::Cube => "Cube",
+            ConvexBrushPreset::Wedge => "Wedge",
+            ConvexBrushPreset::Pyramid => "Pyramid",
+            ConvexBrushPreset::Prism => "Hexagonal Prism",
+        }
+    }
+
+    /// Vertices and faces for this preset
+    pub fn geometry(&self) -> (Vec<Vec3>, Vec<Vec<u32>>) {
+        let h = 0.5;
+        let points = match self {
+            ConvexBrushPreset::Cube => vec![
+                Vec3::new(-h, -h, -h),
+                Vec3::new(h, -h, -h),
+                Vec3::new(h, -h, h),
+                Vec3::new(-h, -h, h),
+                Vec3::new(-h, h, -h),
+                Vec3::new(h, h, -h),
+                Vec3::new(h, h, h),
+                Vec3::new(-h, h, h),
+            ],
+            // Ramp rising towards -Z
+            ConvexBrushPreset::Wedge => vec![
+                Vec3::new(-h, -h, -h),
+                Vec3::new(h, -h, -h),
+                Vec3::new(h, -h, h),
+                Vec3::new(-h, -h, h),
+                Vec3::new(-h, h, -h),
+                Vec3::new(h, h, -h),
+            ],
+            ConvexBrushPreset::Pyramid => vec![
+                Vec3::new(-h, -h, -h),
+                Vec3::new(h, -h, -h),
+                Vec3::new(h, -h, h),
+                Vec3::new(-h, -h, h),
+                Vec3::new(0.0, h, 0.0),
+            ],
+            ConvexBrushPreset::Prism => (0..6)
+                .flat_map(|i| {
+                    let angle = i as f32 * std::f32::consts::TAU / 6.0;
+                    let (x, z) = (angle.cos() * h, angle.sin() * h);
+                    [Vec3::new(x, -h, z), Vec3::new(x, h, z)]
+                })
+                .collect(),
+        };
+
+        hull_from_points(&points).expect("Convex brush presets are valid hulls")
+    }
+}
+
+/// Intersect half spaces into a convex solid. Every plane faces out of the brush
+/// Returns None if the planes do not enclose a volume
+pub fn hull_from_planes(planes: &[BrushPlane]) -> Option<(Vec<Vec3>, Vec<Vec<u32>>)> {
+    let mut unique_planes: Vec<BrushPlane> = Vec::new();
+    for plane in planes {
+        if plane.normal.length_squared() > EPSILON
+            && !unique_planes.iter().any(|other| other.approx_eq(plane))
+        {
+            unique_planes.push(*plane);
+        }
+    }
+    let planes = unique_planes;
+    if planes.len() < 4 {
+        return None;
+    }
+
+    // Every corner is where three planes meet and is inside all of the others
+    let mut vertices: Vec<Vec3> = Vec::new();
+    for i in 0..planes.len() {
+        for j in (i + 1)..planes.len() {
+            for k in (j + 1)..planes.len() {
+                let Some(point) = intersect_planes(&planes[i], &planes[j], &planes[k]) else {
+                    continue;
+                };
+                if planes
+                    .iter()
+                    .any(|plane| plane.signed_distance(point) > EPSILON)
+                {
+                    continue;
+                }
+                if !vertices
+                    .iter()
+                    .any(|vertex| vertex.distance_squared(point) < EPSILON * EPSILON)
+                {
+                    vertices.push(point);
+                }
+            }
+        }
+    }
+
+    let faces: Vec<Vec<u32>> = planes
+        .iter()
+        .filter_map(|plane| face_loop_on_plane(&vertices, plane))
+        .collect();
+
+    (faces.len() >= 4).then_some((vertices, faces))
+}
+
+/// Convex hull of a point cloud. Interior points are dropped
+pub fn hull_from_points(points: &[Vec3]) -> Option<(Vec<Vec3>, Vec<Vec<u32>>)> {
+    hull_from_planes(&planes_from_points(points))
+}
+
+/// Outward planes of the convex hull of a point cloud
+/// Brushes only have a handful of points so this brute forces candidate planes through every triangle
+pub fn planes_from_points(points: &[Vec3]) -> Vec<BrushPlane> {
+    let mut planes: Vec<BrushPlane> = Vec::new();
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            for k in (j + 1)..points.len() {
+                let (a, b, c) = (points[i], points[j], points[k]);
+                let normal = (b - a).cross(c - a);
+                if normal.length_squared() < EPSILON * EPSILON {
+                    continue;
+                }
+                let mut plane = BrushPlane::from_point(normal, a);
+
+                let mut in_front = false;
+                let mut behind = false;
+                for point in points {
+                    let distance = plane.signed_distance(*point);
+                    in_front |= distance > EPSILON;
+                    behind |= distance < -EPSILON;
+                }
+                // Either splits the points or everything is flat
+                if in_front == behind {
+                    continue;
+                }
+                if in_front {
+                    plane = plane.flipped();
+                }
+                if !planes.iter().any(|other| other.approx_eq(&plane)) {
+                    planes.push(plane);
+                }
+            }
+        }
+    }
+    planes
+}
+
+/// Flat shaded mesh of one or more convex solids, every face gets its own vertices so normals and UVs do not blend
+/// Faces are fanned from their first vertex and normals use the same helper as RectBrush
+pub fn mesh_from_solids(solids: &[(Vec<Vec3>, Vec<Vec<u32>>)], uv_scale: Vec2) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut uvs: Vec<[f32; 2]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    for (vertices, faces) in solids {
+        for face in faces {
+            let face_vertices: Vec<Vec3> = face
+                .iter()
+                .filter(|index| (**index as usize) < vertices.len())
+                .map(|index| vertices[*index as usize])
+                .collect();
+            if face_vertices.len() < 3 {
+                continue;
+            }
+
+            let (u_axis, v_axis) = face_uv_axes(newell_normal(&face_vertices));
+            let base = positions.len() as u32;
+            for vertex in &face_vertices {
+                positions.push(vertex.to_array());
+                uvs.push((Vec2::new(vertex.dot(u_axis), vertex.dot(v_axis)) * uv_scale).to_array());
+            }
+            for i in 1..(face_vertices.len() - 1) {
+                // Collapsed triangles would give NaN normals
+                let (a, b, c) = (face_vertices[0], face_vertices[i], face_vertices[i + 1]);
+                if (b - a).cross(c - a).length_squared() < EPSILON * EPSILON {
+                    continue;
+                }
+                indices.extend([base, base + i as u32, base + i as u32 + 1]);
+            }
+        }
+    }
+
+    let normals = RectBrush::calculate_normals(&positions, &indices);
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
+fn intersect_planes(a: &BrushPlane, b: &BrushPlane, c: &BrushPlane) -> Option<Vec3> {
+    let denominator = a.normal.dot(b.normal.cross(c.normal));
+    if denominator.abs() < EPSILON {
+        return None;
+    }
+    Some(
+        (b.normal.cross(c.normal) * a.distance
+            + c.normal.cross(a.normal) * b.distance
+            + a.normal.cross(b.normal) * c.distance)
+            / denominator,
+    )
+}
+
+/// Newell normal of a polygon, stays sensible for slightly bent faces
+fn newell_normal(polygon: &[Vec3]) -> Vec3 {
+    let mut normal = Vec3::ZERO;
+    for (i, current) in polygon.iter().enumerate() {
+        normal += current.cross(polygon[(i + 1) % polygon.len()]);
+    }
+    normal.normalize_or_zero()
+}
+
+/// Vertices lying on the plane, sorted counter clockwise when seen from outside
+fn face_loop_on_plane(vertices: &[Vec3], plane: &BrushPlane) -> Option<Vec<u32>> {
+    let mut face: Vec<u32> = (0..vertices.len() as u32)
+        .filter(|index| plane.signed_distance(vertices[*index as usize]).abs() < EPSILON)
+        .collect();
+    if face.len() < 3 {
+        return None;
+    }
+
+    let center = face
+        .iter()
+        .map(|index| vertices[*index as usize])
+        .sum::<Vec3>()
+        / face.len() as f32;
+    // u x v == normal, so increasing angle winds counter clockwise around the normal
+    let u = plane.normal.any_orthonormal_vector();
+    let v = plane.normal.cross(u);
+    let angle = |index: &u32| {
+        let offset = vertices[*index as usize] - center;
+        offset.dot(v).atan2(offset.dot(u))
+    };
+    face.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+    Some(face)
+}
+
+/// U and V directions for planar UV projection, picked from the dominant axis of the face normal
+/// Matches the default RectBrush face layout so both brush types tile the same way
+fn face_uv_axes(normal: Vec3) -> (Vec3, Vec3) {
+    let abs = normal.abs();
+    if abs.x >= abs.y && abs.x >= abs.z {
+        if normal.x > 0.0 {
+            (Vec3::NEG_Z, Vec3::NEG_Y)
+        } else {
+            (Vec3::Z, Vec3::NEG_Y)
+        }
+    } else if abs.y >= abs.z {
+        if normal.y > 0.0 {
+            (Vec3::X, Vec3::Z)
+        } else {
+            (Vec3::X, Vec3::NEG_Z)
+        }
+    } else {
+        (Vec3::X, Vec3::NEG_Y)
+    }
+}
+
+impl ConvexBrush {
+    /// Build from half spaces. Planes face out of the brush
+    pub fn from_planes(planes: &[BrushPlane]) -> Option<ConvexBrush> {
+        let (vertices, faces) = hull_from_planes(planes)?;
+        Some(ConvexBrush {
+            vertices,
+            faces,
+            ..Default::default()
+        })
+    }
+
+    /// Build from the convex hull of a set of points
+    pub fn from_points(points: &[Vec3]) -> Option<ConvexBrush> {
+        let (vertices, faces) = hull_from_points(points)?;
+        Some(ConvexBrush {
+            vertices,
+            faces,
+            ..Default::default()
+        })
+    }
+
+    pub fn apply_preset(&mut self, preset: ConvexBrushPreset) {
+        (self.vertices, self.faces) = preset.geometry();
+    }
+
+    /// Recompute faces from the current vertices, dropping any that ended up inside the hull
+    /// Vertex indices may change, returns false and leaves the brush alone if there is no volume
+    pub fn rebuild_hull(&mut self) -> bool {
+        match hull_from_points(&self.vertices) {
+            Some((vertices, faces)) => {
+                self.vertices = vertices;
+                self.faces = faces;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Enough faces to close a volume and every index points at a vertex
+    pub fn is_valid(&self) -> bool {
+        self.faces.len() >= 4
+            && self.faces.iter().all(|face| {
+                face.len() >= 3
+                    && face
+                        .iter()
+                        .all(|index| (*index as usize) < self.vertices.len())
+            })
+    }
+
+    /// Every face is flat and no vertex pokes out through another face
+    pub fn is_convex(&self) -> bool {
+        if !self.is_valid() {
+            return false;
+        }
+        self.planes()
+            .iter()
+            .zip(self.faces.iter())
+            .all(|(plane, face)| {
+                let flat = face.iter().all(|index| {
+                    plane.signed_distance(self.vertices[*index as usize]).abs() < CONVEX_TOLERANCE
+                });
+                flat && self
+                    .vertices
+                    .iter()
+                    .all(|vertex| plane.signed_distance(*vertex) < CONVEX_TOLERANCE)
+            })
+    }
+
+    /// Outward plane of every face, in face order
+    pub fn planes(&self) -> Vec<BrushPlane> {
+        (0..self.faces.len())
+            .map(|face| BrushPlane::from_point(self.face_normal(face), self.face_center(face)))
+            .collect()
+    }
+
+    pub fn face_normal(&self, face: usize) -> Vec3 {
+        let Some(indices) = self.faces.get(face) else {
+            return Vec3::ZERO;
+        };
+        let polygon: Vec<Vec3> = indices.iter().map(|index| self.vertex(*index)).collect();
+        newell_normal(&polygon)
+    }
+
+    pub fn face_center(&self, face: usize) -> Vec3 {
+        self.element_center(ConvexBrushElement::Face(face))
+    }
+
+    /// Unique edges, lowest vertex index first
+    pub fn edges(&self) -> Vec<(u32, u32)> {
+        let mut edges = BTreeSet::new();
+        for face in &self.faces {
+            for (i, a) in face.iter().enumerate() {
+                let (a, b) = (*a, face[(i + 1) % face.len()]);
+                edges.insert((a.min(b), a.max(b)));
+            }
+        }
+        edges.into_iter().collect()
+    }
+
+    /// Every element of the given kind, in a stable order
+    pub fn elements(&self, kind: ConvexBrushElementKind) -> Vec<ConvexBrushElement> {
+        match kind {
+            ConvexBrushElementKind::Vertex => (0..self.vertices.len() as u32)
+                .map(ConvexBrushElement::Vertex)
+                .collect(),
+            ConvexBrushElementKind::Edge => self
+                .edges()
+                .into_iter()
+                .map(|(a, b)| ConvexBrushElement::Edge(a, b))
+                .collect(),
+            ConvexBrushElementKind::Face => (0..self.faces.len())
+                .map(ConvexBrushElement::Face)
+                .collect(),
+        }
+    }
+
+    pub fn element_vertex_indices(&self, element: ConvexBrushElement) -> Vec<u32> {
+        let indices = match element {
+            ConvexBrushElement::Vertex(index) => vec![index],
+            ConvexBrushElement::Edge(a, b) => vec![a, b],
+            ConvexBrushElement::Face(face) => self.faces.get(face).cloned().unwrap_or_default(),
+        };
+        indices
+            .into_iter()
+            .filter(|index| (*index as usize) < self.vertices.len())
+            .collect()
+    }
+
+    /// Brush space center of a vertex, edge or face
+    pub fn element_center(&self, element: ConvexBrushElement) -> Vec3 {
+        let indices = self.element_vertex_indices(element);
+        if indices.is_empty() {
+            return Vec3::ZERO;
+        }
+        indices
+            .iter()
+            .map(|index| self.vertex(*index))
+            .sum::<Vec3>()
+            / indices.len() as f32
+    }
+
+    /// Move every vertex of the element by a brush space offset
+    pub fn translate_element(&mut self, element: ConvexBrushElement, delta: Vec3) {
+        for index in self.element_vertex_indices(element) {
+            self.vertices[index as usize] += delta;
+        }
+    }
+
+    fn vertex(&self, index: u32) -> Vec3 {
+        self.vertices
+            .get(index as usize)
+            .copied()
+            .unwrap_or(Vec3::ZERO)
+    }
+
+    /// Flat shaded mesh of the brush, see mesh_from_solids
+    pub fn create_mesh(&self) -> Mesh {
+        mesh_from_solids(
+            &[(self.vertices.clone(), self.faces.clone())],
+            self.uv_scale,
+        )
+    }
+}
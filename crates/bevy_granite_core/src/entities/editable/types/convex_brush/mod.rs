@@ -0,0 +1,170 @@
+use crate::{
+    assets::EditableMaterial,
+    entities::{
+        editable::{
+            GraniteType, RequestEntityUpdateFromClass, RequiredMaterialData,
+            RequiredMaterialDataMut,
+        },
+        CsgOperation, EntitySaveReadyData, PromptData,
+    },
+    AvailableEditableMaterials, ClassCategory, MaterialData,
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        entity::Entity,
+        event::Event,
+        system::{Commands, Res, ResMut},
+    },
+    math::{Vec2, Vec3},
+    pbr::StandardMaterial,
+    prelude::Reflect,
+    render::mesh::Mesh,
+    transform::components::Transform,
+};
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+pub mod creation;
+pub mod geometry;
+pub mod plugin;
+pub mod ui;
+pub mod update_event;
+
+pub use geometry::*;
+pub use plugin::*;
+pub use update_event::*;
+
+/// Internal event thats called when user edits UI ConvexBrush variables
+#[derive(Event)]
+pub struct UserUpdatedConvexBrushEvent {
+    pub entity: Entity,
+    pub data: ConvexBrush,
+}
+
+/// Actual serialized class data thats stored inside IdentityData
+/// A convex solid stored as shared vertex positions and faces. Each face is a loop of vertex indices,
+/// counter clockwise when looking at it from outside. Brushes can also be built from planes, see ConvexBrush::from_planes
+/// UVs are planar projected per face from brush space, uv_scale is in UV repeats per unit
+/// Setting csg makes this brush an operand of its parent brush, see entities::csg
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct ConvexBrush {
+    pub vertices: Vec<Vec3>,
+    pub faces: Vec<Vec<u32>>,
+    pub uv_scale: Vec2,
+    pub material: MaterialData,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csg: Option<CsgOperation>,
+}
+
+impl ConvexBrush {
+    pub fn type_name_static() -> String {
+        "Convex Brush".to_string()
+    }
+
+    pub fn type_abv_static() -> String {
+        "Convex".to_string()
+    }
+
+    pub fn internal_material_path() -> String {
+        "materials/internal/convex_brush.mat".to_string()
+    }
+}
+
+impl Default for ConvexBrush {
+    fn default() -> Self {
+        let (path, name) = (Self::internal_material_path(), Self::type_name_static());
+
+        // Create a material with the internal defaults for convex brushes
+        let mut brush_material = EditableMaterial::get_new_unnamed_base_color();
+        brush_material.update_name(name.clone());
+        brush_material.update_path(path.clone());
+
+        let (vertices, faces) = ConvexBrushPreset::Cube.geometry();
+        Self {
+            vertices,
+            faces,
+            uv_scale: Vec2::ONE,
+            material: MaterialData {
+                path: path.clone(),
+                current: brush_material.clone(),
+                last: brush_material.clone(),
+            },
+            csg: None,
+        }
+    }
+}
+
+impl GraniteType for ConvexBrush {
+    fn category(&self) -> ClassCategory {
+        ClassCategory::Mesh
+    }
+
+    fn type_name(&self) -> String {
+        ConvexBrush::type_name_static()
+    }
+
+    fn type_abv(&self) -> String {
+        ConvexBrush::type_abv_static()
+    }
+
+    fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        mut standard_materials: ResMut<Assets<StandardMaterial>>,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut available_materials: ResMut<AvailableEditableMaterials>,
+        asset_server: Res<AssetServer>,
+        _maybe_prompt_data: Option<PromptData>,
+    ) -> Entity {
+        self.spawn_from_new_identity(
+            commands,
+            transform,
+            &mut standard_materials,
+            &mut available_materials,
+            &asset_server,
+            &mut meshes,
+        )
+    }
+
+    fn spawn_from_save_data(
+        &self,
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+    ) -> Entity {
+        ConvexBrush::spawn_from_save_data(
+            save_data,
+            commands,
+            standard_materials,
+            available_materials,
+            asset_server,
+            meshes,
+        )
+    }
+
+    fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
+        self.push_to_entity(entity, request_update)
+    }
+
+    fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        self.edit_via_ui(ui, spacing)
+    }
+
+    fn get_material_data(&self) -> Option<RequiredMaterialData<'_>> {
+        Some(self.material.as_ref())
+    }
+
+    fn get_mut_material_data(&mut self) -> Option<RequiredMaterialDataMut<'_>> {
+        Some(self.material.as_mut())
+    }
+
+    fn needs_unique_handle(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,27 @@
+use crate::ConvexBrush;
+
+use super::{
+    update_convex_brush_system, BrushPlane, ConvexBrushPreset, UserUpdatedConvexBrushEvent,
+};
+use bevy::app::{App, Plugin, Update};
+
+pub struct ConvexBrushPlugin;
+impl Plugin for ConvexBrushPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            //
+            // Event
+            //
+            .add_event::<UserUpdatedConvexBrushEvent>()
+            //
+            // Register
+            //
+            .register_type::<ConvexBrush>()
+            .register_type::<ConvexBrushPreset>()
+            .register_type::<BrushPlane>()
+            //
+            // Schedule system
+            //
+            .add_systems(Update, update_convex_brush_system);
+    }
+}
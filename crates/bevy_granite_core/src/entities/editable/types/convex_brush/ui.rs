@@ -0,0 +1,114 @@
+use super::{ConvexBrush, ConvexBrushPreset};
+use crate::entities::csg::edit_csg_operation;
+use bevy::math::Vec2;
+use bevy_egui::egui;
+
+impl ConvexBrush {
+    /// Function to edit self's data via UI side panel
+    /// We have a sister system that pushes changes to world entity - can be found inside 'update_event.rs'
+    /// When true, sends an update to propagate these vars to the world's entity
+    pub fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        let brush_data = self;
+        let small_spacing = spacing.0;
+        let large_spacing = spacing.1;
+        let mut changed = false;
+
+        ui.label(egui::RichText::new("Convex Brush Data").italics());
+        ui.add_space(large_spacing);
+
+        egui::Grid::new("convex_brush_data_grid")
+            .num_columns(2)
+            .spacing([large_spacing, large_spacing])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Shape:");
+                egui::ComboBox::from_id_salt("convex_brush_preset_dropdown")
+                    .selected_text("Reset to...")
+                    .show_ui(ui, |ui| {
+                        for preset in ConvexBrushPreset::all() {
+                            if ui.selectable_label(false, preset.name()).clicked() {
+                                brush_data.apply_preset(preset);
+                                changed = true;
+                            }
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Elements:");
+                ui.label(format!(
+                    "{} vertices, {} edges, {} faces",
+                    brush_data.vertices.len(),
+                    brush_data.edges().len(),
+                    brush_data.faces.len()
+                ))
+                .on_hover_text("Press Tab in the viewport to drag vertices, edges and faces");
+                ui.end_row();
+
+                ui.label("UV:");
+                ui.horizontal(|ui| {
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut brush_data.uv_scale.x)
+                                .speed(0.01)
+                                .fixed_decimals(2),
+                        )
+                        .changed();
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut brush_data.uv_scale.y)
+                                .speed(0.01)
+                                .fixed_decimals(2),
+                        )
+                        .changed();
+                    if ui.button("Reset").clicked() {
+                        brush_data.uv_scale = Vec2::ONE;
+                        changed = true;
+                    }
+                });
+                ui.end_row();
+
+                ui.label("CSG:");
+                changed |= edit_csg_operation(ui, &mut brush_data.csg, "convex_brush_csg_dropdown");
+                ui.end_row();
+            });
+
+        if !brush_data.is_convex() {
+            ui.add_space(small_spacing);
+            ui.horizontal(|ui| {
+                ui.colored_label(egui::Color32::YELLOW, "Brush is no longer convex");
+                if ui
+                    .button("Rebuild Hull")
+                    .on_hover_text(
+                        "Wrap the vertices in a new convex hull, inner vertices are removed",
+                    )
+                    .clicked()
+                {
+                    changed |= brush_data.rebuild_hull();
+                }
+            });
+        }
+
+        ui.add_space(large_spacing);
+
+        ui.collapsing("Vertices", |ui| {
+            egui::Grid::new("convex_brush_vertices_grid")
+                .num_columns(2)
+                .spacing([large_spacing, small_spacing])
+                .show(ui, |ui| {
+                    for (index, vertex) in brush_data.vertices.iter_mut().enumerate() {
+                        ui.label(format!("{}:", index));
+                        ui.horizontal(|ui| {
+                            for value in [&mut vertex.x, &mut vertex.y, &mut vertex.z] {
+                                changed |= ui
+                                    .add(egui::DragValue::new(value).speed(0.01).fixed_decimals(2))
+                                    .changed();
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+
+        changed
+    }
+}
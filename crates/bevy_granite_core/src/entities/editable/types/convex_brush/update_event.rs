@@ -0,0 +1,99 @@
+use crate::{entities::editable::RequestEntityUpdateFromClass, NeedsTangents};
+
+use super::{ConvexBrush, UserUpdatedConvexBrushEvent};
+use bevy::{
+    asset::Assets,
+    ecs::{
+        event::EventReader,
+        system::{Commands, Query, ResMut},
+    },
+    prelude::Entity,
+    render::{
+        mesh::{Mesh, Mesh3d, MeshAabb},
+        primitives::Aabb,
+    },
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+impl ConvexBrush {
+    pub fn push_to_entity(
+        &self,
+        brush_e: Entity,
+        request_update: &mut RequestEntityUpdateFromClass,
+    ) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Requesting Convex Brush update"
+        );
+        request_update
+            .convex_brush
+            .write(UserUpdatedConvexBrushEvent {
+                entity: brush_e,
+                data: self.clone(),
+            });
+    }
+}
+
+/// Regenerates the brush mesh in place. The handle is unique per entity so nothing else is affected
+pub fn update_convex_brush_system(
+    mut commands: Commands,
+    mut reader: EventReader<UserUpdatedConvexBrushEvent>,
+    query: Query<&Mesh3d>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut aabbs: Query<&mut Aabb>,
+) {
+    for UserUpdatedConvexBrushEvent {
+        entity: requested_entity,
+        data: new,
+    } in reader.read()
+    {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Heard convex brush update event: {}",
+            requested_entity
+        );
+        let Ok(mesh_handle) = query.get(*requested_entity) else {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Entity,
+                "Could not find convex brush entity {}",
+                requested_entity
+            );
+            continue;
+        };
+        let Some(mesh) = meshes.get_mut(mesh_handle) else {
+            continue;
+        };
+
+        *mesh = new.create_mesh();
+
+        if let Some(new_mesh_aabb) = mesh.compute_aabb() {
+            if let Ok(mut entity_aabb) = aabbs.get_mut(*requested_entity) {
+                *entity_aabb = new_mesh_aabb;
+            }
+        } else {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Entity,
+                "Failed to compute AABB for updated convex brush mesh"
+            );
+        }
+
+        // Replacing the mesh drops any generated tangents
+        commands.entity(*requested_entity).insert(NeedsTangents);
+
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Updated convex brush mesh for entity {}",
+            requested_entity
+        );
+    }
+}
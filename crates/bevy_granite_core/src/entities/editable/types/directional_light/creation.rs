@@ -55,6 +55,7 @@ impl DirLight {
         let mut entity =
             commands.spawn(Self::get_bundle(self.clone(), identity.clone(), transform));
 
+        entity.insert(self.cascades.to_bevy());
         if self.volumetric {
             entity.insert(VolumetricLight);
         }
@@ -73,6 +74,10 @@ impl DirLight {
                 color: Color::linear_rgb(dir_light.color.0, dir_light.color.1, dir_light.color.2),
                 illuminance: dir_light.illuminance,
                 shadows_enabled: dir_light.shadows_enabled,
+                shadow_depth_bias: dir_light.shadow_depth_bias,
+                shadow_normal_bias: dir_light.shadow_normal_bias,
+                #[cfg(feature = "experimental_pbr_pcss")]
+                soft_shadow_size: dir_light.soft_shadow_size,
                 ..Default::default()
             },
             Name::new(identity.name.clone()),
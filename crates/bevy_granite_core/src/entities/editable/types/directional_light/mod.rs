@@ -5,7 +5,7 @@ use bevy::{
         event::Event,
         system::{Commands, Res, ResMut},
     },
-    pbr::StandardMaterial,
+    pbr::{DirectionalLight, StandardMaterial},
     prelude::Reflect,
     render::mesh::Mesh,
     transform::components::Transform,
@@ -36,13 +36,21 @@ pub struct UserUpdatedDirectionalLightEvent {
 
 /// Actual serialized class data thats stored inside IdentityData
 /// Some actual Bevy Directional light data, some custom flags for easy inserting of additional data  
+/// Missing fields fall back to default so older scenes still load
 #[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
 pub struct DirLight {
     pub color: (f32, f32, f32),
     pub illuminance: f32,
     pub shadows_enabled: bool,
     // Flag that gets used to insert VolumetricLight
     pub volumetric: bool,
+    pub shadow_depth_bias: f32,
+    pub shadow_normal_bias: f32,
+    // Only applied with the "experimental_pbr_pcss" feature
+    pub soft_shadow_size: Option<f32>,
+    // Used to build and insert CascadeShadowConfig
+    pub cascades: ShadowCascades,
 }
 impl Default for DirLight {
     fn default() -> Self {
@@ -51,6 +59,28 @@ impl Default for DirLight {
             illuminance: 32_000.0,
             shadows_enabled: true,
             volumetric: false,
+            shadow_depth_bias: DirectionalLight::DEFAULT_SHADOW_DEPTH_BIAS,
+            shadow_normal_bias: DirectionalLight::DEFAULT_SHADOW_NORMAL_BIAS,
+            soft_shadow_size: None,
+            cascades: ShadowCascades::default(),
+        }
+    }
+}
+
+/// Serializable wrapper for the parts of CascadeShadowConfigBuilder we expose
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct ShadowCascades {
+    pub count: usize,
+    pub first_cascade_far_bound: f32,
+    pub max_distance: f32,
+}
+impl Default for ShadowCascades {
+    fn default() -> Self {
+        Self {
+            count: 4,
+            first_cascade_far_bound: 5.0,
+            max_distance: 150.0,
         }
     }
 }
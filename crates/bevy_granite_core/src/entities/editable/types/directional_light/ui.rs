@@ -61,13 +61,108 @@ impl DirLight {
                     changed |= ui.checkbox(&mut data.shadows_enabled, "").changed();
                     ui.end_row();
 
+                    if data.shadows_enabled {
+                        changed |= shadow_settings_ui(ui, data);
+                    }
+
                     ui.label("Volumetric Fog:");
                     changed |= ui
                         .checkbox(&mut data.volumetric, "(Settings on Camera)")
                         .changed();
                     ui.end_row();
                 });
+
+            if data.shadows_enabled {
+                ui.add_space(large_spacing);
+                ui.collapsing("Shadow Cascades", |ui| {
+                    egui::Grid::new("directional_light_cascade_grid")
+                        .num_columns(2)
+                        .spacing([large_spacing, large_spacing])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            let cascades = &mut data.cascades;
+
+                            ui.label("Cascade Count:");
+                            changed |= ui
+                                .add(egui::DragValue::new(&mut cascades.count).range(1..=4))
+                                .changed();
+                            ui.end_row();
+
+                            ui.label("First Cascade Bound:");
+                            changed |= ui
+                                .add(
+                                    egui::DragValue::new(&mut cascades.first_cascade_far_bound)
+                                        .range(0.1..=1000.0)
+                                        .speed(0.1),
+                                )
+                                .on_hover_text("Far bound of the first (highest detail) cascade")
+                                .changed();
+                            ui.end_row();
+
+                            ui.label("Max Distance:");
+                            changed |= ui
+                                .add(
+                                    egui::DragValue::new(&mut cascades.max_distance)
+                                        .range(0.1..=5000.0)
+                                        .speed(1.0),
+                                )
+                                .on_hover_text("Shadows are not drawn past this distance")
+                                .changed();
+                            ui.end_row();
+                        });
+                });
+            }
         });
         changed
     }
 }
+
+fn shadow_settings_ui(ui: &mut egui::Ui, data: &mut DirLight) -> bool {
+    let mut changed = false;
+
+    ui.label("Shadow Depth Bias:");
+    changed |= ui
+        .add(
+            egui::DragValue::new(&mut data.shadow_depth_bias)
+                .range(0.0..=1.0)
+                .speed(0.001),
+        )
+        .changed();
+    ui.end_row();
+
+    ui.label("Shadow Normal Bias:");
+    changed |= ui
+        .add(
+            egui::DragValue::new(&mut data.shadow_normal_bias)
+                .range(0.0..=10.0)
+                .speed(0.01),
+        )
+        .changed();
+    ui.end_row();
+
+    ui.label("Soft Shadows:");
+    let mut soft_shadows = data.soft_shadow_size.is_some();
+    ui.horizontal(|ui| {
+        if ui
+            .checkbox(&mut soft_shadows, "")
+            .on_hover_text("Requires the 'experimental_pbr_pcss' feature")
+            .changed()
+        {
+            data.soft_shadow_size = soft_shadows.then_some(1.0);
+            changed = true;
+        }
+        if let Some(size) = &mut data.soft_shadow_size {
+            changed |= ui
+                .add(
+                    egui::DragValue::new(size)
+                        .range(0.0..=50.0)
+                        .speed(0.05)
+                        .prefix("size: "),
+                )
+                .changed();
+        }
+    });
+    ui.end_row();
+
+    changed
+}
@@ -1,3 +1,4 @@
+use super::ShadowCascades;
 use crate::{
     entities::editable::{RequestEntityUpdateFromClass, UserUpdatedDirectionalLightEvent},
     DirLight,
@@ -9,7 +10,7 @@ use bevy::{
         event::EventReader,
         system::{Commands, Query},
     },
-    pbr::{DirectionalLight, VolumetricLight},
+    pbr::{CascadeShadowConfig, CascadeShadowConfigBuilder, DirectionalLight, VolumetricLight},
 };
 use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
 
@@ -36,6 +37,23 @@ impl DirLight {
     }
 }
 
+impl ShadowCascades {
+    // Bevy asserts on bad cascade bounds, so keep them sane before building
+    pub fn to_bevy(&self) -> CascadeShadowConfig {
+        let builder = CascadeShadowConfigBuilder::default();
+        let first_cascade_far_bound = self
+            .first_cascade_far_bound
+            .max(builder.minimum_distance + 0.01);
+        CascadeShadowConfigBuilder {
+            num_cascades: self.count.clamp(1, 4),
+            first_cascade_far_bound,
+            maximum_distance: self.max_distance.max(first_cascade_far_bound + 0.01),
+            ..builder
+        }
+        .build()
+    }
+}
+
 /// Actually update the specific entity with the class data
 /// In the future im sure we will have FOV and what not
 pub fn update_directional_light_system(
@@ -60,6 +78,13 @@ pub fn update_directional_light_system(
             directional_light.illuminance = new.illuminance;
             directional_light.color = Color::linear_rgb(new.color.0, new.color.1, new.color.2);
             directional_light.shadows_enabled = new.shadows_enabled;
+            directional_light.shadow_depth_bias = new.shadow_depth_bias;
+            directional_light.shadow_normal_bias = new.shadow_normal_bias;
+            #[cfg(feature = "experimental_pbr_pcss")]
+            {
+                directional_light.soft_shadow_size = new.soft_shadow_size;
+            }
+            commands.entity(entity).insert(new.cascades.to_bevy());
             if new.volumetric {
                 commands.entity(entity).insert(VolumetricLight);
             } else {
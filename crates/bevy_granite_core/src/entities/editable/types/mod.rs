@@ -1,21 +1,47 @@
 pub mod camera_3d;
+pub mod convex_brush;
 pub mod directional_light;
 pub mod empty;
 pub mod obj;
 pub mod point_light;
+pub mod primitive;
 pub mod unknown;
 pub mod rect_brush;
+pub mod spline;
+pub mod spot_light;
+pub mod volume;
 
 pub mod plugin;
 
 // Re-exports
 // Class Types
-pub use camera_3d::{Camera3D, Camera3DPlugin, UserUpdatedCamera3DEvent, VolumetricFog};
-pub use directional_light::{DirLight, DirLightPlugin, UserUpdatedDirectionalLightEvent};
+pub use camera_3d::{
+    Camera3D, Camera3DPlugin, CameraBloom, CameraDepthOfField, CameraDistanceFog,
+    CameraEnvironment, CameraProjection, CameraSsao, CameraTonemapping, FogFalloffMode,
+    ProjectionMode, SsaoQuality, UserUpdatedCamera3DEvent, VolumetricFog,
+};
+pub use convex_brush::{
+    BrushPlane, ConvexBrush, ConvexBrushElement, ConvexBrushElementKind, ConvexBrushPlugin,
+    ConvexBrushPreset, UserUpdatedConvexBrushEvent,
+};
+pub use directional_light::{
+    DirLight, DirLightPlugin, ShadowCascades, UserUpdatedDirectionalLightEvent,
+};
 pub use empty::{Empty, EmptyPlugin, UserUpdatedEmptyEvent};
 pub use obj::{OBJPlugin, UserUpdatedOBJEvent, OBJ};
 pub use point_light::{PointLightData, PointLightPlugin, UserUpdatedPointLightEvent};
+pub use primitive::{Primitive, PrimitivePlugin, PrimitiveShape, UserUpdatedPrimitiveEvent};
 pub use unknown::Unknown;
-pub use rect_brush::{UserUpdatedRectBrushEvent, RectBrush, RectBrushPlugin};
+pub use rect_brush::{
+    BrushFace, RectBrush, RectBrushFaceMesh, RectBrushPlugin, UserUpdatedRectBrushEvent,
+};
+pub use spline::{
+    Spline, SplineMode, SplinePlugin, SplinePoint, SplineSample, UserUpdatedSplineEvent,
+};
+pub use spot_light::{SpotLightData, SpotLightPlugin, UserUpdatedSpotLightEvent};
+pub use volume::{
+    UserUpdatedVolumeEvent, Volume, VolumeActivator, VolumeEntered, VolumeExited,
+    VolumeOverlapState, VolumePlugin, VolumeShape,
+};
 
 pub use plugin::ClassTypePlugin;
\ No newline at end of file
@@ -9,7 +9,12 @@ impl Plugin for ClassTypePlugin {
             .add_plugins(Camera3DPlugin)
             .add_plugins(DirLightPlugin)
             .add_plugins(PointLightPlugin)
+            .add_plugins(SpotLightPlugin)
             .add_plugins(RectBrushPlugin)
+            .add_plugins(ConvexBrushPlugin)
+            .add_plugins(PrimitivePlugin)
+            .add_plugins(SplinePlugin)
+            .add_plugins(VolumePlugin)
             .add_plugins(EmptyPlugin)
             .add_plugins(OBJPlugin);
     }
@@ -73,7 +73,12 @@ impl PointLightData {
                     point_light.color.2,
                 ),
                 range: point_light.range,
+                radius: point_light.radius,
                 shadows_enabled: point_light.shadows_enabled,
+                shadow_depth_bias: point_light.shadow_depth_bias,
+                shadow_normal_bias: point_light.shadow_normal_bias,
+                #[cfg(feature = "experimental_pbr_pcss")]
+                soft_shadows_enabled: point_light.soft_shadows_enabled,
                 ..Default::default()
             },
             Name::new(identity.name.clone()),
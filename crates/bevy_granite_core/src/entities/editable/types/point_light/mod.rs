@@ -11,7 +11,7 @@ use bevy::{
         event::Event,
         system::{Commands, Res, ResMut},
     },
-    pbr::StandardMaterial,
+    pbr::{PointLight, StandardMaterial},
     prelude::Reflect,
     render::mesh::Mesh,
     transform::components::Transform,
@@ -36,12 +36,19 @@ pub struct UserUpdatedPointLightEvent {
 
 /// Actual serialized class data thats stored inside IdentityData
 /// In this case, all the vars are direct references for PointLight Bevy data
+/// Missing fields fall back to default so older scenes still load
 #[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
 pub struct PointLightData {
     pub intensity: f32,
     pub range: f32,
     pub shadows_enabled: bool,
     pub color: (f32, f32, f32),
+    pub radius: f32,
+    pub shadow_depth_bias: f32,
+    pub shadow_normal_bias: f32,
+    // Penumbra size comes from radius. Only applied with the "experimental_pbr_pcss" feature
+    pub soft_shadows_enabled: bool,
 }
 impl Default for PointLightData {
     fn default() -> Self {
@@ -50,6 +57,10 @@ impl Default for PointLightData {
             range: 10.0,
             shadows_enabled: true,
             color: (1., 1., 1.),
+            radius: 0.0,
+            shadow_depth_bias: PointLight::DEFAULT_SHADOW_DEPTH_BIAS,
+            shadow_normal_bias: PointLight::DEFAULT_SHADOW_NORMAL_BIAS,
+            soft_shadows_enabled: false,
         }
     }
 }
@@ -58,11 +58,60 @@ impl PointLightData {
                         )
                         .changed();
                     ui.end_row();
+
+                    ui.label("Radius:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut data.radius)
+                                .range(0.0..=10.0)
+                                .speed(0.01),
+                        )
+                        .on_hover_text("Light source size. Also drives the soft shadow penumbra")
+                        .changed();
+                    ui.end_row();
+
                     ui.label("Shadows Enabled:");
                     changed |= ui.checkbox(&mut data.shadows_enabled, "").changed();
                     ui.end_row();
+
+                    if data.shadows_enabled {
+                        changed |= shadow_settings_ui(ui, data);
+                    }
                 });
         });
         changed
     }
 }
+
+fn shadow_settings_ui(ui: &mut egui::Ui, data: &mut PointLightData) -> bool {
+    let mut changed = false;
+
+    ui.label("Shadow Depth Bias:");
+    changed |= ui
+        .add(
+            egui::DragValue::new(&mut data.shadow_depth_bias)
+                .range(0.0..=1.0)
+                .speed(0.001),
+        )
+        .changed();
+    ui.end_row();
+
+    ui.label("Shadow Normal Bias:");
+    changed |= ui
+        .add(
+            egui::DragValue::new(&mut data.shadow_normal_bias)
+                .range(0.0..=10.0)
+                .speed(0.01),
+        )
+        .changed();
+    ui.end_row();
+
+    ui.label("Soft Shadows:");
+    changed |= ui
+        .checkbox(&mut data.soft_shadows_enabled, "")
+        .on_hover_text("Uses radius as penumbra size. Requires the 'experimental_pbr_pcss' feature")
+        .changed();
+    ui.end_row();
+
+    changed
+}
@@ -52,7 +52,14 @@ pub fn update_point_light_system(
             point_light.intensity = new.intensity;
             point_light.color = Color::linear_rgb(new.color.0, new.color.1, new.color.2);
             point_light.range = new.range;
+            point_light.radius = new.radius;
             point_light.shadows_enabled = new.shadows_enabled;
+            point_light.shadow_depth_bias = new.shadow_depth_bias;
+            point_light.shadow_normal_bias = new.shadow_normal_bias;
+            #[cfg(feature = "experimental_pbr_pcss")]
+            {
+                point_light.soft_shadows_enabled = new.soft_shadows_enabled;
+            }
         } else {
             log!(
                 LogType::Editor,
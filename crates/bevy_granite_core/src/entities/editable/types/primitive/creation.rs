@@ -0,0 +1,222 @@
+use super::{Primitive, PrimitiveShape};
+use crate::{
+    entities::EntitySaveReadyData, AvailableEditableMaterials, GraniteEditorSerdeEntity,
+    GraniteType, GraniteTypes, HasRuntimeData, IdentityData, NeedsTangents,
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        bundle::Bundle,
+        entity::Entity,
+        system::{Commands, Res, ResMut},
+    },
+    math::primitives::{Capsule3d, Cone, Cylinder, Plane3d, Sphere, Torus},
+    pbr::{MeshMaterial3d, StandardMaterial},
+    prelude::Name,
+    render::mesh::{Mesh, Mesh3d, MeshBuilder, Meshable, VertexAttributeValues},
+    transform::components::Transform,
+};
+use uuid::Uuid;
+
+// Keep generated meshes valid no matter what gets typed into the UI
+const MIN_EXTENT: f32 = 0.001;
+const MIN_SEGMENTS: u32 = 3;
+const MIN_RINGS: u32 = 2;
+
+impl Primitive {
+    /// Extract needed info to spawn this entity via save data
+    pub fn spawn_from_save_data(
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> Entity {
+        let identity = &save_data.identity;
+        let save_transform = &save_data.transform;
+
+        Self::spawn_from_identity(
+            commands,
+            identity,
+            save_transform.to_bevy(),
+            standard_materials,
+            available_materials,
+            asset_server,
+            meshes,
+        )
+    }
+
+    /// Take the name and class from identity to spawn
+    pub fn spawn_from_identity(
+        commands: &mut Commands,
+        identity: &IdentityData,
+        transform: Transform,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> Entity {
+        let mut class = Self::extract_class(identity);
+
+        // Load and configure the material
+        class.load_and_configure_material(available_materials, standard_materials, asset_server);
+
+        commands
+            .spawn(Self::get_bundle(class, identity.clone(), transform, meshes))
+            .id()
+    }
+
+    /// Generally to be used from UI popups as it gives default name
+    pub fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> Entity {
+        let identity = IdentityData {
+            name: self.shape.name().to_string(),
+            uuid: Uuid::new_v4(),
+            class: GraniteTypes::Primitive(self.clone()),
+        };
+        Self::spawn_from_identity(
+            commands,
+            &identity,
+            transform,
+            standard_materials,
+            available_materials,
+            asset_server,
+            meshes,
+        )
+    }
+
+    /// Build a bundle that is ready to spawn from a primitive
+    fn get_bundle(
+        primitive: Primitive,
+        identity: IdentityData,
+        transform: Transform,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> impl Bundle {
+        // Every primitive owns its mesh so edits only affect this entity
+        let mesh_handle = meshes.add(primitive.create_mesh());
+
+        (
+            transform,
+            Mesh3d(mesh_handle),
+            MeshMaterial3d(
+                primitive
+                    .material
+                    .current
+                    .handle
+                    .clone()
+                    .expect("Default material should always have a handle"),
+            ),
+            Name::new(identity.name.clone()),
+            HasRuntimeData,
+            GraniteEditorSerdeEntity,
+            NeedsTangents,
+            IdentityData {
+                name: identity.name.clone(),
+                uuid: identity.uuid,
+                class: GraniteTypes::Primitive(primitive.clone()),
+            },
+        )
+    }
+
+    fn extract_class(identity: &IdentityData) -> Primitive {
+        match &identity.class {
+            GraniteTypes::Primitive(primitive_data) => primitive_data.clone(),
+            _ => panic!("Expected Primitive class data, got different type from save data"),
+        }
+    }
+
+    /// Generate the mesh for the current shape and parameters
+    /// Shapes are centered on the origin, Y up
+    pub fn create_mesh(&self) -> Mesh {
+        let radius = self.radius.max(MIN_EXTENT);
+        let height = self.height.max(MIN_EXTENT);
+        let segments = self.segments.max(MIN_SEGMENTS);
+        let rings = self.rings.max(MIN_RINGS);
+
+        let mut mesh = match self.shape {
+            PrimitiveShape::Sphere => Sphere::new(radius).mesh().uv(segments, rings),
+            PrimitiveShape::Cylinder => Cylinder::new(radius, height)
+                .mesh()
+                .resolution(segments)
+                .build(),
+            PrimitiveShape::Capsule => {
+                // Height is the total height, Capsule3d wants the length of the middle section
+                let length = (height - radius * 2.0).max(0.0);
+                Capsule3d::new(radius, length)
+                    .mesh()
+                    .longitudes(segments)
+                    .latitudes(rings)
+                    .build()
+            }
+            PrimitiveShape::Plane => Plane3d::default()
+                .mesh()
+                .size(self.size.x.max(MIN_EXTENT), self.size.y.max(MIN_EXTENT))
+                .build(),
+            PrimitiveShape::Cone => Cone { radius, height }.mesh().resolution(segments).build(),
+            PrimitiveShape::Torus => Torus {
+                minor_radius: self.minor_radius.max(MIN_EXTENT),
+                major_radius: radius,
+            }
+            .mesh()
+            .major_resolution(segments as usize)
+            .minor_resolution(rings as usize)
+            .build(),
+        };
+
+        if let Some(VertexAttributeValues::Float32x2(uvs)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0)
+        {
+            for uv in uvs.iter_mut() {
+                uv[0] *= self.uv_scale.x;
+                uv[1] *= self.uv_scale.y;
+            }
+        }
+
+        mesh
+    }
+
+    /// Load and configure material with proper metadata
+    /// Uses a static friendly name and internal material as fallback as default primitives share the same material
+    fn load_and_configure_material(
+        &mut self,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        asset_server: &Res<AssetServer>,
+    ) {
+        let fallback_path = Self::internal_material_path();
+
+        // Use saved material path if available, otherwise use fallback
+        let material_path = if !self.material.path.is_empty() {
+            &self.material.path
+        } else {
+            &fallback_path
+        };
+
+        self.material.current.path = material_path.to_string();
+
+        let _created_new = self.material.current.material_exists_and_load(
+            available_materials,
+            standard_materials,
+            asset_server,
+            &self.type_name(),
+            material_path,
+        );
+
+        // Fix the material metadata after loading (since loaded materials have "None" path)
+        self.material.current.path = material_path.to_string();
+        self.material.current.friendly_name = self.type_name();
+        self.material.last = self.material.current.clone();
+
+        if self.material.path.is_empty() {
+            self.material.path = fallback_path.clone();
+        }
+    }
+}
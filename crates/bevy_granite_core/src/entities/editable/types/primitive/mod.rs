@@ -0,0 +1,213 @@
+use crate::{
+    assets::EditableMaterial,
+    entities::{
+        editable::{
+            GraniteType, RequestEntityUpdateFromClass, RequiredMaterialData,
+            RequiredMaterialDataMut,
+        },
+        EntitySaveReadyData, PromptData,
+    },
+    AvailableEditableMaterials, ClassCategory, MaterialData,
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        entity::Entity,
+        event::Event,
+        system::{Commands, Res, ResMut},
+    },
+    math::Vec2,
+    pbr::StandardMaterial,
+    prelude::Reflect,
+    render::mesh::Mesh,
+    transform::components::Transform,
+};
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+pub mod creation;
+pub mod plugin;
+pub mod ui;
+pub mod update_event;
+
+pub use plugin::*;
+pub use update_event::*;
+
+/// Internal event thats called when user edits UI Primitive variables
+#[derive(Event)]
+pub struct UserUpdatedPrimitiveEvent {
+    pub entity: Entity,
+    pub data: Primitive,
+}
+
+/// Which shape the primitive generates. Parameters live on Primitive and are shared between shapes
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrimitiveShape {
+    #[default]
+    Sphere,
+    Cylinder,
+    Capsule,
+    Plane,
+    Cone,
+    Torus,
+}
+
+impl PrimitiveShape {
+    pub fn all() -> [PrimitiveShape; 6] {
+        [
+            PrimitiveShape::Sphere,
+            PrimitiveShape::Cylinder,
+            PrimitiveShape::Capsule,
+            PrimitiveShape::Plane,
+            PrimitiveShape::Cone,
+            PrimitiveShape::Torus,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PrimitiveShape::Sphere => "Sphere",
+            PrimitiveShape::Cylinder => "Cylinder",
+            PrimitiveShape::Capsule => "Capsule",
+            PrimitiveShape::Plane => "Plane",
+            PrimitiveShape::Cone => "Cone",
+            PrimitiveShape::Torus => "Torus",
+        }
+    }
+}
+
+/// Actual serialized class data thats stored inside IdentityData
+/// Mesh is generated from these parameters, so like RectBrush every entity owns its own mesh handle
+/// Not every parameter is used by every shape:
+/// radius - sphere, cylinder, capsule, cone, and torus ring radius
+/// height - cylinder, capsule (total), cone
+/// minor_radius - torus tube
+/// size - plane
+/// segments - radial resolution, rings - sphere stacks, capsule latitudes, torus tube resolution
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct Primitive {
+    pub shape: PrimitiveShape,
+    pub radius: f32,
+    pub height: f32,
+    pub minor_radius: f32,
+    pub size: Vec2,
+    pub segments: u32,
+    pub rings: u32,
+    pub uv_scale: Vec2,
+    pub material: MaterialData,
+}
+
+impl Primitive {
+    pub fn type_name_static() -> String {
+        "Primitive".to_string()
+    }
+
+    pub fn type_abv_static() -> String {
+        "Prim".to_string()
+    }
+
+    pub fn internal_material_path() -> String {
+        "materials/internal/primitive.mat".to_string()
+    }
+}
+
+impl Default for Primitive {
+    fn default() -> Self {
+        let (path, name) = (Self::internal_material_path(), Self::type_name_static());
+
+        // Create a material with the internal defaults for primitives
+        let mut primitive_material = EditableMaterial::get_new_unnamed_base_color();
+        primitive_material.update_name(name.clone());
+        primitive_material.update_path(path.clone());
+
+        Self {
+            shape: PrimitiveShape::Sphere,
+            radius: 0.5,
+            height: 1.0,
+            minor_radius: 0.15,
+            size: Vec2::ONE,
+            segments: 32,
+            rings: 16,
+            uv_scale: Vec2::ONE,
+            material: MaterialData {
+                path: path.clone(),
+                current: primitive_material.clone(),
+                last: primitive_material.clone(),
+            },
+        }
+    }
+}
+
+impl GraniteType for Primitive {
+    fn category(&self) -> ClassCategory {
+        ClassCategory::Mesh
+    }
+
+    fn type_name(&self) -> String {
+        Primitive::type_name_static()
+    }
+
+    fn type_abv(&self) -> String {
+        Primitive::type_abv_static()
+    }
+
+    fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        mut standard_materials: ResMut<Assets<StandardMaterial>>,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut available_materials: ResMut<AvailableEditableMaterials>,
+        asset_server: Res<AssetServer>,
+        _maybe_prompt_data: Option<PromptData>,
+    ) -> Entity {
+        self.spawn_from_new_identity(
+            commands,
+            transform,
+            &mut standard_materials,
+            &mut available_materials,
+            &asset_server,
+            &mut meshes,
+        )
+    }
+
+    fn spawn_from_save_data(
+        &self,
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+    ) -> Entity {
+        Primitive::spawn_from_save_data(
+            save_data,
+            commands,
+            standard_materials,
+            available_materials,
+            asset_server,
+            meshes,
+        )
+    }
+
+    fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
+        self.push_to_entity(entity, request_update)
+    }
+
+    fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        self.edit_via_ui(ui, spacing)
+    }
+
+    fn get_material_data(&self) -> Option<RequiredMaterialData<'_>> {
+        Some(self.material.as_ref())
+    }
+
+    fn get_mut_material_data(&mut self) -> Option<RequiredMaterialDataMut<'_>> {
+        Some(self.material.as_mut())
+    }
+
+    fn needs_unique_handle(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,24 @@
+use crate::Primitive;
+
+use super::{update_primitive_system, PrimitiveShape, UserUpdatedPrimitiveEvent};
+use bevy::app::{App, Plugin, Update};
+
+pub struct PrimitivePlugin;
+impl Plugin for PrimitivePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            //
+            // Event
+            //
+            .add_event::<UserUpdatedPrimitiveEvent>()
+            //
+            // Register
+            //
+            .register_type::<Primitive>()
+            .register_type::<PrimitiveShape>()
+            //
+            // Schedule system
+            //
+            .add_systems(Update, update_primitive_system);
+    }
+}
@@ -0,0 +1,149 @@
+use super::{Primitive, PrimitiveShape};
+use bevy::math::Vec2;
+use bevy_egui::egui;
+
+impl Primitive {
+    /// Function to edit self's data via UI side panel
+    /// We have a sister system that pushes changes to world entity - can be found inside 'update_event.rs'
+    /// When true, sends an update to propagate these vars to the world's entity
+    pub fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        let primitive_data = self;
+        let large_spacing = spacing.1;
+        let mut changed = false;
+
+        ui.label(egui::RichText::new("Primitive Data").italics());
+        ui.add_space(large_spacing);
+
+        egui::Grid::new("primitive_data_grid")
+            .num_columns(2)
+            .spacing([large_spacing, large_spacing])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Shape:");
+                egui::ComboBox::from_id_salt("primitive_shape_dropdown")
+                    .selected_text(primitive_data.shape.name())
+                    .show_ui(ui, |ui| {
+                        for shape in PrimitiveShape::all() {
+                            changed |= ui
+                                .selectable_value(&mut primitive_data.shape, shape, shape.name())
+                                .changed();
+                        }
+                    });
+                ui.end_row();
+
+                let shape = primitive_data.shape;
+                let uses_radius = shape != PrimitiveShape::Plane;
+                let uses_height = matches!(
+                    shape,
+                    PrimitiveShape::Cylinder | PrimitiveShape::Capsule | PrimitiveShape::Cone
+                );
+                let uses_rings = matches!(
+                    shape,
+                    PrimitiveShape::Sphere | PrimitiveShape::Capsule | PrimitiveShape::Torus
+                );
+
+                if uses_radius {
+                    ui.label("Radius:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut primitive_data.radius)
+                                .speed(0.01)
+                                .range(0.001..=f32::MAX)
+                                .fixed_decimals(2),
+                        )
+                        .changed();
+                    ui.end_row();
+                }
+
+                if shape == PrimitiveShape::Torus {
+                    ui.label("Tube Radius:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut primitive_data.minor_radius)
+                                .speed(0.01)
+                                .range(0.001..=f32::MAX)
+                                .fixed_decimals(2),
+                        )
+                        .changed();
+                    ui.end_row();
+                }
+
+                if uses_height {
+                    ui.label("Height:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut primitive_data.height)
+                                .speed(0.01)
+                                .range(0.001..=f32::MAX)
+                                .fixed_decimals(2),
+                        )
+                        .changed();
+                    ui.end_row();
+                }
+
+                if shape == PrimitiveShape::Plane {
+                    ui.label("Size:");
+                    ui.horizontal(|ui| {
+                        changed |= ui
+                            .add(
+                                egui::DragValue::new(&mut primitive_data.size.x)
+                                    .speed(0.01)
+                                    .range(0.001..=f32::MAX)
+                                    .fixed_decimals(2),
+                            )
+                            .changed();
+                        changed |= ui
+                            .add(
+                                egui::DragValue::new(&mut primitive_data.size.y)
+                                    .speed(0.01)
+                                    .range(0.001..=f32::MAX)
+                                    .fixed_decimals(2),
+                            )
+                            .changed();
+                    });
+                    ui.end_row();
+                }
+
+                if uses_radius {
+                    ui.label("Segments:");
+                    changed |= ui
+                        .add(egui::DragValue::new(&mut primitive_data.segments).range(3..=256))
+                        .changed();
+                    ui.end_row();
+                }
+
+                if uses_rings {
+                    ui.label("Rings:");
+                    changed |= ui
+                        .add(egui::DragValue::new(&mut primitive_data.rings).range(2..=256))
+                        .changed();
+                    ui.end_row();
+                }
+
+                ui.label("UV:");
+                ui.horizontal(|ui| {
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut primitive_data.uv_scale.x)
+                                .speed(0.01)
+                                .fixed_decimals(2),
+                        )
+                        .changed();
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut primitive_data.uv_scale.y)
+                                .speed(0.01)
+                                .fixed_decimals(2),
+                        )
+                        .changed();
+                    if ui.button("Reset").clicked() {
+                        primitive_data.uv_scale = Vec2::ONE;
+                        changed = true;
+                    }
+                });
+                ui.end_row();
+            });
+
+        changed
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{entities::editable::RequestEntityUpdateFromClass, NeedsTangents};
+
+use super::{Primitive, UserUpdatedPrimitiveEvent};
+use bevy::{
+    asset::Assets,
+    ecs::{
+        event::EventReader,
+        system::{Commands, Query, ResMut},
+    },
+    prelude::Entity,
+    render::{
+        mesh::{Mesh, Mesh3d, MeshAabb},
+        primitives::Aabb,
+    },
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+impl Primitive {
+    pub fn push_to_entity(
+        &self,
+        primitive_e: Entity,
+        request_update: &mut RequestEntityUpdateFromClass,
+    ) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Requesting Primitive update"
+        );
+        request_update.primitive.write(UserUpdatedPrimitiveEvent {
+            entity: primitive_e,
+            data: self.clone(),
+        });
+    }
+}
+
+/// Regenerates the primitive mesh in place. The handle is unique per entity so nothing else is affected
+pub fn update_primitive_system(
+    mut commands: Commands,
+    mut reader: EventReader<UserUpdatedPrimitiveEvent>,
+    query: Query<&Mesh3d>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut aabbs: Query<&mut Aabb>,
+) {
+    for UserUpdatedPrimitiveEvent {
+        entity: requested_entity,
+        data: new,
+    } in reader.read()
+    {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Heard primitive update event: {}",
+            requested_entity
+        );
+        let Ok(mesh_handle) = query.get(*requested_entity) else {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Entity,
+                "Could not find primitive entity {}",
+                requested_entity
+            );
+            continue;
+        };
+        let Some(mesh) = meshes.get_mut(mesh_handle) else {
+            continue;
+        };
+
+        *mesh = new.create_mesh();
+
+        if let Some(new_mesh_aabb) = mesh.compute_aabb() {
+            if let Ok(mut entity_aabb) = aabbs.get_mut(*requested_entity) {
+                *entity_aabb = new_mesh_aabb;
+            }
+        } else {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Entity,
+                "Failed to compute AABB for updated primitive mesh"
+            );
+        }
+
+        // Replacing the mesh drops any generated tangents
+        commands.entity(*requested_entity).insert(NeedsTangents);
+
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Updated primitive mesh for entity {}",
+            requested_entity
+        );
+    }
+}
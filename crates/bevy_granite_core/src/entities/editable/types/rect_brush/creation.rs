@@ -1,4 +1,4 @@
-use super::RectBrush;
+use super::{spawn_rect_brush_face_meshes, RectBrush};
 use crate::{
     entities::EntitySaveReadyData, AvailableEditableMaterials, GraniteEditorSerdeEntity,
     GraniteType, GraniteTypes, HasRuntimeData, IdentityData, NeedsTangents,
@@ -108,14 +108,24 @@ impl RectBrush {
         // Load and configure the material
         self.load_and_configure_material(available_materials, standard_materials, asset_server);
 
-        commands
+        let entity = commands
             .spawn(Self::get_bundle(
                 self.clone(), // Clone AFTER fixing materials
                 identity.clone(),
                 transform,
                 meshes,
             ))
-            .id()
+            .id();
+
+        let face_meshes = self.face_meshes(
+            None,
+            meshes,
+            standard_materials,
+            available_materials,
+            asset_server,
+        );
+        spawn_rect_brush_face_meshes(commands, entity, face_meshes);
+        entity
     }
 
     /// Build a bundle that is ready to spawn from a rect brush
@@ -125,12 +135,8 @@ impl RectBrush {
         transform: Transform,
         meshes: &mut ResMut<Assets<Mesh>>,
     ) -> impl Bundle {
-        let rect_mesh = Self::create_mesh(
-            rectangle_brush.size.x,
-            rectangle_brush.size.y,
-            rectangle_brush.size.z,
-            rectangle_brush.uv_scale,
-        );
+        // Faces with their own material are left out, they are spawned as children
+        let rect_mesh = rectangle_brush.build_face_mesh("", None);
         let rect_handle = meshes.add(rect_mesh);
 
         (
@@ -0,0 +1,257 @@
+use super::RectBrush;
+use crate::{
+    assets::material_from_path_into_scene, AvailableEditableMaterials, CsgBaked, GraniteTypes,
+    IconProxy, IdentityData, NeedsTangents, TreeHiddenEntity,
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        bundle::Bundle,
+        component::Component,
+        entity::Entity,
+        hierarchy::{ChildOf, Children},
+        query::{Changed, With, Without},
+        system::{Commands, Query, Res, ResMut},
+    },
+    math::{Vec2, Vec3},
+    pbr::{MeshMaterial3d, StandardMaterial},
+    prelude::{Name, Reflect},
+    render::mesh::{Indices, Mesh, Mesh3d},
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+use serde::{Deserialize, Serialize};
+
+/// Same order as the brush vertices, 4 verts and 6 indices per face
+pub const RECT_BRUSH_FACE_NAMES: [&str; 6] = ["Front", "Back", "Right", "Left", "Top", "Bottom"];
+
+// U and V directions of each face in brush space, matching the default corner UVs
+// Used by texture lock to project world positions onto the face
+const FACE_UV_AXES: [(Vec3, Vec3); 6] = [
+    (Vec3::X, Vec3::NEG_Y),
+    (Vec3::X, Vec3::NEG_Y),
+    (Vec3::NEG_Z, Vec3::NEG_Y),
+    (Vec3::Z, Vec3::NEG_Y),
+    (Vec3::X, Vec3::Z),
+    (Vec3::X, Vec3::NEG_Z),
+];
+
+/// Per face overrides for a RectBrush
+/// An empty material path means the face uses the brush material
+/// UV offset/scale/rotation are applied on top of the brush UVs. Rotation is in degrees
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct BrushFace {
+    pub material_path: String,
+    pub uv_offset: Vec2,
+    pub uv_scale: Vec2,
+    pub uv_rotation: f32,
+}
+
+impl Default for BrushFace {
+    fn default() -> Self {
+        Self {
+            material_path: String::new(),
+            uv_offset: Vec2::ZERO,
+            uv_scale: Vec2::ONE,
+            uv_rotation: 0.0,
+        }
+    }
+}
+
+impl BrushFace {
+    pub fn has_material(&self) -> bool {
+        !self.material_path.is_empty()
+    }
+
+    fn transform_uv(&self, uv: Vec2) -> Vec2 {
+        let scaled = uv * self.uv_scale;
+        Vec2::from_angle(self.uv_rotation.to_radians()).rotate(scaled) + self.uv_offset
+    }
+}
+
+/// Child mesh that renders the faces of a brush that use their own material
+/// Not saved, rebuilt from the brush class data whenever it changes
+#[derive(Component)]
+pub struct RectBrushFaceMesh;
+
+impl RectBrush {
+    pub fn faces_are_default(faces: &[BrushFace; 6]) -> bool {
+        faces.iter().all(|face| *face == BrushFace::default())
+    }
+
+    /// UVs for every vertex with the brush scale and per face transform applied
+    /// With texture lock the UVs are projected from world space, so resizing or moving the brush keeps textures in place
+    pub fn face_uvs(&self, world_transform: Option<&GlobalTransform>) -> Vec<[f32; 2]> {
+        let vertices = Self::get_vertices(self.size.x, self.size.y, self.size.z);
+        let corner_uvs = Self::get_uvs(self.uv_scale);
+        let world_transform = world_transform.copied().unwrap_or_default();
+        let rotation = world_transform.rotation();
+
+        vertices
+            .iter()
+            .zip(corner_uvs)
+            .enumerate()
+            .map(|(index, (vertex, corner_uv))| {
+                let face_index = index / 4;
+                let uv = if self.texture_lock {
+                    let (u_axis, v_axis) = FACE_UV_AXES[face_index];
+                    let world_position = world_transform.transform_point(Vec3::from(*vertex));
+                    Vec2::new(
+                        world_position.dot(rotation * u_axis),
+                        world_position.dot(rotation * v_axis),
+                    ) * self.uv_scale
+                } else {
+                    Vec2::from(corner_uv)
+                };
+                self.faces[face_index].transform_uv(uv).to_array()
+            })
+            .collect()
+    }
+
+    /// Distinct material paths used by face overrides
+    pub fn face_material_paths(&self) -> Vec<String> {
+        let mut paths: Vec<String> = self
+            .faces
+            .iter()
+            .filter(|face| face.has_material())
+            .map(|face| face.material_path.clone())
+            .collect();
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+
+    /// Build a mesh that only draws the faces using this material path, empty path is the brush material
+    /// All vertices are kept so the bounds always cover the whole brush
+    pub fn build_face_mesh(
+        &self,
+        material_path: &str,
+        world_transform: Option<&GlobalTransform>,
+    ) -> Mesh {
+        let mut mesh = Self::create_mesh(self.size.x, self.size.y, self.size.z, self.uv_scale);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, self.face_uvs(world_transform));
+
+        let indices: Vec<u32> = Self::get_indices()
+            .chunks(6)
+            .zip(self.faces.iter())
+            .filter(|(_, face)| face.material_path == material_path)
+            .flat_map(|(face_indices, _)| face_indices.to_vec())
+            .collect();
+        mesh.insert_indices(Indices::U32(indices));
+        mesh
+    }
+}
+
+impl RectBrush {
+    /// One mesh per face material, faces without a material are drawn by the brush entity itself
+    /// Spawn them with spawn_rect_brush_face_meshes
+    pub fn face_meshes(
+        &self,
+        world_transform: Option<&GlobalTransform>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+    ) -> Vec<impl Bundle> {
+        let mut face_meshes = Vec::new();
+        for path in self.face_material_paths() {
+            let Some(handle) = material_from_path_into_scene(
+                &path,
+                standard_materials,
+                available_materials,
+                asset_server,
+            )
+            .and_then(|material| material.handle) else {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Warning,
+                    LogCategory::Asset,
+                    "Could not load brush face material: {}",
+                    path
+                );
+                continue;
+            };
+
+            face_meshes.push((
+                Mesh3d(meshes.add(self.build_face_mesh(&path, world_transform))),
+                MeshMaterial3d(handle),
+                Transform::default(),
+                Name::new(format!("Brush Face ({})", path)),
+                RectBrushFaceMesh,
+                NeedsTangents,
+            ));
+        }
+        face_meshes
+    }
+}
+
+/// Spawn the face meshes as children of the brush
+/// Clicking a face mesh selects the brush through IconProxy
+pub fn spawn_rect_brush_face_meshes(
+    commands: &mut Commands,
+    brush_entity: Entity,
+    face_meshes: Vec<impl Bundle>,
+) {
+    for face_mesh in face_meshes {
+        commands.spawn((
+            face_mesh,
+            IconProxy {
+                target_entity: brush_entity,
+            },
+            TreeHiddenEntity,
+            ChildOf(brush_entity),
+        ));
+    }
+}
+
+type TextureLockBrushQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static IdentityData,
+        &'static GlobalTransform,
+        &'static Mesh3d,
+        Option<&'static Children>,
+    ),
+    (Changed<GlobalTransform>, Without<CsgBaked>),
+>;
+
+/// Keeps texture locked brushes projecting from world space while they are moved, rotated or scaled
+/// CSG baked brushes are skipped, their mesh no longer has the brush vertex layout
+pub fn rect_brush_texture_lock_system(
+    brush_query: TextureLockBrushQuery,
+    face_query: Query<&Mesh3d, With<RectBrushFaceMesh>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    for (identity, global_transform, mesh_handle, children) in brush_query.iter() {
+        let GraniteTypes::RectBrush(brush) = &identity.class else {
+            continue;
+        };
+        if !brush.texture_lock {
+            continue;
+        }
+
+        let uvs = brush.face_uvs(Some(global_transform));
+        let face_handles = children
+            .iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| face_query.get(*child).ok());
+        for handle in std::iter::once(mesh_handle).chain(face_handles) {
+            let Some(mesh) = meshes.get_mut(handle) else {
+                continue;
+            };
+            mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs.clone());
+            // Tangents follow the UVs, NeedsTangents would keep the old ones since they already exist
+            if let Err(error) = mesh.generate_tangents() {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Error,
+                    LogCategory::Entity,
+                    "Failed to regenerate tangents for texture locked brush: {:?}",
+                    error
+                );
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     assets::EditableMaterial,
     entities::{editable::{
         GraniteType, RequestEntityUpdateFromClass, RequiredMaterialData, RequiredMaterialDataMut,
-    }, EntitySaveReadyData, PromptData},
+    }, CsgOperation, EntitySaveReadyData, PromptData},
     AvailableEditableMaterials, ClassCategory, MaterialData,
 };
 use bevy::{
@@ -22,10 +22,12 @@ use bevy_egui::egui;
 use serde::{Deserialize, Serialize};
 
 pub mod creation;
+pub mod faces;
 pub mod plugin;
 pub mod ui;
 pub mod update_event;
 
+pub use faces::*;
 pub use plugin::*;
 pub use update_event::*;
 
@@ -40,11 +42,21 @@ pub struct UserUpdatedRectBrushEvent {
 /// Size is x,y,z that actually edits the verts of this brush, not just a scale
 /// UV scale is mapped directly to the verts, so this is separate then Material editing UVs
 /// Rectangle Brushes contain materials on their surface so we pass the path, last, and current material under MaterialData
+/// Faces can override the material and UVs per side, see BrushFace. Texture lock projects UVs from world space
+/// Setting csg makes this brush an operand of its parent brush, see entities::csg
 #[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
 pub struct RectBrush {
     pub size: Vec3,
     pub uv_scale: Vec2,
     pub material: MaterialData,
+    /// Boxed to keep GraniteTypes small, faces are only edited through the class UI so they skip reflection
+    #[serde(skip_serializing_if = "RectBrush::faces_are_default")]
+    #[reflect(ignore)]
+    pub faces: Box<[BrushFace; 6]>,
+    pub texture_lock: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csg: Option<CsgOperation>,
 }
 
 impl RectBrush {
@@ -79,6 +91,9 @@ impl Default for RectBrush {
                 current: brush_material.clone(),
                 last: brush_material.clone(),
             },
+            faces: Default::default(),
+            texture_lock: false,
+            csg: None,
         }
     }
 }
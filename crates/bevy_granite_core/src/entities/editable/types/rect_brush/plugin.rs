@@ -1,7 +1,14 @@
 use crate::RectBrush;
 
-use super::{update_rectangle_brush_system, UserUpdatedRectBrushEvent};
-use bevy::app::{App, Plugin, Update};
+use super::{
+    rect_brush_texture_lock_system, update_rectangle_brush_system, BrushFace,
+    UserUpdatedRectBrushEvent,
+};
+use bevy::{
+    app::{App, Plugin, PostUpdate, Update},
+    ecs::schedule::IntoScheduleConfigs,
+    transform::TransformSystem,
+};
 
 pub struct RectBrushPlugin;
 impl Plugin for RectBrushPlugin {
@@ -15,9 +22,14 @@ impl Plugin for RectBrushPlugin {
             // Register
             //
             .register_type::<RectBrush>()
+            .register_type::<BrushFace>()
             //
             // Schedule system
             //
-            .add_systems(Update, update_rectangle_brush_system);
+            .add_systems(Update, update_rectangle_brush_system)
+            .add_systems(
+                PostUpdate,
+                rect_brush_texture_lock_system.after(TransformSystem::TransformPropagate),
+            );
     }
 }
@@ -1,4 +1,5 @@
-use super::RectBrush;
+use super::{BrushFace, RectBrush, RECT_BRUSH_FACE_NAMES};
+use crate::{absolute_asset_to_rel, entities::csg::edit_csg_operation, shared::asset_file_browser};
 use bevy::math::{Vec2, Vec3};
 use bevy_egui::egui;
 
@@ -106,8 +107,139 @@ impl RectBrush {
                         }
                     });
             });
+            ui.add_space(small_spacing);
+            changed |= ui
+                .checkbox(&mut rect_data.texture_lock, "Texture Lock")
+                .on_hover_text("Project UVs from world space so textures stay put when the brush is moved or resized")
+                .changed();
+        });
+
+        ui.add_space(large_spacing);
+
+        ui.horizontal(|ui| {
+            ui.label("CSG:");
+            changed |= edit_csg_operation(ui, &mut rect_data.csg, "rect_brush_csg_dropdown");
+        });
+
+        ui.add_space(large_spacing);
+
+        // Per face material and UVs
+        ui.collapsing("Faces", |ui| {
+            for (index, face) in rect_data.faces.iter_mut().enumerate() {
+                let name = RECT_BRUSH_FACE_NAMES[index];
+                let title = if face.has_material() {
+                    format!("{} ({})", name, face.material_path)
+                } else {
+                    name.to_string()
+                };
+                egui::CollapsingHeader::new(title)
+                    .id_salt(("rect_brush_face", index))
+                    .show(ui, |ui| {
+                        changed |= face_ui(ui, face, index, drag_size, normal_spacing);
+                    });
+            }
         });
 
         changed
     }
 }
+
+fn face_ui(
+    ui: &mut egui::Ui,
+    face: &mut BrushFace,
+    index: usize,
+    drag_size: [f32; 2],
+    spacing: f32,
+) -> bool {
+    let mut changed = false;
+    egui::Grid::new(("rect_brush_face_grid", index))
+        .num_columns(2)
+        .spacing([spacing, spacing])
+        .show(ui, |ui| {
+            ui.label("Material:");
+            ui.horizontal(|ui| {
+                let label = if face.has_material() {
+                    face.material_path.as_str()
+                } else {
+                    "Brush material"
+                };
+                ui.label(label);
+                if ui.button("Browse").clicked() {
+                    if let Some(path) = asset_file_browser("materials".to_string(), vec!["mat"]) {
+                        face.material_path = absolute_asset_to_rel(path).to_string();
+                        changed = true;
+                    }
+                }
+                if face.has_material() && ui.small_button("X").clicked() {
+                    face.material_path.clear();
+                    changed = true;
+                }
+            });
+            ui.end_row();
+
+            ui.label("UV Offset:");
+            ui.horizontal(|ui| {
+                changed |= ui
+                    .add_sized(
+                        drag_size,
+                        egui::DragValue::new(&mut face.uv_offset.x)
+                            .speed(0.01)
+                            .fixed_decimals(2),
+                    )
+                    .changed();
+                changed |= ui
+                    .add_sized(
+                        drag_size,
+                        egui::DragValue::new(&mut face.uv_offset.y)
+                            .speed(0.01)
+                            .fixed_decimals(2),
+                    )
+                    .changed();
+            });
+            ui.end_row();
+
+            ui.label("UV Scale:");
+            ui.horizontal(|ui| {
+                changed |= ui
+                    .add_sized(
+                        drag_size,
+                        egui::DragValue::new(&mut face.uv_scale.x)
+                            .speed(0.01)
+                            .fixed_decimals(2),
+                    )
+                    .changed();
+                changed |= ui
+                    .add_sized(
+                        drag_size,
+                        egui::DragValue::new(&mut face.uv_scale.y)
+                            .speed(0.01)
+                            .fixed_decimals(2),
+                    )
+                    .changed();
+            });
+            ui.end_row();
+
+            ui.label("UV Rotation:");
+            changed |= ui
+                .add_sized(
+                    drag_size,
+                    egui::DragValue::new(&mut face.uv_rotation)
+                        .speed(1.0)
+                        .suffix("°"),
+                )
+                .changed();
+            ui.end_row();
+
+            ui.label("");
+            if ui.button("Reset UVs").clicked() {
+                let material_path = std::mem::take(&mut face.material_path);
+                *face = BrushFace {
+                    material_path,
+                    ..Default::default()
+                };
+                changed = true;
+            }
+            ui.end_row();
+        });
+    changed
+}
@@ -1,13 +1,21 @@
-use crate::entities::editable::RequestEntityUpdateFromClass;
+use crate::{
+    entities::editable::RequestEntityUpdateFromClass, AvailableEditableMaterials, NeedsTangents,
+};
 
-use super::{RectBrush, UserUpdatedRectBrushEvent};
+use super::{
+    spawn_rect_brush_face_meshes, RectBrush, RectBrushFaceMesh, UserUpdatedRectBrushEvent,
+};
 use bevy::{
-    asset::Assets,
+    asset::{AssetServer, Assets},
     ecs::{
         event::EventReader,
-        system::{Query, ResMut},
+        hierarchy::ChildOf,
+        query::With,
+        system::{Commands, Query, Res, ResMut, SystemParam},
     },
+    pbr::StandardMaterial,
     render::mesh::{Mesh3d, MeshAabb},
+    transform::components::GlobalTransform,
 };
 use bevy::{
     prelude::Entity,
@@ -36,11 +44,22 @@ impl RectBrush {
     }
 }
 
+/// Everything needed to rebuild the brush and face meshes
+#[derive(SystemParam)]
+pub struct RectBrushMeshAssets<'w> {
+    pub meshes: ResMut<'w, Assets<Mesh>>,
+    pub standard_materials: ResMut<'w, Assets<StandardMaterial>>,
+    pub available_materials: ResMut<'w, AvailableEditableMaterials>,
+    pub asset_server: Res<'w, AssetServer>,
+}
+
 pub fn update_rectangle_brush_system(
+    mut commands: Commands,
     mut reader: EventReader<UserUpdatedRectBrushEvent>,
-    mut query: Query<(Entity, &Mesh3d)>,
-    mut meshes: ResMut<Assets<Mesh>>,
+    query: Query<(Entity, &Mesh3d, Option<&GlobalTransform>)>,
+    face_query: Query<(Entity, &ChildOf), With<RectBrushFaceMesh>>,
     mut aabbs: Query<&mut Aabb>,
+    mut assets: RectBrushMeshAssets,
 ) {
     for UserUpdatedRectBrushEvent {
         entity: requested_entity,
@@ -54,16 +73,27 @@ pub fn update_rectangle_brush_system(
             "Heard rectangle brush update event: {}",
             requested_entity
         );
-        if let Ok((_entity, mesh_handle)) = query.get_mut(*requested_entity) {
-            if let Some(mesh) = meshes.get_mut(mesh_handle) {
-                let new_vertices = RectBrush::get_vertices(new.size.x, new.size.y, new.size.z);
-                let new_uvs = RectBrush::get_uvs(new.uv_scale);
-                let indices = RectBrush::get_indices();
-                let normals = RectBrush::calculate_normals(&new_vertices, &indices);
+        if let Ok((_entity, mesh_handle, global_transform)) = query.get(*requested_entity) {
+            // Face meshes are cheap, rebuild them so material and UV changes are picked up
+            for (face_entity, child_of) in face_query.iter() {
+                if child_of.parent() == *requested_entity {
+                    commands.entity(face_entity).despawn();
+                }
+            }
+            let face_meshes = new.face_meshes(
+                global_transform,
+                &mut assets.meshes,
+                &mut assets.standard_materials,
+                &mut assets.available_materials,
+                &assets.asset_server,
+            );
+            spawn_rect_brush_face_meshes(&mut commands, *requested_entity, face_meshes);
+
+            if let Some(mesh) = assets.meshes.get_mut(mesh_handle) {
+                *mesh = new.build_face_mesh("", global_transform);
+                // Replacing the mesh drops any generated tangents
+                commands.entity(*requested_entity).insert(NeedsTangents);
 
-                mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, new_vertices);
-                mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, new_uvs);
-                mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
                 // Compute the new AABB from the updated mesh
                 if let Some(new_mesh_aabb) = mesh.compute_aabb() {
                     if let Ok(mut entity_aabb) = aabbs.get_mut(*requested_entity) {
@@ -0,0 +1,172 @@
+use crate::{
+    entities::{
+        editable::{GraniteType, RequestEntityUpdateFromClass},
+        EntitySaveReadyData,
+    },
+    AvailableEditableMaterials, ClassCategory, PromptData,
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        entity::Entity,
+        event::Event,
+        system::{Commands, Res, ResMut},
+    },
+    math::Vec3,
+    pbr::StandardMaterial,
+    reflect::Reflect,
+    render::mesh::Mesh,
+    transform::components::Transform,
+};
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+pub mod creation;
+pub mod plugin;
+pub mod sampling;
+pub mod ui;
+pub mod update_event;
+
+pub use plugin::*;
+pub use sampling::*;
+pub use update_event::*;
+
+/// Internal event thats called when user edits UI Spline variables
+#[derive(Event)]
+pub struct UserUpdatedSplineEvent {
+    pub entity: Entity,
+    pub data: Spline,
+}
+
+/// How the path is interpolated between control points
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplineMode {
+    Linear,
+    #[default]
+    CatmullRom,
+    Bezier,
+}
+
+impl SplineMode {
+    pub fn all() -> [SplineMode; 3] {
+        [
+            SplineMode::Linear,
+            SplineMode::CatmullRom,
+            SplineMode::Bezier,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SplineMode::Linear => "Linear",
+            SplineMode::CatmullRom => "Catmull-Rom",
+            SplineMode::Bezier => "Bézier",
+        }
+    }
+}
+
+/// Control point of a spline. Tangents are offsets from the position and only used in Bézier mode
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct SplinePoint {
+    pub position: Vec3,
+    pub in_tangent: Vec3,
+    pub out_tangent: Vec3,
+}
+
+impl Default for SplinePoint {
+    fn default() -> Self {
+        Self::new(Vec3::ZERO)
+    }
+}
+
+impl SplinePoint {
+    /// Point with smooth Bézier handles along X
+    pub fn new(position: Vec3) -> Self {
+        Self {
+            position,
+            in_tangent: Vec3::new(-0.5, 0.0, 0.0),
+            out_tangent: Vec3::new(0.5, 0.0, 0.0),
+        }
+    }
+}
+
+/// Actual serialized class data thats stored inside IdentityData
+/// An authored path for patrol routes, camera rails, rivers, etc. Points are in the entity's local space
+/// Sample it at runtime by distance, see sampling.rs
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct Spline {
+    pub points: Vec<SplinePoint>,
+    pub mode: SplineMode,
+    pub closed: bool,
+}
+
+impl Default for Spline {
+    fn default() -> Self {
+        Self {
+            points: vec![
+                SplinePoint::new(Vec3::new(-2.0, 0.0, 0.0)),
+                SplinePoint::new(Vec3::new(0.0, 0.0, -1.0)),
+                SplinePoint::new(Vec3::new(2.0, 0.0, 0.0)),
+            ],
+            mode: SplineMode::default(),
+            closed: false,
+        }
+    }
+}
+
+impl GraniteType for Spline {
+    fn type_name(&self) -> String {
+        "Spline".to_string()
+    }
+
+    fn type_abv(&self) -> String {
+        "Spline".to_string()
+    }
+
+    fn category(&self) -> ClassCategory {
+        ClassCategory::Gameplay
+    }
+
+    fn get_embedded_icon_bytes(&self) -> Option<&'static [u8]> {
+        Some(include_bytes!("Spline.png"))
+    }
+
+    fn get_icon_filename(&self) -> Option<&'static str> {
+        Some("Spline.png")
+    }
+
+    fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        _standard_materials: ResMut<Assets<StandardMaterial>>,
+        _meshes: ResMut<Assets<Mesh>>,
+        _available_materials: ResMut<AvailableEditableMaterials>,
+        _asset_server: Res<AssetServer>,
+        _maybe_prompt_data: Option<PromptData>,
+    ) -> Entity {
+        Spline::spawn_from_new_identity(self, commands, transform)
+    }
+
+    fn spawn_from_save_data(
+        &self,
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        _standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        _meshes: &mut ResMut<Assets<Mesh>>,
+        _available_materials: &mut ResMut<AvailableEditableMaterials>,
+        _asset_server: &Res<AssetServer>,
+    ) -> Entity {
+        Spline::spawn_from_save_data(save_data, commands)
+    }
+
+    fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
+        self.push_to_entity(entity, request_update)
+    }
+
+    fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        self.edit_via_ui(ui, spacing)
+    }
+}
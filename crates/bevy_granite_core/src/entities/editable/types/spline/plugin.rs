@@ -0,0 +1,23 @@
+use super::{update_spline_system, Spline, SplineMode, SplinePoint, UserUpdatedSplineEvent};
+use bevy::app::{App, Plugin, Update};
+
+pub struct SplinePlugin;
+impl Plugin for SplinePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            //
+            // Event
+            //
+            .add_event::<UserUpdatedSplineEvent>()
+            //
+            // Register
+            //
+            .register_type::<Spline>()
+            .register_type::<SplineMode>()
+            .register_type::<SplinePoint>()
+            //
+            // Schedule system
+            //
+            .add_systems(Update, update_spline_system);
+    }
+}
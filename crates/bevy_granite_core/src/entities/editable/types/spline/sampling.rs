@@ -0,0 +1,215 @@
+use super::{Spline, SplineMode};
+use bevy::{math::Vec3, transform::components::GlobalTransform};
+
+// Steps per segment when measuring arc length, plenty for authored paths
+const LENGTH_STEPS: usize = 32;
+
+/// Position and unit direction at some point along a spline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplineSample {
+    pub position: Vec3,
+    pub tangent: Vec3,
+}
+
+impl Spline {
+    /// Closed splines get an extra segment from the last point back to the first
+    pub fn segment_count(&self) -> usize {
+        match self.points.len() {
+            0 | 1 => 0,
+            count if self.closed => count,
+            count => count - 1,
+        }
+    }
+
+    /// Local space position on a segment, t goes from 0 at its start point to 1 at its end point
+    pub fn segment_position(&self, segment: usize, t: f32) -> Vec3 {
+        if self.points.len() < 2 {
+            return self
+                .points
+                .first()
+                .map_or(Vec3::ZERO, |point| point.position);
+        }
+        let t = t.clamp(0.0, 1.0);
+        match self.mode {
+            SplineMode::Linear => {
+                let [_, start, end, _] = self.catmull_rom_points(segment);
+                start.lerp(end, t)
+            }
+            SplineMode::CatmullRom => {
+                let [p0, p1, p2, p3] = self.catmull_rom_points(segment);
+                let (t2, t3) = (t * t, t * t * t);
+                0.5 * (2.0 * p1
+                    + (p2 - p0) * t
+                    + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+                    + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+            }
+            SplineMode::Bezier => {
+                let [a, b, c, d] = self.bezier_points(segment);
+                let u = 1.0 - t;
+                a * (u * u * u) + b * (3.0 * u * u * t) + c * (3.0 * u * t * t) + d * (t * t * t)
+            }
+        }
+    }
+
+    /// Local space derivative on a segment, not normalized
+    pub fn segment_derivative(&self, segment: usize, t: f32) -> Vec3 {
+        if self.points.len() < 2 {
+            return Vec3::ZERO;
+        }
+        let t = t.clamp(0.0, 1.0);
+        match self.mode {
+            SplineMode::Linear => {
+                let [_, start, end, _] = self.catmull_rom_points(segment);
+                end - start
+            }
+            SplineMode::CatmullRom => {
+                let [p0, p1, p2, p3] = self.catmull_rom_points(segment);
+                0.5 * ((p2 - p0)
+                    + 2.0 * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t
+                    + 3.0 * (3.0 * p1 - p0 - 3.0 * p2 + p3) * t * t)
+            }
+            SplineMode::Bezier => {
+                let [a, b, c, d] = self.bezier_points(segment);
+                let u = 1.0 - t;
+                3.0 * u * u * (b - a) + 6.0 * u * t * (c - b) + 3.0 * t * t * (d - c)
+            }
+        }
+    }
+
+    /// Total length in local units
+    pub fn length(&self) -> f32 {
+        self.arc_lengths().last().copied().unwrap_or(0.0)
+    }
+
+    /// Position and direction at a distance along the path, in local space
+    /// Open splines clamp the distance to the ends, closed splines wrap around
+    /// None if there are fewer than two points
+    pub fn sample_at_distance(&self, distance: f32) -> Option<SplineSample> {
+        let segments = self.segment_count();
+        if segments == 0 {
+            return None;
+        }
+        let lengths = self.arc_lengths();
+        let total = lengths.last().copied().unwrap_or(0.0);
+        let distance = if self.closed && total > 0.0 {
+            distance.rem_euclid(total)
+        } else {
+            distance.clamp(0.0, total)
+        };
+
+        // First step that ends past the distance, then interpolate inside it
+        let step = lengths
+            .partition_point(|length| *length < distance)
+            .clamp(1, lengths.len() - 1);
+        let (start, end) = (lengths[step - 1], lengths[step]);
+        let fraction = if end > start {
+            (distance - start) / (end - start)
+        } else {
+            0.0
+        };
+        let global_t = (step - 1) as f32 + fraction;
+        let segment = ((global_t as usize) / LENGTH_STEPS).min(segments - 1);
+        let t = (global_t / LENGTH_STEPS as f32 - segment as f32).clamp(0.0, 1.0);
+
+        Some(self.segment_sample(segment, t))
+    }
+
+    /// Same as sample_at_distance but placed in the world with the spline's transform
+    /// Distance is still measured in local units
+    pub fn sample_at_distance_world(
+        &self,
+        transform: &GlobalTransform,
+        distance: f32,
+    ) -> Option<SplineSample> {
+        let sample = self.sample_at_distance(distance)?;
+        Some(SplineSample {
+            position: transform.transform_point(sample.position),
+            tangent: transform
+                .affine()
+                .transform_vector3(sample.tangent)
+                .normalize_or_zero(),
+        })
+    }
+
+    /// Points along the whole path, for drawing
+    pub fn polyline(&self, steps_per_segment: usize) -> Vec<Vec3> {
+        let steps = steps_per_segment.max(1);
+        let mut points = Vec::new();
+        for segment in 0..self.segment_count() {
+            let first_step = if segment == 0 { 0 } else { 1 };
+            for step in first_step..=steps {
+                points.push(self.segment_position(segment, step as f32 / steps as f32));
+            }
+        }
+        points
+    }
+
+    fn segment_sample(&self, segment: usize, t: f32) -> SplineSample {
+        let position = self.segment_position(segment, t);
+        // Bézier handles of zero length give a zero derivative at the ends, fall back to the chord
+        let tangent = self
+            .segment_derivative(segment, t)
+            .try_normalize()
+            .unwrap_or_else(|| {
+                (self.segment_position(segment, 1.0) - self.segment_position(segment, 0.0))
+                    .normalize_or_zero()
+            });
+        SplineSample { position, tangent }
+    }
+
+    /// Cumulative length at every measuring step, starting at 0
+    fn arc_lengths(&self) -> Vec<f32> {
+        let mut lengths = vec![0.0];
+        let mut total = 0.0;
+        for segment in 0..self.segment_count() {
+            let mut previous = self.segment_position(segment, 0.0);
+            for step in 1..=LENGTH_STEPS {
+                let position = self.segment_position(segment, step as f32 / LENGTH_STEPS as f32);
+                total += previous.distance(position);
+                lengths.push(total);
+                previous = position;
+            }
+        }
+        lengths
+    }
+
+    /// Position of a point by index, wrapping for closed splines
+    /// Open splines mirror the neighbouring point past either end so Catmull-Rom has something to aim at
+    fn point_position(&self, index: isize) -> Vec3 {
+        let count = self.points.len() as isize;
+        if self.closed {
+            return self.points[index.rem_euclid(count) as usize].position;
+        }
+        if index < 0 {
+            let (first, second) = (self.points[0].position, self.points[1].position);
+            return 2.0 * first - second;
+        }
+        if index >= count {
+            let last = self.points[(count - 1) as usize].position;
+            let before = self.points[(count - 2) as usize].position;
+            return 2.0 * last - before;
+        }
+        self.points[index as usize].position
+    }
+
+    fn catmull_rom_points(&self, segment: usize) -> [Vec3; 4] {
+        let index = segment as isize;
+        [
+            self.point_position(index - 1),
+            self.point_position(index),
+            self.point_position(index + 1),
+            self.point_position(index + 2),
+        ]
+    }
+
+    fn bezier_points(&self, segment: usize) -> [Vec3; 4] {
+        let start = self.points[segment % self.points.len()];
+        let end = self.points[(segment + 1) % self.points.len()];
+        [
+            start.position,
+            start.position + start.out_tangent,
+            end.position + end.in_tangent,
+            end.position,
+        ]
+    }
+}
@@ -0,0 +1,118 @@
+use super::{Spline, SplineMode, SplinePoint};
+use bevy::math::Vec3;
+use bevy_egui::egui;
+
+impl Spline {
+    /// Function to edit self's data via UI side panel
+    /// We have a sister system that pushes changes to world entity - can be found inside 'update_event.rs'
+    /// When true, sends an update to propagate these vars to the world's entity
+    pub fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        let spline_data = self;
+        let small_spacing = spacing.0;
+        let large_spacing = spacing.1;
+        let mut changed = false;
+
+        ui.label(egui::RichText::new("Spline Data").italics());
+        ui.add_space(large_spacing);
+
+        egui::Grid::new("spline_data_grid")
+            .num_columns(2)
+            .spacing([large_spacing, large_spacing])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Mode:");
+                egui::ComboBox::from_id_salt("spline_mode_dropdown")
+                    .selected_text(spline_data.mode.name())
+                    .show_ui(ui, |ui| {
+                        for mode in SplineMode::all() {
+                            changed |= ui
+                                .selectable_value(&mut spline_data.mode, mode, mode.name())
+                                .changed();
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Closed:");
+                changed |= ui.checkbox(&mut spline_data.closed, "").changed();
+                ui.end_row();
+
+                ui.label("Length:");
+                ui.label(format!("{:.2}", spline_data.length()))
+                    .on_hover_text("Select the spline to drag its points in the viewport");
+                ui.end_row();
+            });
+
+        ui.add_space(large_spacing);
+
+        let show_tangents = spline_data.mode == SplineMode::Bezier;
+        let can_remove = spline_data.points.len() > 2;
+        let mut remove = None;
+        ui.collapsing("Points", |ui| {
+            egui::Grid::new("spline_points_grid")
+                .num_columns(3)
+                .spacing([large_spacing, small_spacing])
+                .show(ui, |ui| {
+                    for (index, point) in spline_data.points.iter_mut().enumerate() {
+                        ui.label(format!("{}:", index));
+                        changed |= vec3_drag(ui, &mut point.position);
+                        if ui
+                            .add_enabled(can_remove, egui::Button::new("Remove"))
+                            .clicked()
+                        {
+                            remove = Some(index);
+                        }
+                        ui.end_row();
+
+                        if show_tangents {
+                            ui.weak("In:");
+                            changed |= vec3_drag(ui, &mut point.in_tangent);
+                            ui.end_row();
+                            ui.weak("Out:");
+                            changed |= vec3_drag(ui, &mut point.out_tangent);
+                            ui.end_row();
+                        }
+                    }
+                });
+
+            ui.add_space(small_spacing);
+            if ui.button("Add Point").clicked() {
+                let point = spline_data.next_point();
+                spline_data.points.push(point);
+                changed = true;
+            }
+        });
+
+        if let Some(index) = remove {
+            spline_data.points.remove(index);
+            changed = true;
+        }
+
+        changed
+    }
+
+    /// New point continuing the path past its last point
+    fn next_point(&self) -> SplinePoint {
+        let count = self.points.len();
+        let position = match count {
+            0 => Vec3::ZERO,
+            1 => self.points[0].position + Vec3::X,
+            _ => {
+                let (before, last) = (self.points[count - 2], self.points[count - 1]);
+                2.0 * last.position - before.position
+            }
+        };
+        SplinePoint::new(position)
+    }
+}
+
+fn vec3_drag(ui: &mut egui::Ui, value: &mut Vec3) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        for axis in [&mut value.x, &mut value.y, &mut value.z] {
+            changed |= ui
+                .add(egui::DragValue::new(axis).speed(0.01).fixed_decimals(2))
+                .changed();
+        }
+    });
+    changed
+}
@@ -0,0 +1,43 @@
+use crate::{
+    entities::editable::{RequestEntityUpdateFromClass, UserUpdatedSplineEvent},
+    Spline,
+};
+use bevy::ecs::{entity::Entity, event::EventReader};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+impl Spline {
+    pub fn push_to_entity(
+        &self,
+        entity: Entity,
+        request_update: &mut RequestEntityUpdateFromClass,
+    ) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Requesting spline entity update"
+        );
+
+        request_update.spline.write(UserUpdatedSplineEvent {
+            entity,
+            data: self.clone(),
+        });
+    }
+}
+
+/// The path is read straight from IdentityData wherever it is used, so there is nothing to rebuild
+pub fn update_spline_system(mut reader: EventReader<UserUpdatedSplineEvent>) {
+    for UserUpdatedSplineEvent {
+        entity: requested_entity,
+        data: _new,
+    } in reader.read()
+    {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Heard spline update event: {}",
+            requested_entity
+        );
+    }
+}
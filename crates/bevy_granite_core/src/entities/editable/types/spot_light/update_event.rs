@@ -0,0 +1,78 @@
+use crate::{
+    entities::editable::{RequestEntityUpdateFromClass, UserUpdatedSpotLightEvent},
+    SpotLightData,
+};
+use bevy::{
+    color::Color,
+    ecs::{entity::Entity, event::EventReader, system::Query},
+    pbr::SpotLight,
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+impl SpotLightData {
+    /// Request an entity update with this data
+    pub fn push_to_entity(
+        &self,
+        entity: Entity,
+        request_update: &mut RequestEntityUpdateFromClass,
+    ) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Requesting spot light entity update"
+        );
+
+        request_update.spot_light.write(UserUpdatedSpotLightEvent {
+            entity,
+            data: self.clone(),
+        });
+    }
+
+    /// Bevy requires inner <= outer <= PI/2, so clamp here instead of trusting save data
+    pub fn to_bevy(&self) -> SpotLight {
+        let outer_angle = self.outer_angle.clamp(0.0, std::f32::consts::FRAC_PI_2);
+        SpotLight {
+            color: Color::linear_rgb(self.color.0, self.color.1, self.color.2),
+            intensity: self.intensity,
+            range: self.range,
+            radius: self.radius,
+            inner_angle: self.inner_angle.clamp(0.0, outer_angle),
+            outer_angle,
+            shadows_enabled: self.shadows_enabled,
+            shadow_depth_bias: self.shadow_depth_bias,
+            shadow_normal_bias: self.shadow_normal_bias,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn update_spot_light_system(
+    mut reader: EventReader<UserUpdatedSpotLightEvent>,
+    mut query: Query<(Entity, &mut SpotLight)>,
+) {
+    for UserUpdatedSpotLightEvent {
+        entity: requested_entity,
+        data: new,
+    } in reader.read()
+    {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Heard spot light update event: {}",
+            requested_entity
+        );
+        if let Ok((_entity, mut spot_light)) = query.get_mut(*requested_entity) {
+            *spot_light = new.to_bevy();
+        } else {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Entity,
+                "Could not find spot light on: {}",
+                requested_entity
+            );
+        }
+    }
+}
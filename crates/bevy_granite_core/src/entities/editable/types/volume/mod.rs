@@ -0,0 +1,144 @@
+use crate::{
+    entities::{
+        editable::{GraniteType, RequestEntityUpdateFromClass},
+        EntitySaveReadyData,
+    },
+    AvailableEditableMaterials, ClassCategory, PromptData,
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        entity::Entity,
+        event::Event,
+        system::{Commands, Res, ResMut},
+    },
+    math::Vec3,
+    pbr::StandardMaterial,
+    reflect::Reflect,
+    render::mesh::Mesh,
+    transform::components::Transform,
+};
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+pub mod creation;
+pub mod overlap;
+pub mod plugin;
+pub mod ui;
+pub mod update_event;
+
+pub use overlap::*;
+pub use plugin::*;
+pub use update_event::*;
+
+/// Internal event thats called when user edits UI Volume variables
+#[derive(Event)]
+pub struct UserUpdatedVolumeEvent {
+    pub entity: Entity,
+    pub data: Volume,
+}
+
+/// Shape of the region a volume covers, centered on the entity
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VolumeShape {
+    #[default]
+    Box,
+    Sphere,
+    Capsule,
+}
+
+impl VolumeShape {
+    pub fn all() -> [VolumeShape; 3] {
+        [VolumeShape::Box, VolumeShape::Sphere, VolumeShape::Capsule]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            VolumeShape::Box => "Box",
+            VolumeShape::Sphere => "Sphere",
+            VolumeShape::Capsule => "Capsule",
+        }
+    }
+}
+
+/// Actual serialized class data thats stored inside IdentityData
+/// An invisible trigger region. Entities with VolumeActivator fire VolumeEntered/VolumeExited as they cross it
+/// Only the field matching the shape is used: half_extents for Box, radius for Sphere, radius and half_length for Capsule
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct Volume {
+    pub shape: VolumeShape,
+    pub half_extents: Vec3,
+    pub radius: f32,
+    /// Half the length of the capsule's straight section along local Y
+    pub half_length: f32,
+    /// Disabled volumes keep their data but never fire events
+    pub enabled: bool,
+}
+
+impl Default for Volume {
+    fn default() -> Self {
+        Self {
+            shape: VolumeShape::default(),
+            half_extents: Vec3::splat(1.0),
+            radius: 1.0,
+            half_length: 0.5,
+            enabled: true,
+        }
+    }
+}
+
+impl GraniteType for Volume {
+    fn type_name(&self) -> String {
+        "Volume".to_string()
+    }
+
+    fn type_abv(&self) -> String {
+        "Volume".to_string()
+    }
+
+    fn category(&self) -> ClassCategory {
+        ClassCategory::Gameplay
+    }
+
+    fn get_embedded_icon_bytes(&self) -> Option<&'static [u8]> {
+        Some(include_bytes!("Volume.png"))
+    }
+
+    fn get_icon_filename(&self) -> Option<&'static str> {
+        Some("Volume.png")
+    }
+
+    fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        _standard_materials: ResMut<Assets<StandardMaterial>>,
+        _meshes: ResMut<Assets<Mesh>>,
+        _available_materials: ResMut<AvailableEditableMaterials>,
+        _asset_server: Res<AssetServer>,
+        _maybe_prompt_data: Option<PromptData>,
+    ) -> Entity {
+        Volume::spawn_from_new_identity(self, commands, transform)
+    }
+
+    fn spawn_from_save_data(
+        &self,
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        _standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        _meshes: &mut ResMut<Assets<Mesh>>,
+        _available_materials: &mut ResMut<AvailableEditableMaterials>,
+        _asset_server: &Res<AssetServer>,
+    ) -> Entity {
+        Volume::spawn_from_save_data(save_data, commands)
+    }
+
+    fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
+        self.push_to_entity(entity, request_update)
+    }
+
+    fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        self.edit_via_ui(ui, spacing)
+    }
+}
@@ -0,0 +1,144 @@
+use super::{Volume, VolumeShape};
+use crate::{GraniteTypes, IdentityData};
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        event::{Event, EventWriter},
+        query::With,
+        resource::Resource,
+        system::{Query, ResMut},
+    },
+    math::Vec3,
+    prelude::{
+        ReflectComponent, ReflectDefault, ReflectDeserialize, ReflectFromReflect, ReflectSerialize,
+    },
+    reflect::Reflect,
+    transform::components::GlobalTransform,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Tag an entity with this so volumes report it entering and leaving
+/// Only its origin is tested, so give large actors a child activator where it matters
+#[derive(Reflect, Serialize, Deserialize, Debug, Clone, Component, Default, PartialEq)]
+#[reflect(Component, Serialize, Deserialize, Default, FromReflect)]
+pub struct VolumeActivator;
+
+/// Fired the frame an activator's origin moves inside a volume
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VolumeEntered {
+    pub volume: Entity,
+    pub entity: Entity,
+}
+
+/// Fired the frame an activator leaves a volume
+/// Also fired when the activator, or the volume itself, is despawned or disabled while inside
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VolumeExited {
+    pub volume: Entity,
+    pub entity: Entity,
+}
+
+/// Activators currently inside each volume, used to turn overlap into enter/exit edges
+#[derive(Resource, Default)]
+pub struct VolumeOverlapState {
+    pub occupants: HashMap<Entity, HashSet<Entity>>,
+}
+
+impl VolumeOverlapState {
+    /// Whether the entity was inside the volume as of the last overlap check
+    pub fn is_inside(&self, volume: Entity, entity: Entity) -> bool {
+        self.occupants
+            .get(&volume)
+            .is_some_and(|occupants| occupants.contains(&entity))
+    }
+}
+
+impl Volume {
+    /// Whether a point in the volume's local space is inside its shape
+    pub fn contains_local_point(&self, point: Vec3) -> bool {
+        match self.shape {
+            VolumeShape::Box => {
+                let extents = self.half_extents.abs();
+                point.abs().cmple(extents).all()
+            }
+            VolumeShape::Sphere => point.length_squared() <= self.radius * self.radius,
+            VolumeShape::Capsule => {
+                let half_length = self.half_length.max(0.0);
+                let closest = Vec3::new(0.0, point.y.clamp(-half_length, half_length), 0.0);
+                point.distance_squared(closest) <= self.radius * self.radius
+            }
+        }
+    }
+
+    /// Whether a world point is inside the volume placed with the given transform
+    /// Scale is respected, so a non-uniformly scaled sphere tests as an ellipsoid
+    pub fn contains_point(&self, transform: &GlobalTransform, point: Vec3) -> bool {
+        let local = transform.affine().inverse().transform_point3(point);
+        self.contains_local_point(local)
+    }
+}
+
+/// Tests every activator against every volume and fires events on the changes since last frame
+/// Runs after transform propagation so moves made this frame are seen this frame
+pub fn volume_overlap_system(
+    mut state: ResMut<VolumeOverlapState>,
+    volume_query: Query<(Entity, &IdentityData, &GlobalTransform)>,
+    activator_query: Query<(Entity, &GlobalTransform), With<VolumeActivator>>,
+    mut entered_writer: EventWriter<VolumeEntered>,
+    mut exited_writer: EventWriter<VolumeExited>,
+) {
+    let mut seen = HashSet::new();
+    for (volume_entity, identity, transform) in volume_query.iter() {
+        let GraniteTypes::Volume(volume) = &identity.class else {
+            continue;
+        };
+        seen.insert(volume_entity);
+
+        let to_local = transform.affine().inverse();
+        let inside: HashSet<Entity> = if volume.enabled {
+            activator_query
+                .iter()
+                .filter(|(entity, activator_transform)| {
+                    *entity != volume_entity
+                        && volume.contains_local_point(
+                            to_local.transform_point3(activator_transform.translation()),
+                        )
+                })
+                .map(|(entity, _)| entity)
+                .collect()
+        } else {
+            HashSet::new()
+        };
+
+        let previous = state.occupants.entry(volume_entity).or_default();
+        for entity in previous.difference(&inside) {
+            exited_writer.write(VolumeExited {
+                volume: volume_entity,
+                entity: *entity,
+            });
+        }
+        for entity in inside.difference(previous) {
+            entered_writer.write(VolumeEntered {
+                volume: volume_entity,
+                entity: *entity,
+            });
+        }
+        *previous = inside;
+    }
+
+    // Volumes that were despawned or changed class still owe exits so every enter gets one
+    state.occupants.retain(|volume, occupants| {
+        if seen.contains(volume) {
+            return true;
+        }
+        for entity in occupants.iter() {
+            exited_writer.write(VolumeExited {
+                volume: *volume,
+                entity: *entity,
+            });
+        }
+        false
+    });
+}
@@ -0,0 +1,42 @@
+use super::{
+    update_volume_system, volume_overlap_system, UserUpdatedVolumeEvent, Volume, VolumeActivator,
+    VolumeEntered, VolumeExited, VolumeOverlapState, VolumeShape,
+};
+use crate::entities::BridgeTag;
+use bevy::{
+    app::{App, Plugin, PostUpdate, Update},
+    ecs::schedule::IntoScheduleConfigs,
+    transform::TransformSystem,
+};
+
+pub struct VolumePlugin;
+impl Plugin for VolumePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            //
+            // Event
+            //
+            .add_event::<UserUpdatedVolumeEvent>()
+            .add_event::<VolumeEntered>()
+            .add_event::<VolumeExited>()
+            //
+            // Register
+            //
+            .register_type::<Volume>()
+            .register_type::<VolumeShape>()
+            .register_type::<VolumeActivator>()
+            .register_type_data::<VolumeActivator, BridgeTag>()
+            //
+            // Resources
+            //
+            .insert_resource(VolumeOverlapState::default())
+            //
+            // Schedule system
+            //
+            .add_systems(Update, update_volume_system)
+            .add_systems(
+                PostUpdate,
+                volume_overlap_system.after(TransformSystem::TransformPropagate),
+            );
+    }
+}
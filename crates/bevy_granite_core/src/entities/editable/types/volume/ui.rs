@@ -0,0 +1,95 @@
+use super::{Volume, VolumeShape};
+use crate::GraniteType;
+use bevy_egui::egui;
+
+impl Volume {
+    /// Function to edit self's data via UI side panel
+    /// We have a sister system that pushes changes to world entity - can be found inside 'update_event.rs'
+    /// When true, sends an update to propagate these vars to the world's entity
+    pub fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        let type_name = self.type_name();
+        let data = self;
+        let large_spacing = spacing.1;
+        ui.label(egui::RichText::new(type_name).italics());
+        ui.add_space(large_spacing);
+
+        let mut changed = false;
+        egui::Grid::new("volume_data_grid")
+            .num_columns(2)
+            .spacing([large_spacing, large_spacing])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Shape:");
+                egui::ComboBox::from_id_salt("volume_shape_dropdown")
+                    .selected_text(data.shape.name())
+                    .show_ui(ui, |ui| {
+                        for shape in VolumeShape::all() {
+                            changed |= ui
+                                .selectable_value(&mut data.shape, shape, shape.name())
+                                .changed();
+                        }
+                    });
+                ui.end_row();
+
+                match data.shape {
+                    VolumeShape::Box => {
+                        ui.label("Half Extents:");
+                        ui.horizontal(|ui| {
+                            for axis in [
+                                &mut data.half_extents.x,
+                                &mut data.half_extents.y,
+                                &mut data.half_extents.z,
+                            ] {
+                                changed |= ui
+                                    .add(egui::DragValue::new(axis).range(0.0..=1000.0).speed(0.01))
+                                    .changed();
+                            }
+                        });
+                        ui.end_row();
+                    }
+                    VolumeShape::Sphere => {
+                        ui.label("Radius:");
+                        changed |= ui
+                            .add(
+                                egui::DragValue::new(&mut data.radius)
+                                    .range(0.0..=1000.0)
+                                    .speed(0.01),
+                            )
+                            .changed();
+                        ui.end_row();
+                    }
+                    VolumeShape::Capsule => {
+                        ui.label("Radius:");
+                        changed |= ui
+                            .add(
+                                egui::DragValue::new(&mut data.radius)
+                                    .range(0.0..=1000.0)
+                                    .speed(0.01),
+                            )
+                            .changed();
+                        ui.end_row();
+
+                        ui.label("Half Length:");
+                        changed |= ui
+                            .add(
+                                egui::DragValue::new(&mut data.half_length)
+                                    .range(0.0..=1000.0)
+                                    .speed(0.01),
+                            )
+                            .on_hover_text("Half the straight section, along local Y")
+                            .changed();
+                        ui.end_row();
+                    }
+                }
+
+                ui.label("Enabled:");
+                changed |= ui
+                    .checkbox(&mut data.enabled, "")
+                    .on_hover_text("Fires events for entities with VolumeActivator")
+                    .changed();
+                ui.end_row();
+            });
+
+        changed
+    }
+}
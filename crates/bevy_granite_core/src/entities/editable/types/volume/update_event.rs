@@ -0,0 +1,43 @@
+use crate::{
+    entities::editable::{RequestEntityUpdateFromClass, UserUpdatedVolumeEvent},
+    Volume,
+};
+use bevy::ecs::{entity::Entity, event::EventReader};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+impl Volume {
+    pub fn push_to_entity(
+        &self,
+        entity: Entity,
+        request_update: &mut RequestEntityUpdateFromClass,
+    ) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Requesting volume entity update"
+        );
+
+        request_update.volume.write(UserUpdatedVolumeEvent {
+            entity,
+            data: self.clone(),
+        });
+    }
+}
+
+/// The overlap check reads the shape straight from IdentityData, so there is nothing to rebuild
+pub fn update_volume_system(mut reader: EventReader<UserUpdatedVolumeEvent>) {
+    for UserUpdatedVolumeEvent {
+        entity: requested_entity,
+        data: _new,
+    } in reader.read()
+    {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Heard volume update event: {}",
+            requested_entity
+        );
+    }
+}
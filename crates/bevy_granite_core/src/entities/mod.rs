@@ -13,6 +13,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 pub mod component_editor;
+pub mod csg;
 pub mod deserialize;
 pub mod editable;
 pub mod generate_tangents;
@@ -174,9 +175,16 @@ impl Default for PromptData {
 pub use component_editor::{
     is_bridge_component_check, BridgeTag, ComponentEditor, ExposedToEditor, ReflectedComponent,
 };
+pub use csg::{
+    bake_csg_system, brush_csg_operation, CsgBakeState, CsgBaked, CsgOperand, CsgOperation,
+};
 pub use deserialize::{deserialize_entities, GraniteEditorSerdeEntity};
 pub use editable::{
-    Camera3D, DirLight, Empty, GraniteTypes, PointLightData, RectBrush, VolumetricFog, OBJ,
+    BrushPlane, Camera3D, CameraEnvironment, ConvexBrush, ConvexBrushElement,
+    ConvexBrushElementKind, ConvexBrushPreset, DirLight, Empty, GraniteTypes, PointLightData,
+    Primitive, PrimitiveShape, RectBrush, ShadowCascades, Spline, SplineMode, SplinePoint,
+    SplineSample, SpotLightData, Volume, VolumeActivator, VolumeEntered, VolumeExited,
+    VolumeOverlapState, VolumeShape, VolumetricFog, OBJ,
 };
 pub use generate_tangents::{generate_tangents_system, NeedsTangents};
 pub use lifecycle::{
@@ -184,7 +192,9 @@ pub use lifecycle::{
     despawn_recursive_serializable_entities,
 };
 pub use plugin::EntityPlugin;
-pub use serialize::{serialize_entities, EntitySaveReadyData, SceneData, SceneMetadata};
+pub use serialize::{
+    read_scene_file, serialize_entities, EntitySaveReadyData, SceneData, SceneMetadata,
+};
 
 // Im adding this so you cant select the editor camera
 // and to stop a crash because you can select a gizmo that then despawns its self
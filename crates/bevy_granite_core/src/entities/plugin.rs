@@ -1,9 +1,20 @@
 use super::{
-    despawn_entities_system, despawn_entities_by_source_system, generate_tangents_system, BridgeTag, ComponentEditor, HasRuntimeData,
-    IdentityData, InternalNote, MainCamera, SpawnSource, UICamera
+    bake_csg_system, despawn_entities_system, despawn_entities_by_source_system, generate_tangents_system, BridgeTag, ComponentEditor, HasRuntimeData,
+    CsgBakeState, CsgOperation, IdentityData, InternalNote, MainCamera, SpawnSource, UICamera
+};
+use crate::entities::{
+    editable::{
+        types::{
+            convex_brush::update_convex_brush_system, rect_brush::update_rectangle_brush_system,
+        },
+        ClassTypePlugin,
+    },
+    PromptImportSettings,
+};
+use bevy::{
+    app::{App, Plugin, Update},
+    ecs::schedule::IntoScheduleConfigs,
 };
-use crate::entities::{editable::ClassTypePlugin, PromptImportSettings};
-use bevy::app::{App, Plugin, Update};
 
 pub struct EntityPlugin;
 impl Plugin for EntityPlugin {
@@ -25,14 +36,22 @@ impl Plugin for EntityPlugin {
             .register_type_data::<InternalNote, BridgeTag>()
             .register_type::<IdentityData>()
             .register_type::<HasRuntimeData>()
+            .register_type::<CsgOperation>()
             //
             // Resources
             //
             .insert_resource(ComponentEditor::default())
             .insert_resource(PromptImportSettings::default())
+            .insert_resource(CsgBakeState::default())
             //
             // Schedule system
             //
-            .add_systems(Update, (despawn_entities_system, despawn_entities_by_source_system, generate_tangents_system));
+            .add_systems(Update, (despawn_entities_system, despawn_entities_by_source_system, generate_tangents_system))
+            .add_systems(
+                Update,
+                bake_csg_system
+                    .after(update_rectangle_brush_system)
+                    .after(update_convex_brush_system),
+            );
     }
 }
@@ -28,7 +28,7 @@ pub struct SceneData {
     pub entities: Vec<EntitySaveReadyData>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct EntitySaveReadyData {
     pub identity: IdentityData,
     pub transform: TransformData,
@@ -47,7 +47,7 @@ pub fn serialize_entities(world_state: WorldState, path: Option<String>) {
 
     // Read original file data for PreserveDiskFull entities
     let original_entities = if let Some(ref path_str) = path {
-        read_existing_file_data(path_str)
+        read_scene_file(path_str)
     } else {
         Vec::new()
     };
@@ -220,8 +220,8 @@ fn round_quat(q: Quat) -> Quat {
     Quat::from_xyzw(round3(q.x), round3(q.y), round3(q.z), round3(q.w))
 }
 
-/// Read existing file data to get original entity data for PreserveDiskFull entities
-fn read_existing_file_data(path: &str) -> Vec<EntitySaveReadyData> {
+/// Reads a .scene file from disk into save ready data. Returns empty if missing or unreadable
+pub fn read_scene_file(path: &str) -> Vec<EntitySaveReadyData> {
     let file_path = Path::new(path);
     if !file_path.exists() {
         return Vec::new();
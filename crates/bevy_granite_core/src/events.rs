@@ -1,5 +1,5 @@
 use bevy::{prelude::Event, transform::components::Transform};
-use crate::entities::SaveSettings;
+use crate::{assets::AssetRenamePlan, entities::SaveSettings};
 
 #[derive(Event)]
 pub struct RuntimeDataReadyEvent(pub String);
@@ -13,6 +13,9 @@ pub struct WorldLoadSuccessEvent(pub String);
 #[derive(Event)]
 pub struct WorldSaveSuccessEvent(pub String);
 
+#[derive(Event)]
+pub struct AssetRenameSuccessEvent(pub AssetRenamePlan);
+
 // User callable events begin with "Request"
 
 #[derive(Event)]
@@ -31,4 +34,8 @@ pub struct RequestDespawnSerializableEntities;
 #[derive(Event)]
 pub struct RequestDespawnBySource(pub String);
 
+/// Rename or move an asset (from, to) under /assets and rewrite every .scene and .mat that references it
+#[derive(Event)]
+pub struct RequestAssetRenameEvent(pub String, pub String);
+
 
@@ -24,8 +24,10 @@ use crate::world::WorldPlugin;
 
 // Re-exports
 pub use assets::{
-    get_material_from_path, load_texture_with_repeat, material_from_path_into_scene,
-    materials_from_folder_into_scene, AvailableEditableMaterials, EditableMaterial,
+    apply_asset_rename, build_asset_dependency_report, get_material_from_path,
+    load_texture_with_repeat, material_from_path_into_scene, materials_from_folder_into_scene,
+    plan_asset_rename, rename_asset, AssetDependency, AssetDependencyKind, AssetDependencyReport,
+    AssetManifest, AssetRenameEdit, AssetRenamePlan, AvailableEditableMaterials, EditableMaterial,
     EditableMaterialError, EditableMaterialField, MaterialData, NewEditableMaterial,
     RequiredMaterialData, RequiredMaterialDataMut, StandardMaterialDef,
 };
@@ -41,22 +43,27 @@ pub trait UICallableEventProvider {
 }
 
 pub use entities::{
-    BridgeTag, Camera3D, ClassCategory, ComponentEditor, DirLight, EditorIgnore,
-    GraniteEditorSerdeEntity, GraniteType, GraniteTypes, HasRuntimeData, IdentityData, MainCamera,
-    MaterialNameSource, NeedsTangents, PointLightData, PromptData, PromptImportSettings, RectBrush,
-    ReflectedComponent, SaveSettings, SpawnSource, TransformData, TreeHiddenEntity, UICamera,
-    VolumetricFog, OBJ
+    BridgeTag, BrushPlane, Camera3D, CameraEnvironment, ClassCategory, ComponentEditor,
+    ConvexBrush, ConvexBrushElement, ConvexBrushElementKind, ConvexBrushPreset, CsgBaked,
+    CsgOperand, CsgOperation, DirLight, EditorIgnore, GraniteEditorSerdeEntity, GraniteType,
+    GraniteTypes, HasRuntimeData, IdentityData, MainCamera, MaterialNameSource, NeedsTangents,
+    PointLightData, Primitive, PrimitiveShape, PromptData, PromptImportSettings, RectBrush,
+    ReflectedComponent, SaveSettings, ShadowCascades, SpawnSource, Spline, SplineMode, SplinePoint,
+    SplineSample, SpotLightData, TransformData, TreeHiddenEntity, UICamera, Volume,
+    VolumeActivator, VolumeEntered, VolumeExited, VolumeOverlapState, VolumeShape, VolumetricFog,
+    OBJ
 };
 pub use events::{
-    CollectRuntimeDataEvent, RequestDespawnBySource, RequestDespawnSerializableEntities,
-    RequestLoadEvent, RequestReloadEvent, RequestSaveEvent, RuntimeDataReadyEvent,
-    WorldLoadSuccessEvent, WorldSaveSuccessEvent,
+    AssetRenameSuccessEvent, CollectRuntimeDataEvent, RequestAssetRenameEvent,
+    RequestDespawnBySource, RequestDespawnSerializableEntities, RequestLoadEvent,
+    RequestReloadEvent, RequestSaveEvent, RuntimeDataReadyEvent, WorldLoadSuccessEvent,
+    WorldSaveSuccessEvent,
 };
 pub use setup::RegisteredTypeNames;
 pub use shared::{
-    absolute_asset_to_rel, get_current_scene_version, get_minimum_scene_version,
-    is_scene_version_compatible, mouse_to_world_delta, rel_asset_to_absolute, CursorWindowPos,
-    IconEntity, IconProxy, IconType, InputTypes, UserInput,
+    absolute_asset_to_rel, get_current_scene_version, get_minimum_scene_version, is_modifier,
+    is_scene_version_compatible, key_name, mouse_to_world_delta, rel_asset_to_absolute,
+    CursorWindowPos, IconEntity, IconProxy, IconType, InputTypes, KeyChord, UserInput,
 };
 
 // Bevy Granite Core plugin
@@ -94,6 +101,8 @@ impl Plugin for BevyGraniteCore {
             .add_event::<CollectRuntimeDataEvent>()
             .add_event::<RuntimeDataReadyEvent>()
             .add_event::<RequestReloadEvent>()
+            .add_event::<RequestAssetRenameEvent>()
+            .add_event::<AssetRenameSuccessEvent>()
             //
             // Resources
             //
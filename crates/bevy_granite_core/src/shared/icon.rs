@@ -6,6 +6,7 @@ pub enum IconType {
     Empty,
     Camera3D,
     PointLight,
+    SpotLight,
     DirectionalLight,
 }
 
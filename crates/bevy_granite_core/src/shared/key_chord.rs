@@ -0,0 +1,239 @@
+use bevy::input::{keyboard::KeyCode, ButtonInput};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Keys that can be bound, named after the US layout since KeyCode is the physical key
+const KEY_NAMES: &[(KeyCode, &str)] = &[
+    (KeyCode::KeyA, "A"),
+    (KeyCode::KeyB, "B"),
+    (KeyCode::KeyC, "C"),
+    (KeyCode::KeyD, "D"),
+    (KeyCode::KeyE, "E"),
+    (KeyCode::KeyF, "F"),
+    (KeyCode::KeyG, "G"),
+    (KeyCode::KeyH, "H"),
+    (KeyCode::KeyI, "I"),
+    (KeyCode::KeyJ, "J"),
+    (KeyCode::KeyK, "K"),
+    (KeyCode::KeyL, "L"),
+    (KeyCode::KeyM, "M"),
+    (KeyCode::KeyN, "N"),
+    (KeyCode::KeyO, "O"),
+    (KeyCode::KeyP, "P"),
+    (KeyCode::KeyQ, "Q"),
+    (KeyCode::KeyR, "R"),
+    (KeyCode::KeyS, "S"),
+    (KeyCode::KeyT, "T"),
+    (KeyCode::KeyU, "U"),
+    (KeyCode::KeyV, "V"),
+    (KeyCode::KeyW, "W"),
+    (KeyCode::KeyX, "X"),
+    (KeyCode::KeyY, "Y"),
+    (KeyCode::KeyZ, "Z"),
+    (KeyCode::Digit0, "0"),
+    (KeyCode::Digit1, "1"),
+    (KeyCode::Digit2, "2"),
+    (KeyCode::Digit3, "3"),
+    (KeyCode::Digit4, "4"),
+    (KeyCode::Digit5, "5"),
+    (KeyCode::Digit6, "6"),
+    (KeyCode::Digit7, "7"),
+    (KeyCode::Digit8, "8"),
+    (KeyCode::Digit9, "9"),
+    (KeyCode::F1, "F1"),
+    (KeyCode::F2, "F2"),
+    (KeyCode::F3, "F3"),
+    (KeyCode::F4, "F4"),
+    (KeyCode::F5, "F5"),
+    (KeyCode::F6, "F6"),
+    (KeyCode::F7, "F7"),
+    (KeyCode::F8, "F8"),
+    (KeyCode::F9, "F9"),
+    (KeyCode::F10, "F10"),
+    (KeyCode::F11, "F11"),
+    (KeyCode::F12, "F12"),
+    (KeyCode::Delete, "Del"),
+    (KeyCode::Backspace, "Backspace"),
+    (KeyCode::Insert, "Insert"),
+    (KeyCode::Home, "Home"),
+    (KeyCode::End, "End"),
+    (KeyCode::PageUp, "PageUp"),
+    (KeyCode::PageDown, "PageDown"),
+    (KeyCode::ArrowUp, "Up"),
+    (KeyCode::ArrowDown, "Down"),
+    (KeyCode::ArrowLeft, "Left"),
+    (KeyCode::ArrowRight, "Right"),
+    (KeyCode::Space, "Space"),
+    (KeyCode::Enter, "Enter"),
+    (KeyCode::Tab, "Tab"),
+    (KeyCode::Escape, "Esc"),
+    (KeyCode::Backquote, "`"),
+    (KeyCode::Minus, "-"),
+    (KeyCode::Equal, "="),
+    (KeyCode::BracketLeft, "["),
+    (KeyCode::BracketRight, "]"),
+    (KeyCode::Backslash, "\\"),
+    (KeyCode::Semicolon, ";"),
+    (KeyCode::Quote, "'"),
+    (KeyCode::Comma, ","),
+    (KeyCode::Period, "."),
+    (KeyCode::Slash, "/"),
+];
+
+pub fn key_name(key: KeyCode) -> Option<&'static str> {
+    KEY_NAMES
+        .iter()
+        .find(|(code, _)| *code == key)
+        .map(|(_, name)| *name)
+}
+
+fn key_from_name(name: &str) -> Option<KeyCode> {
+    KEY_NAMES
+        .iter()
+        .find(|(_, key_name)| key_name.eq_ignore_ascii_case(name))
+        .map(|(code, _)| *code)
+}
+
+pub fn is_modifier(key: KeyCode) -> bool {
+    matches!(
+        key,
+        KeyCode::ControlLeft
+            | KeyCode::ControlRight
+            | KeyCode::ShiftLeft
+            | KeyCode::ShiftRight
+            | KeyCode::AltLeft
+            | KeyCode::AltRight
+            | KeyCode::SuperLeft
+            | KeyCode::SuperRight
+    )
+}
+
+/// A key plus the modifiers that must be held with it
+/// Stored in the config as text like "Ctrl+Shift+Z"
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeyChord {
+    pub key: KeyCode,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl KeyChord {
+    pub const fn new(key: KeyCode) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    pub const fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    pub const fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Chord for a key with whatever modifiers are held right now, None for keys that can't be bound
+    pub fn from_input(key: KeyCode, keyboard: &ButtonInput<KeyCode>) -> Option<Self> {
+        key_name(key)?;
+        Some(Self {
+            key,
+            ctrl: keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            shift: keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            alt: keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+        })
+    }
+
+    /// Modifiers have to match exactly, so Ctrl+Z doesn't also fire Ctrl+Shift+Z
+    pub fn just_pressed(&self, keyboard: &ButtonInput<KeyCode>) -> bool {
+        keyboard.just_pressed(self.key) && self.modifiers_held(keyboard)
+    }
+
+    /// Held rather than just pressed, for keys that act as a modifier while dragging
+    pub fn pressed(&self, keyboard: &ButtonInput<KeyCode>) -> bool {
+        keyboard.pressed(self.key) && self.modifiers_held(keyboard)
+    }
+
+    /// True if nothing but this chord's key and modifiers is held
+    pub fn held_alone(&self, keyboard: &ButtonInput<KeyCode>) -> bool {
+        keyboard
+            .get_pressed()
+            .all(|key| *key == self.key || is_modifier(*key))
+    }
+
+    fn modifiers_held(&self, keyboard: &ButtonInput<KeyCode>) -> bool {
+        self.ctrl == keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+            && self.shift == keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+            && self.alt == keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight])
+    }
+
+    fn join(&self, separator: &str) -> String {
+        let mut parts = Vec::new();
+        if self.ctrl {
+            parts.push("Ctrl");
+        }
+        if self.shift {
+            parts.push("Shift");
+        }
+        if self.alt {
+            parts.push("Alt");
+        }
+        parts.push(key_name(self.key).unwrap_or("?"));
+        parts.join(separator)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.join(" + "))
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut chord: Option<KeyChord> = None;
+        let (mut ctrl, mut shift, mut alt) = (false, false, false);
+        for part in text.split('+').map(str::trim) {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => ctrl = true,
+                "shift" => shift = true,
+                "alt" => alt = true,
+                _ => {
+                    if chord.is_some() {
+                        return Err(format!("'{}' has more than one key", text));
+                    }
+                    let key = key_from_name(part)
+                        .ok_or_else(|| format!("Unknown key '{}' in '{}'", part, text))?;
+                    chord = Some(KeyChord::new(key));
+                }
+            }
+        }
+        let mut chord = chord.ok_or_else(|| format!("'{}' has no key", text))?;
+        chord.ctrl = ctrl;
+        chord.shift = shift;
+        chord.alt = alt;
+        Ok(chord)
+    }
+}
+
+impl TryFrom<String> for KeyChord {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<KeyChord> for String {
+    fn from(chord: KeyChord) -> Self {
+        chord.join("+")
+    }
+}
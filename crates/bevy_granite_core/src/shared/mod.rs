@@ -1,4 +1,5 @@
 pub mod icon;
+pub mod key_chord;
 pub mod plugin;
 pub mod user_input;
 pub mod version;
@@ -8,6 +9,7 @@ pub mod file;
 pub use file::*;
 pub use file_browser::{asset_file_browser, asset_file_browser_multiple};
 pub use icon::{IconEntity, IconProxy, IconType};
+pub use key_chord::{is_modifier, key_name, KeyChord};
 pub use plugin::SharedPlugin;
 pub use user_input::{
     capture_input_events, mouse_to_world_delta, update_mouse_pos, CursorWindowPos, InputTypes,
@@ -41,6 +41,17 @@ pub struct UserInput {
     pub key_p: UserButtonState,
     pub key_delete: UserButtonState,
     pub key_space: UserButtonState,
+    pub key_tab: UserButtonState,
+    pub key_1: UserButtonState,
+    pub key_2: UserButtonState,
+    pub key_3: UserButtonState,
+    pub key_g: UserButtonState,
+    pub key_t: UserButtonState,
+    pub key_x: UserButtonState,
+    pub key_y: UserButtonState,
+    pub key_enter: UserButtonState,
+    pub key_escape: UserButtonState,
+    pub key_v: UserButtonState,
 }
 
 #[derive(PartialEq, Default, Clone, Copy, Debug)]
@@ -160,6 +171,17 @@ pub fn capture_input_events(
     let mut key_z = user_input.key_z;
     let mut key_f = user_input.key_f;
     let mut key_space = user_input.key_space;
+    let mut key_tab = user_input.key_tab;
+    let mut key_1 = user_input.key_1;
+    let mut key_2 = user_input.key_2;
+    let mut key_3 = user_input.key_3;
+    let mut key_g = user_input.key_g;
+    let mut key_t = user_input.key_t;
+    let mut key_x = user_input.key_x;
+    let mut key_y = user_input.key_y;
+    let mut key_enter = user_input.key_enter;
+    let mut key_escape = user_input.key_escape;
+    let mut key_v = user_input.key_v;
 
     // Update state
     mouse_left.update_mouse(&mouse_input, MouseButton::Left, &mut user_input);
@@ -188,6 +210,17 @@ pub fn capture_input_events(
     key_q.update_key(&keyboard_input, KeyCode::KeyQ, &mut user_input);
     key_f.update_key(&keyboard_input, KeyCode::KeyF, &mut user_input);
     key_space.update_key(&keyboard_input, KeyCode::Space, &mut user_input);
+    key_tab.update_key(&keyboard_input, KeyCode::Tab, &mut user_input);
+    key_1.update_key(&keyboard_input, KeyCode::Digit1, &mut user_input);
+    key_2.update_key(&keyboard_input, KeyCode::Digit2, &mut user_input);
+    key_3.update_key(&keyboard_input, KeyCode::Digit3, &mut user_input);
+    key_g.update_key(&keyboard_input, KeyCode::KeyG, &mut user_input);
+    key_t.update_key(&keyboard_input, KeyCode::KeyT, &mut user_input);
+    key_x.update_key(&keyboard_input, KeyCode::KeyX, &mut user_input);
+    key_y.update_key(&keyboard_input, KeyCode::KeyY, &mut user_input);
+    key_enter.update_key(&keyboard_input, KeyCode::Enter, &mut user_input);
+    key_escape.update_key(&keyboard_input, KeyCode::Escape, &mut user_input);
+    key_v.update_key(&keyboard_input, KeyCode::KeyV, &mut user_input);
 
     // Write the updated states back
     user_input.key_delete = key_delete;
@@ -213,6 +246,17 @@ pub fn capture_input_events(
     user_input.key_q = key_q;
     user_input.key_z = key_z;
     user_input.key_space = key_space;
+    user_input.key_tab = key_tab;
+    user_input.key_1 = key_1;
+    user_input.key_2 = key_2;
+    user_input.key_3 = key_3;
+    user_input.key_g = key_g;
+    user_input.key_t = key_t;
+    user_input.key_x = key_x;
+    user_input.key_y = key_y;
+    user_input.key_enter = key_enter;
+    user_input.key_escape = key_escape;
+    user_input.key_v = key_v;
     user_input.alt_left = alt_left;
 
     if let Ok(ctx) = contexts.ctx_mut() {
@@ -9,7 +9,7 @@ use bevy::{asset::io::file::FileAssetReader, prelude::ResMut};
 use bevy_granite_core::{
     absolute_asset_to_rel,
     events::{
-        RequestDespawnBySource, RequestDespawnSerializableEntities, 
+        AssetRenameSuccessEvent, RequestDespawnBySource, RequestDespawnSerializableEntities,
         WorldLoadSuccessEvent, WorldSaveSuccessEvent,
     },
 };
@@ -32,8 +32,18 @@ pub fn update_active_world_system(
     mut entities_despawned_reader: EventReader<RequestDespawnSerializableEntities>,
     mut entities_despawned_by_source_reader: EventReader<RequestDespawnBySource>,
     mut set_active_world_reader: EventReader<SetActiveWorld>,
+    mut asset_rename_reader: EventReader<AssetRenameSuccessEvent>,
     mut editor_state: ResMut<EditorState>,
 ) {
+    for AssetRenameSuccessEvent(plan) in asset_rename_reader.read() {
+        if editor_state.loaded_sources.remove(&plan.from) {
+            editor_state.loaded_sources.insert(plan.to.clone());
+        }
+        if editor_state.current_file.as_ref() == Some(&plan.from) {
+            editor_state.current_file = Some(plan.to.clone());
+        }
+    }
+
     for RequestDespawnSerializableEntities in entities_despawned_reader.read() {
         editor_state.current_file = None;
         editor_state.loaded_sources.clear();
@@ -0,0 +1,341 @@
+use crate::{
+    editor_state::EditorState,
+    history::EntitySnapshot,
+    interface::events::{RequestCopyEntities, RequestPasteEntities},
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        entity::Entity,
+        event::{EventReader, EventWriter},
+        query::With,
+        system::{Commands, Query, Res, ResMut, SystemParam},
+        world::World,
+    },
+    pbr::StandardMaterial,
+    picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings},
+    prelude::{AppTypeRegistry, ChildOf, Children, Resource},
+    render::{camera::Camera, mesh::Mesh},
+    transform::components::GlobalTransform,
+};
+use bevy_egui::{egui, EguiContexts};
+use bevy_granite_core::{
+    entities::{GraniteType, SaveSettings, SceneData, SceneMetadata, SpawnSource},
+    get_current_scene_version, is_scene_version_compatible, AvailableEditableMaterials,
+    ComponentEditor, EditorIgnore, IdentityData, TransformData, UserInput,
+};
+use bevy_granite_gizmos::{selection::events::EntityEvent, GizmoCamera, Selected};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use ron::ser::{to_string_pretty, PrettyConfig};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Copied text waiting for the next egui pass, which is where the system clipboard is written
+#[derive(Resource, Default)]
+pub struct EntityClipboard {
+    pub to_copy: Option<String>,
+}
+
+/// Ctrl+C / Ctrl+V arrive as egui copy and paste events, so text fields keep them when focused
+pub fn clipboard_input_system(
+    mut contexts: EguiContexts,
+    mut clipboard: ResMut<EntityClipboard>,
+    mut copy_writer: EventWriter<RequestCopyEntities>,
+    mut paste_writer: EventWriter<RequestPasteEntities>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    if let Some(text) = clipboard.to_copy.take() {
+        ctx.copy_text(text);
+    }
+    if ctx.wants_keyboard_input() {
+        return;
+    }
+    let events = ctx.input(|input| input.events.clone());
+    for event in events {
+        match event {
+            egui::Event::Copy => {
+                copy_writer.write(RequestCopyEntities);
+            }
+            egui::Event::Paste(text) => {
+                paste_writer.write(RequestPasteEntities(text));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Serializes the selected subtrees the same way a scene file stores them
+/// Roots lose their parent and keep their world transform, so they paste anywhere
+pub fn copy_entities_system(
+    mut commands: Commands,
+    mut copy_reader: EventReader<RequestCopyEntities>,
+    selected: Query<Entity, (With<Selected>, With<IdentityData>)>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    identities: Query<(), With<IdentityData>>,
+) {
+    if copy_reader.read().count() == 0 {
+        return;
+    }
+    let roots: Vec<Entity> = selected
+        .iter()
+        .filter(|entity| {
+            !parents
+                .iter_ancestors(*entity)
+                .any(|ancestor| selected.contains(ancestor))
+        })
+        .collect();
+    if roots.is_empty() {
+        log!(
+            LogType::Editor,
+            LogLevel::Warning,
+            LogCategory::Entity,
+            "Nothing selected to copy"
+        );
+        return;
+    }
+    let mut entities = Vec::new();
+    for root in &roots {
+        entities.push(*root);
+        entities.extend(
+            children
+                .iter_descendants(*root)
+                .filter(|child| identities.contains(*child)),
+        );
+    }
+
+    // Need access to world to get components
+    commands.queue(move |world: &mut World| {
+        let mut saved = Vec::new();
+        for entity in entities {
+            let Some(snapshot) = EntitySnapshot::capture(world, entity) else {
+                continue;
+            };
+            let mut data = snapshot.data;
+            if roots.contains(&entity) {
+                let global = world
+                    .get::<GlobalTransform>(entity)
+                    .map(|global| global.compute_transform())
+                    .unwrap_or_else(|| data.transform.to_bevy());
+                data.parent = None;
+                data.transform = TransformData {
+                    position: global.translation,
+                    rotation: global.rotation,
+                    scale: global.scale,
+                };
+            }
+            saved.push(data);
+        }
+
+        let scene = SceneData {
+            metadata: SceneMetadata {
+                format_version: get_current_scene_version(),
+                entity_count: saved.len(),
+            },
+            entities: saved,
+        };
+        match to_string_pretty(&scene, PrettyConfig::default()) {
+            Ok(text) => {
+                log!(
+                    LogType::Editor,
+                    LogLevel::OK,
+                    LogCategory::Entity,
+                    "Copied {} entities to the clipboard",
+                    scene.metadata.entity_count
+                );
+                world.resource_mut::<EntityClipboard>().to_copy = Some(text);
+            }
+            Err(e) => {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Error,
+                    LogCategory::Entity,
+                    "Failed to serialize entities for the clipboard: {}",
+                    e
+                );
+            }
+        }
+    });
+}
+
+/// Spawns clipboard entities with new uuids into the current world
+/// Roots land on whatever is under the cursor, or where they were copied from
+/// Asset stores pasted entities are spawned into
+#[derive(SystemParam)]
+pub struct PasteAssets<'w> {
+    pub standard_materials: ResMut<'w, Assets<StandardMaterial>>,
+    pub meshes: ResMut<'w, Assets<Mesh>>,
+    pub available_materials: ResMut<'w, AvailableEditableMaterials>,
+    pub asset_server: Res<'w, AssetServer>,
+}
+
+/// Finds where under the cursor a paste lands
+#[derive(SystemParam)]
+pub struct PasteCursor<'w, 's> {
+    pub user_input: Res<'w, UserInput>,
+    pub camera_query: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<GizmoCamera>>,
+    pub ignored: Query<'w, 's, &'static EditorIgnore>,
+    pub ray_cast: MeshRayCast<'w, 's>,
+}
+
+impl PasteCursor<'_, '_> {
+    /// First pickable surface under the cursor in the editor camera, None while over the UI
+    pub fn point(&mut self) -> Option<bevy::math::Vec3> {
+        if self.user_input.mouse_over_egui {
+            return None;
+        }
+        let (camera, camera_transform) = self.camera_query.single().ok()?;
+        let ray = camera
+            .viewport_to_world(camera_transform, self.user_input.mouse_pos)
+            .ok()?;
+        let ignored = &self.ignored;
+        let filter = |entity: Entity| {
+            ignored
+                .get(entity)
+                .map_or(true, |ignore| !ignore.contains(EditorIgnore::PICKING))
+        };
+        let settings = MeshRayCastSettings::default().with_filter(&filter);
+        self.ray_cast
+            .cast_ray(ray, &settings)
+            .first()
+            .map(|(_, hit)| hit.point)
+    }
+}
+
+pub fn paste_entities_system(
+    mut commands: Commands,
+    mut paste_reader: EventReader<RequestPasteEntities>,
+    assets: PasteAssets,
+    editor_state: Res<EditorState>,
+    mut cursor: PasteCursor,
+) {
+    let PasteAssets {
+        mut standard_materials,
+        mut meshes,
+        mut available_materials,
+        asset_server,
+    } = assets;
+    for RequestPasteEntities(text) in paste_reader.read() {
+        let Ok(scene) = ron::de::from_str::<SceneData>(text) else {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Clipboard does not contain granite entities"
+            );
+            continue;
+        };
+        if !is_scene_version_compatible(&scene.metadata.format_version) {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Clipboard scene version {} may not be fully compatible with current version",
+                scene.metadata.format_version
+            );
+        }
+        let mut saved = scene.entities;
+        if saved.is_empty() {
+            continue;
+        }
+
+        // New uuids so pasting twice doesn't clash, links between pasted entities follow
+        let new_uuids: HashMap<Uuid, Uuid> = saved
+            .iter()
+            .map(|data| (data.identity.uuid, Uuid::new_v4()))
+            .collect();
+        for data in saved.iter_mut() {
+            data.identity.uuid = new_uuids[&data.identity.uuid];
+            data.parent = data
+                .parent
+                .and_then(|parent| new_uuids.get(&parent).copied());
+        }
+
+        let cursor_point = cursor.point();
+        if let Some(point) = cursor_point {
+            if let Some(anchor) = saved
+                .iter()
+                .find(|data| data.parent.is_none())
+                .map(|data| data.transform.position)
+            {
+                let offset = point - anchor;
+                for data in saved.iter_mut().filter(|data| data.parent.is_none()) {
+                    data.transform.position += offset;
+                }
+            }
+        }
+
+        let source = editor_state
+            .current_file
+            .clone()
+            .unwrap_or_else(|| "user".to_string());
+
+        let mut uuid_to_entity: HashMap<Uuid, Entity> = HashMap::new();
+        for data in &saved {
+            let entity = data.identity.class.spawn_from_save_data(
+                data,
+                &mut commands,
+                &mut standard_materials,
+                &mut meshes,
+                &mut available_materials,
+                &asset_server,
+            );
+            commands
+                .entity(entity)
+                .insert(SpawnSource::new(source.clone(), SaveSettings::Runtime));
+            uuid_to_entity.insert(data.identity.uuid, entity);
+
+            if let Some(component_map) = data.components.clone() {
+                commands.queue(move |world: &mut World| {
+                    let type_registry = world.resource::<AppTypeRegistry>().clone();
+                    // Remove the resource to avoid borrowing errors
+                    if let Some(component_editor) = world.remove_resource::<ComponentEditor>() {
+                        component_editor.load_components_from_scene_data(
+                            world,
+                            entity,
+                            component_map,
+                            type_registry,
+                        );
+                        world.insert_resource(component_editor);
+                    }
+                });
+            }
+        }
+
+        let mut roots = Vec::new();
+        for data in &saved {
+            let entity = uuid_to_entity[&data.identity.uuid];
+            match data.parent.and_then(|parent| uuid_to_entity.get(&parent)) {
+                Some(parent) => {
+                    commands.entity(*parent).add_child(entity);
+                }
+                None => roots.push(entity),
+            }
+        }
+
+        for (index, root) in roots.iter().enumerate() {
+            commands.trigger(EntityEvent::Select {
+                target: *root,
+                additive: index > 0,
+            });
+        }
+
+        log!(
+            LogType::Editor,
+            LogLevel::OK,
+            LogCategory::Entity,
+            "Pasted {} entities{}",
+            saved.len(),
+            if cursor_point.is_some() {
+                " at the cursor"
+            } else {
+                ""
+            }
+        );
+    }
+}
@@ -1,9 +1,13 @@
 pub mod bounds;
+pub mod clipboard;
 pub mod creation;
 pub mod relationship;
 pub mod plugin;
 
 pub use bounds::{get_entity_bounds, get_entity_bounds_or_fallback};
+pub use clipboard::{
+    clipboard_input_system, copy_entities_system, paste_entities_system, EntityClipboard,
+};
 pub use creation::{new_entity_via_popup_system, process_entity_spawn_queue_system, EntitySpawnQueue, PendingEntitySpawn};
 pub use relationship::{child_removal_system, parent_removal_system, parent_removal_from_entities_system, parent_system, parent_from_node_tree_system};
 
@@ -1,13 +1,15 @@
 use super::{
-    child_removal_system, new_entity_via_popup_system, parent_from_node_tree_system,
-    parent_removal_from_entities_system, parent_removal_system, parent_system,
-    process_entity_spawn_queue_system, EntitySpawnQueue,
+    child_removal_system, clipboard_input_system, copy_entities_system,
+    new_entity_via_popup_system, parent_from_node_tree_system, parent_removal_from_entities_system,
+    parent_removal_system, parent_system, paste_entities_system, process_entity_spawn_queue_system,
+    EntityClipboard, EntitySpawnQueue,
 };
 use crate::setup::is_editor_active;
 use bevy::{
     app::{App, Plugin, Update},
     ecs::schedule::IntoScheduleConfigs,
 };
+use bevy_egui::EguiPrimaryContextPass;
 
 pub struct AssetPlugin;
 impl Plugin for AssetPlugin {
@@ -17,6 +19,7 @@ impl Plugin for AssetPlugin {
             // Resources
             //
             .insert_resource(EntitySpawnQueue::default())
+            .insert_resource(EntityClipboard::default())
             //
             // Schedule system
             //
@@ -30,8 +33,14 @@ impl Plugin for AssetPlugin {
                     child_removal_system,
                     parent_removal_system,
                     parent_removal_from_entities_system,
+                    copy_entities_system,
+                    paste_entities_system,
                 )
                     .run_if(is_editor_active),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                clipboard_input_system.run_if(is_editor_active),
             );
     }
 }
@@ -0,0 +1,11 @@
+use bevy::ecs::event::Event;
+
+/// Move through the editor history, handled once the current interaction is finished
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub enum RequestHistoryEvent {
+    Undo,
+    Redo,
+    /// Undo or redo until this many entries are applied
+    JumpTo(usize),
+    Clear,
+}
@@ -0,0 +1,11 @@
+pub mod events;
+pub mod plugin;
+pub mod record;
+pub mod snapshot;
+pub mod stack;
+
+pub use events::RequestHistoryEvent;
+pub use plugin::HistoryPlugin;
+pub use record::{record_history_system, HistoryTracker, HistoryWatch};
+pub use snapshot::{apply_changes, EntitySnapshot};
+pub use stack::{EditorHistory, EntityChange, HistoryEntry};
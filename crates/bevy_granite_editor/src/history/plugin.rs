@@ -0,0 +1,24 @@
+use super::{record_history_system, EditorHistory, HistoryTracker, RequestHistoryEvent};
+use bevy::app::{App, Plugin, PostUpdate};
+
+pub struct HistoryPlugin;
+impl Plugin for HistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            //
+            // Events
+            //
+            .add_event::<RequestHistoryEvent>()
+            //
+            // Resources
+            //
+            .insert_resource(EditorHistory::default())
+            .insert_resource(HistoryTracker::default())
+            //
+            // Schedule system
+            // PostUpdate so every edit made during Update has landed
+            // Always runs so changes made while the editor is off are skipped, not recorded later
+            //
+            .add_systems(PostUpdate, record_history_system);
+    }
+}
@@ -0,0 +1,433 @@
+use super::{
+    apply_changes, EditorHistory, EntityChange, EntitySnapshot, HistoryEntry, RequestHistoryEvent,
+};
+use crate::{
+    editor_state::EditorState, interface::events::UserUpdatedComponentsEvent, play::PlayState,
+};
+use bevy::{
+    ecs::{
+        entity::Entity,
+        event::EventReader,
+        query::{Added, Changed, Or, With},
+        removal_detection::RemovedComponents,
+        resource::Resource,
+        system::{Query, Res, SystemParam, SystemState},
+        world::{Mut, World},
+    },
+    prelude::{ChildOf, Children, Transform},
+    time::Time,
+};
+use bevy_granite_core::{
+    IdentityData, RequestDespawnBySource, RequestDespawnSerializableEntities, RequestReloadEvent,
+    UserInput, WorldLoadSuccessEvent,
+};
+use bevy_granite_gizmos::{gizmos::ModalTransformState, Selected};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Frames after a world load or unload where spawns and despawns belong to it rather than the user
+const WORLD_CHANGE_FRAMES: u8 = 3;
+
+/// Bookkeeping between frames, the history itself lives in EditorHistory
+#[derive(Resource, Default)]
+pub struct HistoryTracker {
+    /// Last recorded state of entities that have been selected, the before side of their next edit
+    cache: HashMap<Entity, EntitySnapshot>,
+    /// Entities edited since the last entry, waiting for the current interaction to finish
+    pending: Vec<Entity>,
+    /// Pending entities that did not exist at the last entry
+    spawned: HashSet<Entity>,
+    /// A drag or modal transform was part of the pending edit, so it won't merge
+    interacted: bool,
+    world_change_frames: u8,
+}
+
+/// Saved entities whose transform, identity or parent changed this frame
+type ChangedIdentityFilter = (
+    With<IdentityData>,
+    Or<(Changed<Transform>, Changed<IdentityData>, Changed<ChildOf>)>,
+);
+
+#[derive(SystemParam)]
+pub struct HistoryWatch<'w, 's> {
+    changed: Query<'w, 's, Entity, ChangedIdentityFilter>,
+    added: Query<'w, 's, Entity, Added<IdentityData>>,
+    newly_selected: Query<'w, 's, Entity, (Added<Selected>, With<IdentityData>)>,
+    selected: Query<'w, 's, (), With<Selected>>,
+    identities: Query<'w, 's, (), With<IdentityData>>,
+    parents: Query<'w, 's, &'static ChildOf>,
+    children: Query<'w, 's, &'static Children>,
+    removed: RemovedComponents<'w, 's, IdentityData>,
+    unparented: RemovedComponents<'w, 's, ChildOf>,
+    component_edits: EventReader<'w, 's, UserUpdatedComponentsEvent>,
+    requests: EventReader<'w, 's, RequestHistoryEvent>,
+    loaded: EventReader<'w, 's, WorldLoadSuccessEvent>,
+    reloaded: EventReader<'w, 's, RequestReloadEvent>,
+    despawn_all: EventReader<'w, 's, RequestDespawnSerializableEntities>,
+    despawn_by_source: EventReader<'w, 's, RequestDespawnBySource>,
+    user_input: Res<'w, UserInput>,
+    modal: Res<'w, ModalTransformState>,
+    editor_state: Res<'w, EditorState>,
+    play_state: Res<'w, PlayState>,
+    time: Res<'w, Time>,
+}
+
+/// What happened this frame, read out so the world is free for snapshots
+#[derive(Default)]
+struct WatchedFrame {
+    edited: Vec<Entity>,
+    untracked: Vec<Entity>,
+    added: Vec<Entity>,
+    removed: Vec<Entity>,
+    newly_selected: Vec<Entity>,
+    requests: Vec<RequestHistoryEvent>,
+    world_changed: bool,
+    world_cleared: bool,
+    interacting: bool,
+    editor_active: bool,
+    time: f64,
+}
+
+impl HistoryWatch<'_, '_> {
+    fn read(&mut self) -> WatchedFrame {
+        let added: Vec<Entity> = self.added.iter().collect();
+        let mut frame = WatchedFrame {
+            removed: self.removed.read().collect(),
+            requests: self.requests.read().copied().collect(),
+            world_cleared: self.reloaded.read().count() > 0 || self.despawn_all.read().count() > 0,
+            interacting: self.user_input.mouse_left.pressed || self.modal.is_active(),
+            // Nothing the game does while playing is an edit, Stop puts it all back
+            editor_active: self.editor_state.active && !self.play_state.is_playing(),
+            time: self.time.elapsed_secs_f64(),
+            ..Default::default()
+        };
+        frame.world_changed = frame.world_cleared
+            || self.loaded.read().count() > 0
+            || self.despawn_by_source.read().count() > 0;
+
+        let mut edited: Vec<Entity> = self.changed.iter().collect();
+        edited.extend(self.unparented.read());
+        edited.extend(self.component_edits.read().map(|event| event.entity));
+
+        // Only edits to the selection and its children are the user's, anything else is the game or a load
+        let (selected, parents) = (&self.selected, &self.parents);
+        let tracked = |entity: Entity| {
+            selected.contains(entity)
+                || parents
+                    .iter_ancestors(entity)
+                    .any(|ancestor| selected.contains(ancestor))
+        };
+        for entity in edited {
+            if added.contains(&entity) || !self.identities.contains(entity) {
+                continue;
+            }
+            if tracked(entity) {
+                frame.edited.push(entity);
+            } else {
+                frame.untracked.push(entity);
+            }
+        }
+
+        for entity in self.newly_selected.iter() {
+            frame.newly_selected.push(entity);
+            frame.newly_selected.extend(
+                self.children
+                    .iter_descendants(entity)
+                    .filter(|child| self.identities.contains(*child)),
+            );
+        }
+        frame.added = added;
+        frame
+    }
+
+    /// Marks everything so far as seen, used after history itself changed the world
+    fn skip(&mut self) {
+        self.removed.clear();
+        self.unparented.clear();
+        self.component_edits.clear();
+    }
+}
+
+/// Watches selected entities for edits and turns them into history entries, then handles undo and redo requests
+pub fn record_history_system(world: &mut World, state: &mut SystemState<HistoryWatch>) {
+    let frame = state.get_mut(world).read();
+
+    world.resource_scope(|world, mut tracker: Mut<HistoryTracker>| {
+        world.resource_scope(|world, mut history: Mut<EditorHistory>| {
+            if !frame.editor_active {
+                tracker.reset();
+                return;
+            }
+            tracker.observe(world, &frame, &mut history);
+
+            if frame.requests.is_empty() {
+                return;
+            }
+            if frame.interacting {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Warning,
+                    LogCategory::Entity,
+                    "Finish the current edit before undo or redo"
+                );
+                return;
+            }
+            for request in &frame.requests {
+                tracker.handle_request(world, *request, &mut history);
+            }
+            tracker.refresh(world);
+        });
+    });
+
+    // Our own changes should not come back as new edits next frame
+    if !frame.requests.is_empty() && !frame.interacting {
+        state.get_mut(world).skip();
+    }
+}
+
+impl HistoryTracker {
+    fn reset(&mut self) {
+        self.cache.clear();
+        self.pending.clear();
+        self.spawned.clear();
+        self.interacted = false;
+        // Coming back to the editor can respawn entities, like when play mode restores its snapshot
+        self.world_change_frames = WORLD_CHANGE_FRAMES;
+    }
+
+    fn mark(&mut self, entity: Entity) {
+        if !self.pending.contains(&entity) {
+            self.pending.push(entity);
+        }
+    }
+
+    fn observe(&mut self, world: &World, frame: &WatchedFrame, history: &mut EditorHistory) {
+        if frame.world_cleared {
+            history.clear();
+            self.reset();
+        }
+        if frame.world_changed {
+            self.world_change_frames = WORLD_CHANGE_FRAMES;
+        }
+        let world_changing = self.world_change_frames > 0;
+        self.world_change_frames = self.world_change_frames.saturating_sub(1);
+
+        // Changed by something else, so the cached state is stale
+        for entity in &frame.untracked {
+            if !self.pending.contains(entity) {
+                self.cache.remove(entity);
+            }
+        }
+        for entity in &frame.edited {
+            self.mark(*entity);
+        }
+        for entity in &frame.added {
+            if world_changing {
+                continue;
+            }
+            self.spawned.insert(*entity);
+            self.mark(*entity);
+        }
+        for entity in &frame.removed {
+            if world_changing {
+                self.cache.remove(entity);
+                self.spawned.remove(entity);
+                self.pending.retain(|pending| pending != entity);
+            } else if self.cache.contains_key(entity) || self.spawned.contains(entity) {
+                self.mark(*entity);
+            }
+        }
+
+        if frame.interacting {
+            self.interacted |= !self.pending.is_empty();
+        } else {
+            self.commit(world, frame.time, history);
+        }
+
+        for entity in &frame.newly_selected {
+            if self.pending.contains(entity) {
+                continue;
+            }
+            if let Some(snapshot) = EntitySnapshot::capture(world, *entity) {
+                self.cache.insert(*entity, snapshot);
+            }
+        }
+    }
+
+    /// Turns pending edits into one history entry
+    fn commit(&mut self, world: &World, time: f64, history: &mut EditorHistory) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let mut changes = Vec::new();
+        for entity in std::mem::take(&mut self.pending) {
+            let after = EntitySnapshot::capture(world, entity);
+            let before = if self.spawned.contains(&entity) {
+                None
+            } else {
+                match self.cache.get(&entity) {
+                    Some(before) => Some(before.clone()),
+                    // Nothing to go back to, start tracking from here
+                    None => {
+                        if let Some(after) = after {
+                            self.cache.insert(entity, after);
+                        }
+                        continue;
+                    }
+                }
+            };
+            match &after {
+                Some(after) => self.cache.insert(entity, after.clone()),
+                None => self.cache.remove(&entity),
+            };
+            if before == after {
+                continue;
+            }
+            let Some(uuid) = after.as_ref().or(before.as_ref()).map(|s| s.uuid()) else {
+                continue;
+            };
+            changes.push(EntityChange {
+                uuid,
+                before,
+                after,
+            });
+        }
+        self.spawned.clear();
+        let mergeable = !std::mem::take(&mut self.interacted);
+
+        if changes.is_empty() {
+            return;
+        }
+        let label = describe(&changes);
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "History: {}",
+            label
+        );
+        history.push(HistoryEntry {
+            label,
+            changes,
+            time,
+            mergeable,
+        });
+    }
+
+    fn handle_request(
+        &mut self,
+        world: &mut World,
+        request: RequestHistoryEvent,
+        history: &mut EditorHistory,
+    ) {
+        match request {
+            RequestHistoryEvent::Undo => {
+                if let Some(changes) = history.step_back() {
+                    apply_changes(world, &changes, false);
+                }
+            }
+            RequestHistoryEvent::Redo => {
+                if let Some(changes) = history.step_forward() {
+                    apply_changes(world, &changes, true);
+                }
+            }
+            RequestHistoryEvent::JumpTo(index) => {
+                while history.undo.len() > index {
+                    let Some(changes) = history.step_back() else {
+                        break;
+                    };
+                    apply_changes(world, &changes, false);
+                }
+                while history.undo.len() < index {
+                    let Some(changes) = history.step_forward() else {
+                        break;
+                    };
+                    apply_changes(world, &changes, true);
+                }
+            }
+            RequestHistoryEvent::Clear => {
+                history.clear();
+            }
+        }
+    }
+
+    /// Re-reads cached entities after history changed them, dropping despawned ones
+    fn refresh(&mut self, world: &mut World) {
+        self.cache
+            .retain(|entity, _| world.get_entity(*entity).is_ok());
+        let mut query = world.query_filtered::<Entity, With<Selected>>();
+        let selected: Vec<Entity> = query.iter(world).collect();
+        let entities: Vec<Entity> = self.cache.keys().copied().chain(selected).collect();
+        for entity in entities {
+            if let Some(snapshot) = EntitySnapshot::capture(world, entity) {
+                self.cache.insert(entity, snapshot);
+            }
+        }
+    }
+}
+
+/// Short label for the history panel, from what actually changed
+fn describe(changes: &[EntityChange]) -> String {
+    let spawned = changes.iter().all(|change| change.before.is_none());
+    let deleted = changes.iter().all(|change| change.after.is_none());
+    let edited: Vec<(&EntitySnapshot, &EntitySnapshot)> = changes
+        .iter()
+        .filter_map(|change| change.before.as_ref().zip(change.after.as_ref()))
+        .collect();
+
+    let action = if spawned {
+        "Add"
+    } else if deleted {
+        "Delete"
+    } else if edited.len() != changes.len() {
+        "Edit"
+    } else if edited
+        .iter()
+        .any(|(before, after)| before.data.parent != after.data.parent)
+    {
+        "Reparent"
+    } else if edited
+        .iter()
+        .any(|(before, after)| before.data.identity != after.data.identity)
+    {
+        let renamed_only = edited
+            .iter()
+            .all(|(before, after)| before.data.identity.class == after.data.identity.class);
+        if renamed_only {
+            "Rename"
+        } else {
+            "Edit"
+        }
+    } else if edited
+        .iter()
+        .any(|(before, after)| before.data.components != after.data.components)
+    {
+        "Edit Components"
+    } else {
+        let transform_changed = |changed: fn(&EntitySnapshot, &EntitySnapshot) -> bool| {
+            edited.iter().any(|(before, after)| changed(before, after))
+        };
+        let moved =
+            transform_changed(|b, a| b.data.transform.position != a.data.transform.position);
+        let rotated =
+            transform_changed(|b, a| b.data.transform.rotation != a.data.transform.rotation);
+        let scaled = transform_changed(|b, a| b.data.transform.scale != a.data.transform.scale);
+        match (moved, rotated, scaled) {
+            (true, false, false) => "Move",
+            (false, true, false) => "Rotate",
+            (false, false, true) => "Scale",
+            _ => "Transform",
+        }
+    };
+
+    match changes {
+        [change] => {
+            let snapshot = change.after.as_ref().or(change.before.as_ref());
+            format!("{} {}", action, snapshot.map_or("entity", |s| s.name()))
+        }
+        _ => format!("{} {} entities", action, changes.len()),
+    }
+}
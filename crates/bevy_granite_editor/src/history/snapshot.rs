@@ -0,0 +1,295 @@
+use super::EntityChange;
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        entity::Entity,
+        system::{Commands, Res, ResMut, SystemState},
+        world::World,
+    },
+    pbr::{MeshMaterial3d, StandardMaterial},
+    prelude::{AppTypeRegistry, ChildOf, Mesh, Name, Transform},
+};
+use bevy_granite_core::{
+    entities::{editable::RequestEntityUpdateFromClass, EntitySaveReadyData},
+    AvailableEditableMaterials, ComponentEditor, GraniteType, HasRuntimeData, IdentityData,
+    SpawnSource, TransformData,
+};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+type RespawnParams<'w, 's> = (
+    Commands<'w, 's>,
+    Res<'w, AssetServer>,
+    ResMut<'w, Assets<StandardMaterial>>,
+    ResMut<'w, Assets<Mesh>>,
+    ResMut<'w, AvailableEditableMaterials>,
+);
+
+type MaterialReloadParams<'w> = (
+    ResMut<'w, Assets<StandardMaterial>>,
+    ResMut<'w, AvailableEditableMaterials>,
+    Res<'w, AssetServer>,
+);
+
+/// Everything needed to rebuild an entity as it was, stored the same way a scene file stores it
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntitySnapshot {
+    pub data: EntitySaveReadyData,
+    pub source: Option<SpawnSource>,
+}
+
+impl EntitySnapshot {
+    /// None if the entity is gone or isn't a granite entity
+    pub fn capture(world: &World, entity: Entity) -> Option<Self> {
+        let entity_ref = world.get_entity(entity).ok()?;
+        let identity = entity_ref.get::<IdentityData>()?.clone();
+        let transform = entity_ref.get::<Transform>().copied().unwrap_or_default();
+        let parent = entity_ref
+            .get::<ChildOf>()
+            .and_then(|child_of| world.get::<IdentityData>(child_of.parent()))
+            .map(|parent| parent.uuid);
+        let components = if entity_ref.contains::<HasRuntimeData>() {
+            world
+                .get_resource::<ComponentEditor>()
+                .map(|editor| editor.serialize_entity_components(world, entity))
+                .filter(|components| !components.is_empty())
+        } else {
+            None
+        };
+
+        Some(Self {
+            data: EntitySaveReadyData {
+                identity,
+                transform: TransformData {
+                    position: transform.translation,
+                    rotation: transform.rotation,
+                    scale: transform.scale,
+                },
+                parent,
+                components,
+            },
+            source: entity_ref.get::<SpawnSource>().cloned(),
+        })
+    }
+
+    pub fn uuid(&self) -> Uuid {
+        self.data.identity.uuid
+    }
+
+    pub fn name(&self) -> &str {
+        &self.data.identity.name
+    }
+}
+
+/// Puts every entity in changes to its after state, or its before state when going back
+/// Entities are found by uuid, so ones that were despawned come back with the same uuid and components
+pub fn apply_changes(world: &mut World, changes: &[EntityChange], forward: bool) {
+    let targets: Vec<(Uuid, Option<&EntitySnapshot>)> = changes
+        .iter()
+        .map(|change| {
+            let target = if forward {
+                &change.after
+            } else {
+                &change.before
+            };
+            (change.uuid, target.as_ref())
+        })
+        .collect();
+    let mut entities = uuid_to_entity_map(world);
+
+    // Despawn what should not exist
+    for (uuid, _) in targets.iter().filter(|(_, target)| target.is_none()) {
+        if let Some(entity) = entities.remove(uuid) {
+            if let Ok(entity_mut) = world.get_entity_mut(entity) {
+                entity_mut.despawn();
+            }
+        }
+    }
+
+    // Spawn what is missing, the same way a scene load does
+    let missing: Vec<&EntitySnapshot> = targets
+        .iter()
+        .filter_map(|(uuid, target)| target.filter(|_| !entities.contains_key(uuid)))
+        .collect();
+    let spawned = spawn_snapshots(world, &missing);
+    entities.extend(spawned.iter().map(|(uuid, entity)| (*uuid, *entity)));
+
+    for (uuid, target) in targets {
+        let (Some(snapshot), Some(&entity)) = (target, entities.get(&uuid)) else {
+            continue;
+        };
+        if world.get_entity(entity).is_err() {
+            continue;
+        }
+        let is_new = spawned
+            .iter()
+            .any(|(spawned_uuid, _)| *spawned_uuid == uuid);
+        restore_entity(world, entity, snapshot, is_new, &entities);
+    }
+
+    log!(
+        LogType::Editor,
+        LogLevel::Info,
+        LogCategory::Entity,
+        "Applied {} entity changes ({} respawned)",
+        changes.len(),
+        spawned.len()
+    );
+}
+
+fn uuid_to_entity_map(world: &mut World) -> HashMap<Uuid, Entity> {
+    let mut query = world.query::<(Entity, &IdentityData)>();
+    query
+        .iter(world)
+        .map(|(entity, identity)| (identity.uuid, entity))
+        .collect()
+}
+
+fn spawn_snapshots(world: &mut World, snapshots: &[&EntitySnapshot]) -> Vec<(Uuid, Entity)> {
+    if snapshots.is_empty() {
+        return Vec::new();
+    }
+    let mut state: SystemState<RespawnParams> = SystemState::new(world);
+
+    let spawned = {
+        let (mut commands, asset_server, mut materials, mut meshes, mut available_materials) =
+            state.get_mut(world);
+        snapshots
+            .iter()
+            .map(|snapshot| {
+                let entity = snapshot.data.identity.class.spawn_from_save_data(
+                    &snapshot.data,
+                    &mut commands,
+                    &mut materials,
+                    &mut meshes,
+                    &mut available_materials,
+                    &asset_server,
+                );
+                if let Some(source) = &snapshot.source {
+                    commands.entity(entity).insert(source.clone());
+                }
+                (snapshot.uuid(), entity)
+            })
+            .collect()
+    };
+    state.apply(world);
+    spawned
+}
+
+fn restore_entity(
+    world: &mut World,
+    entity: Entity,
+    snapshot: &EntitySnapshot,
+    is_new: bool,
+    entities: &HashMap<Uuid, Entity>,
+) {
+    let current = EntitySnapshot::capture(world, entity);
+
+    // Parent first, the saved transform is local to it
+    let parent = snapshot
+        .data
+        .parent
+        .and_then(|uuid| entities.get(&uuid).copied());
+    let current_parent = world
+        .get::<ChildOf>(entity)
+        .map(|child_of| child_of.parent());
+    if parent != current_parent {
+        match parent {
+            Some(parent) => {
+                world.entity_mut(entity).insert(ChildOf(parent));
+            }
+            None => {
+                world.entity_mut(entity).remove::<ChildOf>();
+            }
+        }
+    }
+    world
+        .entity_mut(entity)
+        .insert(snapshot.data.transform.to_bevy());
+
+    if let Some(source) = &snapshot.source {
+        world.entity_mut(entity).insert(source.clone());
+    }
+
+    let current_data = current.as_ref().map(|current| &current.data);
+    if !is_new && current_data.map(|data| &data.identity) != Some(&snapshot.data.identity) {
+        restore_identity(world, entity, &snapshot.data.identity);
+    }
+    if is_new || current_data.map(|data| &data.components) != Some(&snapshot.data.components) {
+        let current_components = current_data
+            .and_then(|data| data.components.clone())
+            .unwrap_or_default();
+        restore_components(
+            world,
+            entity,
+            current_components,
+            snapshot.data.components.clone().unwrap_or_default(),
+        );
+    }
+}
+
+/// Name, class data and material, then lets the class push its data to the entity like a UI edit would
+fn restore_identity(world: &mut World, entity: Entity, identity: &IdentityData) {
+    let mut identity = identity.clone();
+    let live_material_def = world
+        .get::<IdentityData>(entity)
+        .and_then(|live| live.class.get_material_data())
+        .and_then(|material| material.current.def.clone());
+
+    // Material values live in the shared asset rather than on the entity
+    if let Some(material) = identity.class.get_mut_material_data() {
+        if let Some(def) = material.current.def.clone() {
+            if Some(&def) != live_material_def.as_ref() {
+                let mut state: SystemState<MaterialReloadParams> = SystemState::new(world);
+                let (mut materials, mut available_materials, asset_server) = state.get_mut(world);
+                material.current.disk_changes = true;
+                material.current.update_material_handle(
+                    &def,
+                    &mut materials,
+                    &mut available_materials,
+                    &asset_server,
+                );
+                material.current.disk_changes = false;
+            }
+        }
+        if let Some(handle) = material.current.handle.clone() {
+            world.entity_mut(entity).insert(MeshMaterial3d(handle));
+        }
+    }
+
+    world
+        .entity_mut(entity)
+        .insert((Name::new(identity.name.clone()), identity.clone()));
+
+    if identity.class.is_known() {
+        let mut state: SystemState<RequestEntityUpdateFromClass> = SystemState::new(world);
+        let mut request_writer = state.get_mut(world);
+        identity.class.push_to_entity(entity, &mut request_writer);
+        state.apply(world);
+    }
+}
+
+/// Removes components the target doesn't have and reloads the rest from their serialized data
+fn restore_components(
+    world: &mut World,
+    entity: Entity,
+    current: HashMap<String, String>,
+    target: HashMap<String, String>,
+) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    // Remove the resource to avoid borrowing errors
+    let Some(component_editor) = world.remove_resource::<ComponentEditor>() else {
+        return;
+    };
+    for name in current.keys().filter(|name| !target.contains_key(*name)) {
+        component_editor.remove_component_by_name(world, entity, name);
+    }
+    if !target.is_empty() {
+        component_editor.load_components_from_scene_data(world, entity, target, type_registry);
+    }
+    world.insert_resource(component_editor);
+}
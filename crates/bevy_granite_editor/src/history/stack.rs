@@ -0,0 +1,121 @@
+use super::EntitySnapshot;
+use crate::get_interface_config_float;
+use bevy::ecs::resource::Resource;
+use uuid::Uuid;
+
+/// Used when config.toml has no history depth
+const DEFAULT_DEPTH: usize = 100;
+
+/// Seconds after an edit in which the same kind of edit to the same entities folds into it, so typing a name is one step
+const MERGE_WINDOW: f64 = 1.0;
+
+/// One entity before and after an edit, None on either side means it did not exist
+#[derive(Clone, Debug)]
+pub struct EntityChange {
+    pub uuid: Uuid,
+    pub before: Option<EntitySnapshot>,
+    pub after: Option<EntitySnapshot>,
+}
+
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    pub label: String,
+    pub changes: Vec<EntityChange>,
+    /// Elapsed seconds when the entry was last written
+    pub time: f64,
+    /// Keyboard and UI edits can merge, pointer drags are always their own step
+    pub mergeable: bool,
+}
+
+impl HistoryEntry {
+    fn same_entities(&self, other: &HistoryEntry) -> bool {
+        self.changes.len() == other.changes.len()
+            && self
+                .changes
+                .iter()
+                .all(|change| other.changes.iter().any(|o| o.uuid == change.uuid))
+    }
+
+    /// Folds a newer entry into this one, keeping the oldest before state
+    fn merge(&mut self, newer: HistoryEntry) {
+        for change in newer.changes {
+            if let Some(existing) = self.changes.iter_mut().find(|c| c.uuid == change.uuid) {
+                existing.after = change.after;
+            }
+        }
+        self.time = newer.time;
+    }
+}
+
+/// Undo and redo stacks, newest entry last in both
+#[derive(Resource)]
+pub struct EditorHistory {
+    pub undo: Vec<HistoryEntry>,
+    pub redo: Vec<HistoryEntry>,
+    pub depth: usize,
+}
+
+impl Default for EditorHistory {
+    fn default() -> Self {
+        let depth = get_interface_config_float("history.depth");
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            depth: if depth >= 1.0 {
+                depth as usize
+            } else {
+                DEFAULT_DEPTH
+            },
+        }
+    }
+}
+
+impl EditorHistory {
+    /// Records a new entry, anything that could be redone is dropped
+    pub fn push(&mut self, entry: HistoryEntry) {
+        self.redo.clear();
+        if let Some(last) = self.undo.last_mut() {
+            if entry.mergeable
+                && last.mergeable
+                && last.label == entry.label
+                && entry.time - last.time < MERGE_WINDOW
+                && last.same_entities(&entry)
+            {
+                last.merge(entry);
+                return;
+            }
+        }
+        self.undo.push(entry);
+        self.trim();
+    }
+
+    /// Drops the oldest entries past the depth
+    pub fn trim(&mut self) {
+        let depth = self.depth.max(1);
+        if self.undo.len() > depth {
+            let excess = self.undo.len() - depth;
+            self.undo.drain(..excess);
+        }
+    }
+
+    /// Moves the newest entry to the redo stack, returns the changes to put back to their before state
+    pub fn step_back(&mut self) -> Option<Vec<EntityChange>> {
+        let entry = self.undo.pop()?;
+        let changes = entry.changes.clone();
+        self.redo.push(entry);
+        Some(changes)
+    }
+
+    /// Moves the newest undone entry back, returns the changes to put back to their after state
+    pub fn step_forward(&mut self) -> Option<Vec<EntityChange>> {
+        let entry = self.redo.pop()?;
+        let changes = entry.changes.clone();
+        self.undo.push(entry);
+        Some(changes)
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
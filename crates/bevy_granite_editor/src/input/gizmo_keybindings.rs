@@ -0,0 +1,34 @@
+use super::EditorAction;
+use crate::{
+    editor_state::EditorState,
+    interface::{popups::PopupType, PopupState},
+};
+use bevy::ecs::{
+    change_detection::DetectChanges,
+    system::{Res, ResMut},
+};
+use bevy_granite_gizmos::GizmoKeybindings;
+
+/// Copies the gizmo chords from the editor keybindings to the gizmos crate
+/// Gizmo keys are paused while a chord is being recorded or typed into the command palette
+pub fn sync_gizmo_keybindings_system(
+    editor_state: Res<EditorState>,
+    popup_state: Res<PopupState>,
+    mut gizmo_keybindings: ResMut<GizmoKeybindings>,
+) {
+    if !editor_state.is_changed() && !popup_state.is_changed() {
+        return;
+    }
+    let keybindings = &editor_state.config.keybindings;
+    let synced = GizmoKeybindings {
+        chords: EditorAction::ALL
+            .iter()
+            .filter_map(|action| Some((action.gizmo_action()?, keybindings.chords(*action))))
+            .collect(),
+        paused: keybindings.listening.is_some()
+            || matches!(popup_state.active_popup, Some(PopupType::CommandPalette)),
+    };
+    if *gizmo_keybindings != synced {
+        *gizmo_keybindings = synced;
+    }
+}
@@ -0,0 +1,429 @@
+use bevy::input::{keyboard::KeyCode, ButtonInput};
+use bevy_granite_gizmos::GizmoAction;
+use serde::{Deserialize, Deserializer, Serialize};
+
+pub use bevy_granite_core::{is_modifier, key_name, KeyChord};
+
+/// Editor commands that can be bound to a key chord from the settings tab
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum EditorAction {
+    ToggleEditor,
+    ToggleCameraSync,
+    Help,
+    FrameSelection,
+    DeselectAll,
+    DeleteSelection,
+    AddEntity,
+    AddRelationship,
+    SaveWorld,
+    OpenWorld,
+    ReloadWorld,
+    Undo,
+    Redo,
+    CommandPalette,
+    PlayStop,
+    PauseResume,
+    StepFrame,
+    PointerGizmo,
+    MoveGizmo,
+    RotateGizmo,
+    ScaleGizmo,
+    ModalMove,
+    ModalRotate,
+    ModalScale,
+    VertexSnap,
+    BrushEdit,
+    BrushVertexMode,
+    BrushEdgeMode,
+    BrushFaceMode,
+}
+
+/// Part of the help popup an action is listed in
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ShortcutSection {
+    Camera,
+    Selection,
+    Gizmos,
+    Tools,
+}
+
+impl ShortcutSection {
+    /// Placeholder in the help text that is replaced with this section's shortcuts
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            ShortcutSection::Camera => "{camera_shortcuts}",
+            ShortcutSection::Selection => "{selection_shortcuts}",
+            ShortcutSection::Gizmos => "{gizmo_shortcuts}",
+            ShortcutSection::Tools => "{tool_shortcuts}",
+        }
+    }
+}
+
+impl EditorAction {
+    pub const ALL: [EditorAction; 29] = [
+        EditorAction::ToggleEditor,
+        EditorAction::ToggleCameraSync,
+        EditorAction::Help,
+        EditorAction::FrameSelection,
+        EditorAction::DeselectAll,
+        EditorAction::DeleteSelection,
+        EditorAction::AddEntity,
+        EditorAction::AddRelationship,
+        EditorAction::SaveWorld,
+        EditorAction::OpenWorld,
+        EditorAction::ReloadWorld,
+        EditorAction::Undo,
+        EditorAction::Redo,
+        EditorAction::CommandPalette,
+        EditorAction::PlayStop,
+        EditorAction::PauseResume,
+        EditorAction::StepFrame,
+        EditorAction::PointerGizmo,
+        EditorAction::MoveGizmo,
+        EditorAction::RotateGizmo,
+        EditorAction::ScaleGizmo,
+        EditorAction::ModalMove,
+        EditorAction::ModalRotate,
+        EditorAction::ModalScale,
+        EditorAction::VertexSnap,
+        EditorAction::BrushEdit,
+        EditorAction::BrushVertexMode,
+        EditorAction::BrushEdgeMode,
+        EditorAction::BrushFaceMode,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EditorAction::ToggleEditor => "Toggle Editor",
+            EditorAction::ToggleCameraSync => "Toggle Camera Control",
+            EditorAction::Help => "Show Help",
+            EditorAction::FrameSelection => "Frame Selection",
+            EditorAction::DeselectAll => "Deselect All",
+            EditorAction::DeleteSelection => "Delete Selection",
+            EditorAction::AddEntity => "Add Entity",
+            EditorAction::AddRelationship => "Add Relationship",
+            EditorAction::SaveWorld => "Save World",
+            EditorAction::OpenWorld => "Open World",
+            EditorAction::ReloadWorld => "Reload World",
+            EditorAction::Undo => "Undo",
+            EditorAction::Redo => "Redo",
+            EditorAction::CommandPalette => "Command Palette",
+            EditorAction::PlayStop => "Play / Stop",
+            EditorAction::PauseResume => "Pause / Resume",
+            EditorAction::StepFrame => "Step Frame",
+            EditorAction::PointerGizmo => "Pointer Gizmo",
+            EditorAction::MoveGizmo => "Move Gizmo",
+            EditorAction::RotateGizmo => "Rotate Gizmo",
+            EditorAction::ScaleGizmo => "Scale Gizmo",
+            EditorAction::ModalMove => "Modal Move",
+            EditorAction::ModalRotate => "Modal Rotate",
+            EditorAction::ModalScale => "Modal Scale",
+            EditorAction::VertexSnap => "Vertex Snap",
+            EditorAction::BrushEdit => "Brush Edit Mode",
+            EditorAction::BrushVertexMode => "Brush Vertex Mode",
+            EditorAction::BrushEdgeMode => "Brush Edge Mode",
+            EditorAction::BrushFaceMode => "Brush Face Mode",
+        }
+    }
+
+    /// Line shown next to the chord in the help popup
+    pub fn description(&self) -> &'static str {
+        match self {
+            EditorAction::ToggleEditor => "Toggle editor on/off",
+            EditorAction::ToggleCameraSync => "Toggle main/editor camera Controls",
+            EditorAction::Help => "Show this help menu",
+            EditorAction::FrameSelection => "Frame selection",
+            EditorAction::DeselectAll => "Deselect all",
+            EditorAction::DeleteSelection => "Delete entity",
+            EditorAction::AddEntity => "Add entity popup",
+            EditorAction::AddRelationship => "Relationship (parent) popup",
+            EditorAction::SaveWorld => "Save current world",
+            EditorAction::OpenWorld => "Load new world",
+            EditorAction::ReloadWorld => "Despawn worlds and load again",
+            EditorAction::Undo => "Undo",
+            EditorAction::Redo => "Redo",
+            EditorAction::CommandPalette => "Search and run any editor command",
+            EditorAction::PlayStop => "Play the scene, stop to restore it",
+            EditorAction::PauseResume => "Pause or resume play",
+            EditorAction::StepFrame => "Advance play by one frame",
+            EditorAction::PointerGizmo => "Pointer gizmo",
+            EditorAction::MoveGizmo => "Move gizmo",
+            EditorAction::RotateGizmo => "Rotate gizmo",
+            EditorAction::ScaleGizmo => "Scale gizmo",
+            EditorAction::ModalMove => "Move selection with the mouse",
+            EditorAction::ModalRotate => "Rotate selection with the mouse",
+            EditorAction::ModalScale => "Scale selection with the mouse",
+            EditorAction::VertexSnap => "Hold while dragging the move gizmo to snap to vertices",
+            EditorAction::BrushEdit => "Edit the selected convex brush's vertices, edges or faces",
+            EditorAction::BrushVertexMode => "Edit the brush's vertices while in brush edit mode",
+            EditorAction::BrushEdgeMode => "Edit the brush's edges while in brush edit mode",
+            EditorAction::BrushFaceMode => "Edit the brush's faces while in brush edit mode",
+        }
+    }
+
+    pub fn section(&self) -> ShortcutSection {
+        match self {
+            EditorAction::FrameSelection => ShortcutSection::Camera,
+            EditorAction::DeselectAll | EditorAction::DeleteSelection => ShortcutSection::Selection,
+            EditorAction::BrushEdit
+            | EditorAction::BrushVertexMode
+            | EditorAction::BrushEdgeMode
+            | EditorAction::BrushFaceMode => ShortcutSection::Gizmos,
+            _ if self.gizmo_action().is_some() => ShortcutSection::Gizmos,
+            _ => ShortcutSection::Tools,
+        }
+    }
+
+    /// Gizmo shortcut this action is copied to, those are handled by the gizmos crate
+    pub fn gizmo_action(&self) -> Option<GizmoAction> {
+        match self {
+            EditorAction::PointerGizmo => Some(GizmoAction::PointerGizmo),
+            EditorAction::MoveGizmo => Some(GizmoAction::TransformGizmo),
+            EditorAction::RotateGizmo => Some(GizmoAction::RotateGizmo),
+            EditorAction::ScaleGizmo => Some(GizmoAction::ScaleGizmo),
+            EditorAction::ModalMove => Some(GizmoAction::ModalMove),
+            EditorAction::ModalRotate => Some(GizmoAction::ModalRotate),
+            EditorAction::ModalScale => Some(GizmoAction::ModalScale),
+            EditorAction::VertexSnap => Some(GizmoAction::VertexSnap),
+            _ => None,
+        }
+    }
+
+    /// False for actions that only make sense from the keyboard, like ones that follow the mouse
+    pub fn runs_from_palette(&self) -> bool {
+        self.gizmo_action().is_none()
+            && !matches!(
+                self,
+                EditorAction::CommandPalette
+                    | EditorAction::BrushEdit
+                    | EditorAction::BrushVertexMode
+                    | EditorAction::BrushEdgeMode
+                    | EditorAction::BrushFaceMode
+            )
+    }
+
+    pub fn default_chord(&self) -> KeyChord {
+        match self {
+            EditorAction::ToggleEditor => KeyChord::new(KeyCode::F2),
+            EditorAction::ToggleCameraSync => KeyChord::new(KeyCode::F3),
+            EditorAction::Help => KeyChord::new(KeyCode::F1),
+            EditorAction::FrameSelection => KeyChord::new(KeyCode::KeyF),
+            EditorAction::DeselectAll => KeyChord::new(KeyCode::KeyU),
+            EditorAction::DeleteSelection => KeyChord::new(KeyCode::Delete),
+            EditorAction::AddEntity => KeyChord::new(KeyCode::KeyA).shift(),
+            EditorAction::AddRelationship => KeyChord::new(KeyCode::KeyP).shift(),
+            EditorAction::SaveWorld => KeyChord::new(KeyCode::KeyS).ctrl(),
+            EditorAction::OpenWorld => KeyChord::new(KeyCode::KeyO).ctrl(),
+            EditorAction::ReloadWorld => KeyChord::new(KeyCode::KeyR).ctrl(),
+            EditorAction::Undo => KeyChord::new(KeyCode::KeyZ).ctrl(),
+            EditorAction::Redo => KeyChord::new(KeyCode::KeyZ).ctrl().shift(),
+            EditorAction::CommandPalette => KeyChord::new(KeyCode::KeyP).ctrl(),
+            EditorAction::PlayStop => KeyChord::new(KeyCode::F5),
+            EditorAction::PauseResume => KeyChord::new(KeyCode::F6),
+            EditorAction::StepFrame => KeyChord::new(KeyCode::F7),
+            EditorAction::PointerGizmo => GizmoAction::PointerGizmo.default_chord(),
+            EditorAction::MoveGizmo => GizmoAction::TransformGizmo.default_chord(),
+            EditorAction::RotateGizmo => GizmoAction::RotateGizmo.default_chord(),
+            EditorAction::ScaleGizmo => GizmoAction::ScaleGizmo.default_chord(),
+            EditorAction::ModalMove => GizmoAction::ModalMove.default_chord(),
+            EditorAction::ModalRotate => GizmoAction::ModalRotate.default_chord(),
+            EditorAction::ModalScale => GizmoAction::ModalScale.default_chord(),
+            EditorAction::VertexSnap => GizmoAction::VertexSnap.default_chord(),
+            EditorAction::BrushEdit => KeyChord::new(KeyCode::Tab),
+            EditorAction::BrushVertexMode => KeyChord::new(KeyCode::Digit1),
+            EditorAction::BrushEdgeMode => KeyChord::new(KeyCode::Digit2),
+            EditorAction::BrushFaceMode => KeyChord::new(KeyCode::Digit3),
+        }
+    }
+
+    /// The default chord plus any common alternative for the action
+    pub fn default_chords(&self) -> Vec<KeyChord> {
+        match self {
+            EditorAction::Redo => vec![self.default_chord(), KeyChord::new(KeyCode::KeyY).ctrl()],
+            _ => vec![self.default_chord()],
+        }
+    }
+}
+
+pub const COPY_CHORD: KeyChord = KeyChord::new(KeyCode::KeyC).ctrl();
+pub const PASTE_CHORD: KeyChord = KeyChord::new(KeyCode::KeyV).ctrl();
+
+/// Keys handled outside the action map, binding an action to one of these does both
+/// Copy and paste come from the platform clipboard events, so they can't be rebound
+pub fn fixed_chords() -> [(KeyChord, &'static str); 2] {
+    [(COPY_CHORD, "Copy"), (PASTE_CHORD, "Paste")]
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct KeyBinding {
+    pub action: EditorAction,
+    /// Any of these triggers the action, empty leaves it unbound
+    /// Configs from before actions had several chords store a single "chord"
+    #[serde(default, alias = "chord", deserialize_with = "deserialize_chords")]
+    pub chords: Vec<KeyChord>,
+}
+
+fn deserialize_chords<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<KeyChord>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(KeyChord),
+        Many(Vec<KeyChord>),
+    }
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        Some(OneOrMany::One(chord)) => vec![chord],
+        Some(OneOrMany::Many(chords)) => chords,
+        None => Vec::new(),
+    })
+}
+
+/// Chords for every editor action, saved with the rest of the editor config
+/// Actions missing from an older config fall back to their default chords
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct KeyBindings {
+    pub bindings: Vec<KeyBinding>,
+
+    /// Action waiting for the next key press in the settings tab, shortcuts are paused meanwhile
+    /// The pressed chord is added to the ones it already has
+    #[serde(skip)]
+    pub listening: Option<EditorAction>,
+    #[serde(skip)]
+    pub changed: bool,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            bindings: EditorAction::ALL
+                .iter()
+                .map(|action| KeyBinding {
+                    action: *action,
+                    chords: action.default_chords(),
+                })
+                .collect(),
+            listening: None,
+            changed: false,
+        }
+    }
+}
+
+impl KeyBindings {
+    pub fn chords(&self, action: EditorAction) -> Vec<KeyChord> {
+        match self
+            .bindings
+            .iter()
+            .find(|binding| binding.action == action)
+        {
+            Some(binding) => binding.chords.clone(),
+            None => action.default_chords(),
+        }
+    }
+
+    pub fn is_bound(&self, action: EditorAction) -> bool {
+        !self.chords(action).is_empty()
+    }
+
+    fn binding_mut(&mut self, action: EditorAction) -> &mut KeyBinding {
+        let index = match self
+            .bindings
+            .iter()
+            .position(|binding| binding.action == action)
+        {
+            Some(index) => index,
+            None => {
+                self.bindings.push(KeyBinding {
+                    action,
+                    chords: action.default_chords(),
+                });
+                self.bindings.len() - 1
+            }
+        };
+        &mut self.bindings[index]
+    }
+
+    pub fn add(&mut self, action: EditorAction, chord: KeyChord) {
+        let binding = self.binding_mut(action);
+        if !binding.chords.contains(&chord) {
+            binding.chords.push(chord);
+        }
+        self.changed = true;
+    }
+
+    pub fn remove(&mut self, action: EditorAction, chord: KeyChord) {
+        self.binding_mut(action)
+            .chords
+            .retain(|existing| *existing != chord);
+        self.changed = true;
+    }
+
+    pub fn reset(&mut self) {
+        self.bindings = Self::default().bindings;
+        self.changed = true;
+    }
+
+    pub fn just_pressed(&self, action: EditorAction, keyboard: &ButtonInput<KeyCode>) -> bool {
+        self.listening.is_none()
+            && self
+                .chords(action)
+                .iter()
+                .any(|chord| chord.just_pressed(keyboard))
+    }
+
+    /// Chords as shown to the user, "Unbound" if there are none
+    pub fn display(&self, action: EditorAction) -> String {
+        let chords = self.chords(action);
+        if chords.is_empty() {
+            return "Unbound".to_string();
+        }
+        chords
+            .iter()
+            .map(|chord| chord.to_string())
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+
+    /// Names of other actions and fixed editor keys that share one of this action's chords
+    pub fn conflicts(&self, action: EditorAction) -> Vec<&'static str> {
+        let chords = self.chords(action);
+        let shares = |other: &[KeyChord]| other.iter().any(|chord| chords.contains(chord));
+        let actions = EditorAction::ALL
+            .iter()
+            .filter(|other| **other != action && shares(&self.chords(**other)))
+            .map(|other| other.label());
+        let fixed = fixed_chords()
+            .into_iter()
+            .filter(|(fixed, _)| chords.contains(fixed))
+            .map(|(_, name)| name);
+        actions.chain(fixed).collect()
+    }
+
+    pub fn has_conflicts(&self) -> bool {
+        EditorAction::ALL
+            .iter()
+            .any(|action| !self.conflicts(*action).is_empty())
+    }
+
+    /// Fills the shortcut placeholders in the help text from the live bindings
+    pub fn fill_help_text(&self, template: &str) -> String {
+        let mut text = template.to_string();
+        for section in [
+            ShortcutSection::Camera,
+            ShortcutSection::Selection,
+            ShortcutSection::Gizmos,
+            ShortcutSection::Tools,
+        ] {
+            let lines: Vec<String> = EditorAction::ALL
+                .iter()
+                .filter(|action| action.section() == section)
+                .map(|action| format!("• {} - {}", self.display(*action), action.description()))
+                .collect();
+            text = text.replace(section.placeholder(), &lines.join("\n"));
+        }
+        for action in EditorAction::ALL {
+            text = text.replace(&format!("{{{:?}}}", action), &self.display(action));
+        }
+        text
+    }
+}
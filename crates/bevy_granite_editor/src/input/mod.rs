@@ -1,5 +1,9 @@
+pub mod gizmo_keybindings;
+pub mod keybindings;
 pub mod shortcuts;
 pub mod plugin;
 
+pub use gizmo_keybindings::sync_gizmo_keybindings_system;
+pub use keybindings::{EditorAction, KeyBinding, KeyBindings, KeyChord};
 pub use shortcuts::shortcuts_system;
 pub use plugin::InputPlugin;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use super::shortcuts_system;
+use super::{shortcuts_system, sync_gizmo_keybindings_system};
 use bevy::app::{App, Plugin, Update};
 
 pub struct InputPlugin;
@@ -14,6 +14,7 @@ impl Plugin for InputPlugin {
                     // always runs
                     // allows editor/toggle
                     shortcuts_system,
+                    sync_gizmo_keybindings_system,
                 ),
             );
     }
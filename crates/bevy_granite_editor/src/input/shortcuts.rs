@@ -1,6 +1,8 @@
 use bevy::{
+    ecs::system::SystemParam,
+    input::{keyboard::KeyCode, ButtonInput},
     math::Vec2,
-    prelude::{Children, Commands, Entity, Query, Res},
+    prelude::{Children, Commands, Entity, EventReader, Query, Res},
 };
 use bevy_granite_core::{
     entities::SaveSettings, RequestLoadEvent, RequestReloadEvent, RequestSaveEvent, UserInput,
@@ -9,38 +11,79 @@ use bevy_granite_gizmos::{selection::events::EntityEvent, Selected};
 use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
 use native_dialog::FileDialog;
 
+use super::EditorAction;
 use crate::{
     editor_state::EditorState,
+    history::RequestHistoryEvent,
     interface::{
         events::{
-            PopupMenuRequestedEvent, RequestCameraEntityFrame, RequestEditorToggle,
-            RequestToggleCameraSync,
+            PopupMenuRequestedEvent, RequestCameraEntityFrame, RequestEditorAction,
+            RequestEditorToggle, RequestToggleCameraSync,
         },
         popups::PopupType,
-        EditorEvents,
+        EditorEvents, PopupState,
     },
+    play::{PlayState, RequestPlayEvent},
 };
 
+/// Where shortcuts come from, chords pressed on the keyboard and actions run from the command palette
+#[derive(SystemParam)]
+pub struct ShortcutSources<'w, 's> {
+    pub keyboard: Res<'w, ButtonInput<KeyCode>>,
+    pub action_reader: EventReader<'w, 's, RequestEditorAction>,
+    pub popup_state: Res<'w, PopupState>,
+}
+
 pub fn shortcuts_system(
     mut commands: Commands,
     input: Res<UserInput>,
     query: Query<(Entity, &Selected, Option<&Children>)>,
     mut events: EditorEvents,
+    sources: ShortcutSources,
     editor_state: Res<EditorState>,
+    play_state: Res<PlayState>,
 ) {
-    handle_shortcuts(&input, &editor_state, &mut commands, &query, &mut events);
+    let ShortcutSources {
+        keyboard,
+        mut action_reader,
+        popup_state,
+    } = sources;
+    let requested: Vec<EditorAction> = action_reader
+        .read()
+        .map(|RequestEditorAction(action)| *action)
+        .collect();
+    // Keys typed into the palette search are not shortcuts
+    let palette_open = matches!(popup_state.active_popup, Some(PopupType::CommandPalette));
+    let bindings = &editor_state.config.keybindings;
+    // Requested actions come from the command palette and skip the mouse checks
+    let triggered = |action: EditorAction, allowed: bool| {
+        requested.contains(&action)
+            || (allowed && !palette_open && bindings.just_pressed(action, &keyboard))
+    };
+    handle_shortcuts(
+        &input,
+        triggered,
+        &editor_state,
+        play_state.is_playing(),
+        &mut commands,
+        &query,
+        &mut events,
+    );
 }
 
+// Chords come from the keybindings in the editor config, the mouse checks stay fixed per action
 fn handle_shortcuts(
     input: &UserInput,
+    triggered: impl Fn(EditorAction, bool) -> bool,
     editor_state: &EditorState,
+    playing: bool,
     commands: &mut Commands,
     query: &Query<(Entity, &Selected, Option<&Children>)>,
     events: &mut EditorEvents,
 ) {
     // F2
     // Toggle editor on/off
-    if input.key_f2.just_pressed {
+    if triggered(EditorAction::ToggleEditor, true) {
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -50,13 +93,43 @@ fn handle_shortcuts(
         events.toggle_editor.write(RequestEditorToggle);
     }
 
+    // F5 / F6 / F7
+    // Play mode, these work with the editor closed since playing closes it
+    if triggered(EditorAction::PlayStop, editor_state.active || playing) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Input,
+            "(shortcut) Toggling play"
+        );
+        events.play.write(RequestPlayEvent::TogglePlay);
+    }
+    if triggered(EditorAction::PauseResume, playing) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Input,
+            "(shortcut) Toggling play pause"
+        );
+        events.play.write(RequestPlayEvent::TogglePause);
+    }
+    if triggered(EditorAction::StepFrame, playing) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Input,
+            "(shortcut) Stepping one frame"
+        );
+        events.play.write(RequestPlayEvent::Step);
+    }
+
     if !editor_state.active {
         return;
     }
 
     // F3
     // sync cam
-    if input.key_f3.just_pressed {
+    if triggered(EditorAction::ToggleCameraSync, true) {
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -68,7 +141,7 @@ fn handle_shortcuts(
 
     // Delete Key
     // Delete Active Entity
-    if input.key_delete.just_pressed {
+    if triggered(EditorAction::DeleteSelection, true) {
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -87,7 +160,7 @@ fn handle_shortcuts(
 
     // F key
     // Frame selected entity
-    if input.key_f.just_pressed && !input.mouse_over_egui {
+    if triggered(EditorAction::FrameSelection, !input.mouse_over_egui) {
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -99,7 +172,7 @@ fn handle_shortcuts(
 
     // U key
     // Deselect all
-    if input.key_u.just_pressed && !input.mouse_over_egui {
+    if triggered(EditorAction::DeselectAll, !input.mouse_over_egui) {
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -111,11 +184,10 @@ fn handle_shortcuts(
 
     // Shft-A
     // Add Entity
-    if input.shift_left.pressed
-        && input.key_a.just_pressed
-        && !input.mouse_over_egui
-        && !input.mouse_right.any
-    {
+    if triggered(
+        EditorAction::AddEntity,
+        !input.mouse_over_egui && !input.mouse_right.any,
+    ) {
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -130,7 +202,10 @@ fn handle_shortcuts(
 
     // F1
     // Help
-    if input.key_f1.just_pressed && !input.mouse_over_egui && !input.mouse_right.any {
+    if triggered(
+        EditorAction::Help,
+        !input.mouse_over_egui && !input.mouse_right.any,
+    ) {
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -145,7 +220,7 @@ fn handle_shortcuts(
 
     // Ctrl-O
     // Load
-    if input.ctrl_left.pressed && input.key_o.just_pressed && !input.mouse_right.any {
+    if triggered(EditorAction::OpenWorld, !input.mouse_right.any) {
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -167,13 +242,19 @@ fn handle_shortcuts(
 
     // Ctrl-S
     // Save all sources
-    if input.ctrl_left.pressed
-        && input.key_s.just_pressed
-        && !input.mouse_right.any
-        && !input.mouse_left.any
-    {
+    if triggered(
+        EditorAction::SaveWorld,
+        !input.mouse_right.any && !input.mouse_left.any,
+    ) {
         let loaded = &editor_state.loaded_sources;
-        if !loaded.is_empty() {
+        if playing {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Input,
+                "(shortcut) Stop playing before saving, the world has changes made by the game"
+            );
+        } else if !loaded.is_empty() {
             log!(
                 LogType::Editor,
                 LogLevel::Info,
@@ -204,11 +285,10 @@ fn handle_shortcuts(
 
     // Reload loaded worlds
     // Despawn entities and reload world
-    if input.ctrl_left.pressed
-        && input.key_r.just_pressed
-        && !input.mouse_right.any
-        && !input.mouse_left.any
-    {
+    if triggered(
+        EditorAction::ReloadWorld,
+        !input.mouse_right.any && !input.mouse_left.any,
+    ) {
         if let Some(current_file) = &editor_state.current_file {
             log!(
                 LogType::Editor,
@@ -229,13 +309,34 @@ fn handle_shortcuts(
         }
     }
 
+    // Ctrl-Z / Ctrl-Shift-Z / Ctrl-Y
+    // Undo and redo
+    let mouse_free = !input.mouse_right.any && !input.mouse_left.any;
+    if triggered(EditorAction::Undo, mouse_free) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Input,
+            "(shortcut) Undo"
+        );
+        events.history.write(RequestHistoryEvent::Undo);
+    }
+    if triggered(EditorAction::Redo, mouse_free) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Input,
+            "(shortcut) Redo"
+        );
+        events.history.write(RequestHistoryEvent::Redo);
+    }
+
     // Shft-P
     // Relationship menu
-    if input.shift_left.pressed
-        && input.key_p.just_pressed
-        && !input.mouse_over_egui
-        && !input.mouse_right.any
-    {
+    if triggered(
+        EditorAction::AddRelationship,
+        !input.mouse_over_egui && !input.mouse_right.any,
+    ) {
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -247,4 +348,19 @@ fn handle_shortcuts(
             mouse_pos: input.mouse_pos,
         });
     }
+
+    // Ctrl-P
+    // Command palette
+    if triggered(EditorAction::CommandPalette, !input.mouse_right.any) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Input,
+            "(shortcut) Opening command palette"
+        );
+        events.popup.write(PopupMenuRequestedEvent {
+            popup: PopupType::CommandPalette,
+            mouse_pos: Vec2::NAN,
+        });
+    }
 }
@@ -1,7 +1,10 @@
+use crate::history::RequestHistoryEvent;
+use crate::input::EditorAction;
 use crate::interface::popups::PopupType;
 use crate::interface::tabs::entity_editor::{
     EntityGlobalTransformData, EntityIdentityData, EntityRegisteredData,
 };
+use crate::play::RequestPlayEvent;
 use bevy::ecs::event::EventWriter;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::{Entity, Event, Vec2};
@@ -27,6 +30,8 @@ pub struct EditorEvents<'w> {
     pub despawn_all: EventWriter<'w, RequestDespawnSerializableEntities>,
     pub despawn_by_source: EventWriter<'w, RequestDespawnBySource>,
     pub set_active_world: EventWriter<'w, SetActiveWorld>,
+    pub history: EventWriter<'w, RequestHistoryEvent>,
+    pub play: EventWriter<'w, RequestPlayEvent>,
 }
 
 // Internal Events
@@ -109,3 +114,15 @@ pub struct RequestRemoveParentsFromEntities {
 
 #[derive(Event)]
 pub struct RequestRemoveChildren;
+
+/// Serialize the selection into the system clipboard
+#[derive(Event)]
+pub struct RequestCopyEntities;
+
+/// Spawn entities from clipboard text
+#[derive(Event)]
+pub struct RequestPasteEntities(pub String);
+
+/// Run a bindable editor action as if its shortcut was pressed
+#[derive(Event)]
+pub struct RequestEditorAction(pub EditorAction);
@@ -9,6 +9,7 @@ use crate::{
         },
         EditorEvents, SettingsTab,
     },
+    play::PlayState,
     viewport::{EditorViewportCamera, ViewportCameraState},
 };
 
@@ -62,6 +63,7 @@ pub fn dock_ui_system(
         (With<Camera3d>, Without<UICamera>, Without<EditorViewportCamera>),
     >,
     viewport_camera_state: Res<ViewportCameraState>,
+    play_state: Res<PlayState>,
 ) {
     let mut camera_options: Vec<(Entity, String)> = camera_query
         .iter()
@@ -99,6 +101,7 @@ pub fn dock_ui_system(
                     &mut commands,
                     &camera_options,
                     viewport_camera_state.as_ref(),
+                    &play_state,
                 );
             });
         });
@@ -1,5 +1,6 @@
 use crate::{
     editor_state::EditorState,
+    input::EditorAction,
     interface::{
         events::{
             PopupMenuRequestedEvent, RequestCameraEntityFrame, RequestEditorToggle,
@@ -11,11 +12,12 @@ use crate::{
         },
         popups::PopupType,
         tabs::{
-            debug::ui::DebugTabData, log::LogTabData, EditorSettingsTabData, EntityEditorTabData,
-            EventsTabData,
+            debug::ui::DebugTabData, log::LogTabData, AssetsTabData, EditorSettingsTabData,
+            EntityEditorTabData, EventsTabData, HistoryTabData,
         },
         EditorEvents, NodeTreeTabData,
     },
+    play::{PlayState, RequestPlayEvent},
     viewport::ViewportCameraState,
     UI_CONFIG,
 };
@@ -38,6 +40,7 @@ pub fn top_bar_ui(
     commands: &mut Commands,
     camera_options: &[(Entity, String)],
     viewport_camera_state: &ViewportCameraState,
+    play_state: &PlayState,
 ) {
     let active_camera_label = if viewport_camera_state.is_using_editor() {
         "Editor Camera".to_string()
@@ -50,6 +53,9 @@ pub fn top_bar_ui(
     };
 
     let spacing = UI_CONFIG.spacing;
+    let keybindings = &editor_state.config.keybindings;
+    // Saving while playing would write the game's changes into the scene
+    let can_save = !play_state.is_playing();
 
     ui.vertical(|ui| {
         ui.add_space(spacing);
@@ -57,7 +63,10 @@ pub fn top_bar_ui(
         // MENUs
         ui.horizontal(|ui| {
             ui.menu_button("File", |ui| {
-                if ui.button("Save as").clicked() {
+                if ui
+                    .add_enabled(can_save, egui::Button::new("Save as"))
+                    .clicked()
+                {
                     if let Some(path) = FileDialog::new()
                         .add_filter("Granite Scene", &["scene"])
                         .show_save_single_file()
@@ -70,7 +79,16 @@ pub fn top_bar_ui(
                     ui.close();
                 }
 
-                if ui.button("Save (Ctrl + S)").clicked() {
+                if ui
+                    .add_enabled(
+                        can_save,
+                        egui::Button::new(format!(
+                            "Save ({})",
+                            keybindings.display(EditorAction::SaveWorld)
+                        )),
+                    )
+                    .clicked()
+                {
                     let loaded = &editor_state.loaded_sources;
                     if !loaded.is_empty() {
                         for source in loaded.iter() {
@@ -80,7 +98,13 @@ pub fn top_bar_ui(
                     ui.close();
                 }
 
-                if ui.button("Open (Ctrl + O)").clicked() {
+                if ui
+                    .button(format!(
+                        "Open ({})",
+                        keybindings.display(EditorAction::OpenWorld)
+                    ))
+                    .clicked()
+                {
                     if let Some(path) = FileDialog::new()
                         .add_filter("Granite Scene", &["scene"])
                         .show_open_single_file()
@@ -169,7 +193,10 @@ pub fn top_bar_ui(
                     ui.close();
                 }
 
-                if ui.button("Save Default World").clicked() {
+                if ui
+                    .add_enabled(can_save, egui::Button::new("Save Default World"))
+                    .clicked()
+                {
                     events
                         .save
                         .write(RequestSaveEvent(editor_state.default_world.clone()));
@@ -261,6 +288,34 @@ pub fn top_bar_ui(
                     bottom_dock.dock_state.push_to_focused_leaf(tab);
                     ui.close();
                 }
+
+                // Assets
+                if !bottom_dock
+                    .dock_state
+                    .iter_all_tabs()
+                    .any(|(_, tab)| matches!(tab, BottomTab::Assets { .. }))
+                    && ui.button("Assets").clicked()
+                {
+                    let tab = BottomTab::Assets {
+                        data: AssetsTabData::default(),
+                    };
+                    bottom_dock.dock_state.push_to_focused_leaf(tab);
+                    ui.close();
+                }
+
+                // History
+                if !bottom_dock
+                    .dock_state
+                    .iter_all_tabs()
+                    .any(|(_, tab)| matches!(tab, BottomTab::History { .. }))
+                    && ui.button("History").clicked()
+                {
+                    let tab = BottomTab::History {
+                        data: HistoryTabData::default(),
+                    };
+                    bottom_dock.dock_state.push_to_focused_leaf(tab);
+                    ui.close();
+                }
             });
         });
 
@@ -269,36 +324,83 @@ pub fn top_bar_ui(
         // Buttons
         ui.horizontal(|ui| {
             ui.separator();
-            if ui.button("Add Entity (Shft + A) ").clicked() {
+            if ui
+                .button(format!(
+                    "Add Entity ({}) ",
+                    keybindings.display(EditorAction::AddEntity)
+                ))
+                .clicked()
+            {
                 events.popup.write(PopupMenuRequestedEvent {
                     popup: PopupType::AddEntity,
                     mouse_pos: user_input.mouse_pos,
                 });
             }
             ui.separator();
-            if ui.button("Parents (Shft + P) ").clicked() {
+            if ui
+                .button(format!(
+                    "Parents ({}) ",
+                    keybindings.display(EditorAction::AddRelationship)
+                ))
+                .clicked()
+            {
                 events.popup.write(PopupMenuRequestedEvent {
                     popup: PopupType::AddRelationship,
                     mouse_pos: user_input.mouse_pos,
                 });
             }
             ui.separator();
-            if ui.button("Show Help (F1) ").clicked() {
+            if ui
+                .button(format!(
+                    "Show Help ({}) ",
+                    keybindings.display(EditorAction::Help)
+                ))
+                .clicked()
+            {
                 events.popup.write(PopupMenuRequestedEvent {
                     popup: PopupType::Help,
                     mouse_pos: user_input.mouse_pos,
                 });
             }
             ui.separator();
-            if ui.button("Toggle Editor (F2) ").clicked() {
+            if ui
+                .button(format!(
+                    "Toggle Editor ({}) ",
+                    keybindings.display(EditorAction::ToggleEditor)
+                ))
+                .clicked()
+            {
                 events.toggle_editor.write(RequestEditorToggle);
             }
 
             ui.separator();
-            if ui.button("Toggle Camera Control (F3) ").clicked() {
+            if ui
+                .button(format!(
+                    "Toggle Camera Control ({}) ",
+                    keybindings.display(EditorAction::ToggleCameraSync)
+                ))
+                .clicked()
+            {
                 events.toggle_cam_sync.write(RequestToggleCameraSync);
             }
 
+            ui.separator();
+            let play_label = if play_state.is_playing() {
+                "Stop"
+            } else {
+                "Play"
+            };
+            if ui
+                .button(format!(
+                    "{} ({})",
+                    play_label,
+                    keybindings.display(EditorAction::PlayStop)
+                ))
+                .clicked()
+            {
+                events.play.write(RequestPlayEvent::TogglePlay);
+            }
+
             ui.separator();
             ui.label(format!("Viewing: {}", active_camera_label));
             ui.menu_button("Viewport Camera", |ui| {
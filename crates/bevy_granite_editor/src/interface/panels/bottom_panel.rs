@@ -4,7 +4,8 @@ use egui_dock::{DockState, NodeIndex, TabViewer};
 use serde::{Deserialize, Serialize};
 
 use crate::interface::tabs::{
-    debug_tab_ui, events_tab_ui, log_tab_ui, DebugTabData, EventsTabData, LogTabData,
+    assets_tab_ui, debug_tab_ui, events_tab_ui, history_tab_ui, log_tab_ui, AssetsTabData,
+    DebugTabData, EventsTabData, HistoryTabData, LogTabData,
 };
 
 #[derive(Resource, Clone)]
@@ -23,13 +24,16 @@ impl Default for BottomDockState {
         let events_tab = BottomTab::Events {
             data: EventsTabData::default(),
         };
+        let history_tab = BottomTab::History {
+            data: HistoryTabData::default(),
+        };
 
         let mut dock_state = DockState::new(vec![debug_tab]);
 
         let surface = dock_state.main_surface_mut();
 
         let [_debug_node, remaining] =
-            surface.split_right(NodeIndex::root(), 0.33, vec![events_tab]);
+            surface.split_right(NodeIndex::root(), 0.33, vec![events_tab, history_tab]);
         let [_events_node, _log_node] = surface.split_right(remaining, 0.5, vec![log_tab]);
 
         Self { dock_state }
@@ -50,6 +54,14 @@ pub enum BottomTab {
         #[serde(skip)]
         data: EventsTabData,
     },
+    Assets {
+        #[serde(skip)]
+        data: AssetsTabData,
+    },
+    History {
+        #[serde(skip)]
+        data: HistoryTabData,
+    },
 }
 
 #[derive(Resource)]
@@ -63,6 +75,8 @@ impl TabViewer for BottomTabViewer {
             BottomTab::Log { data, .. } => log_tab_ui(ui, data),
             BottomTab::Debug { data, .. } => debug_tab_ui(ui, data),
             BottomTab::Events { data, .. } => events_tab_ui(ui, data),
+            BottomTab::Assets { data, .. } => assets_tab_ui(ui, data),
+            BottomTab::History { data, .. } => history_tab_ui(ui, data),
         }
     }
 
@@ -71,6 +85,8 @@ impl TabViewer for BottomTabViewer {
             BottomTab::Log { .. } => "Log".into(),
             BottomTab::Debug { .. } => "Debug".into(),
             BottomTab::Events { .. } => "Events".into(),
+            BottomTab::Assets { .. } => "Assets".into(),
+            BottomTab::History { .. } => "History".into(),
         }
     }
 }
@@ -2,19 +2,21 @@ use super::{
     cache::update_entity_cache_system,
     events::{
         MaterialDeleteEvent, MaterialHandleUpdateEvent, PopupMenuRequestedEvent,
-        RequestCameraEntityFrame, RequestEditorToggle, RequestNewParent, RequestRemoveChildren,
-        RequestRemoveParents, RequestToggleCameraSync, RequestViewportCameraOverride, SetActiveWorld,
+        RequestCameraEntityFrame, RequestCopyEntities, RequestEditorAction, RequestEditorToggle,
+        RequestNewParent, RequestPasteEntities, RequestRemoveChildren, RequestRemoveParents,
+        RequestToggleCameraSync, RequestViewportCameraOverride, SetActiveWorld,
         UserRequestGraniteTypeViaPopup, UserUpdatedComponentsEvent, UserUpdatedIdentityEvent,
         UserUpdatedTransformEvent,
     },
     layout::dock_ui_system,
     popups::{handle_popup_requests_system, show_active_popups_system},
     tabs::{
-        handle_material_deletion_system, send_queued_events_system, update_debug_tab_ui_system,
-        update_editor_settings_tab_system, update_entity_editor_tab_system,
-        update_entity_with_new_components_system, update_entity_with_new_identity_system,
-        update_entity_with_new_transform_system, update_log_tab_system,
-        update_material_handle_system, update_node_tree_tabs_system, RequestReparentEntityEvent,
+        handle_material_deletion_system, send_queued_events_system, update_assets_tab_system,
+        update_debug_tab_ui_system, update_editor_settings_tab_system,
+        update_entity_editor_tab_system, update_entity_with_new_components_system,
+        update_entity_with_new_identity_system, update_entity_with_new_transform_system,
+        update_history_tab_system, update_log_tab_system, update_material_handle_system,
+        update_node_tree_tabs_system, RequestReparentEntityEvent,
     },
     BottomDockState, EntityUIDataCache, PopupState, SideDockState,
 };
@@ -48,6 +50,9 @@ impl Plugin for InterfacePlugin {
             .add_event::<RequestRemoveChildren>()
             .add_event::<RequestRemoveParents>()
             .add_event::<SetActiveWorld>()
+            .add_event::<RequestCopyEntities>()
+            .add_event::<RequestPasteEntities>()
+            .add_event::<RequestEditorAction>()
             // need to rework
             .add_event::<RequestReparentEntityEvent>()
             .add_event::<RequestRemoveParentsFromEntities>()
@@ -94,6 +99,8 @@ impl Plugin for InterfacePlugin {
                     update_editor_settings_tab_system,
                     update_log_tab_system,
                     update_debug_tab_ui_system,
+                    update_assets_tab_system,
+                    update_history_tab_system,
                     update_node_tree_tabs_system,
                 )
                     .chain()
@@ -0,0 +1,119 @@
+use crate::{
+    input::KeyBindings,
+    interface::shared::widgets::make_frame_solid_via_context,
+    palette::{filter_entries, palette_entries, CommandPalette},
+    UI_CONFIG,
+};
+use bevy::window::Window as BevyWindow;
+use bevy_egui::{
+    egui::{self, Window},
+    EguiContexts,
+};
+
+// Arrows move through the matches, Enter or a click runs one, Esc or clicking away closes
+pub fn command_palette_ui(
+    contexts: &mut EguiContexts,
+    window: &BevyWindow,
+    palette: &mut CommandPalette,
+    keybindings: &KeyBindings,
+) -> bool {
+    let mut should_close = false;
+
+    let spacing = UI_CONFIG.spacing;
+    let width = (window.width() * 0.4).max(400.0);
+    let max_list_height = window.height() * 0.5;
+    let ctx = contexts.ctx_mut().expect("Egui context to exist");
+    let _response = Window::new("Command Palette")
+        .title_bar(false)
+        .resizable(false)
+        .default_width(width)
+        .anchor(
+            egui::Align2::CENTER_TOP,
+            egui::Vec2::new(0.0, window.height() * 0.1),
+        )
+        .frame(make_frame_solid_via_context(
+            egui::Frame::window(&ctx.style()),
+            ctx,
+        ))
+        .show(ctx, |ui| {
+            ui.set_width(width);
+            let search = ui.add(
+                egui::TextEdit::singleline(&mut palette.query)
+                    .hint_text("Type a command...")
+                    .desired_width(f32::INFINITY),
+            );
+            search.request_focus();
+            if search.changed() {
+                palette.selected = 0;
+            }
+
+            let entries = filter_entries(palette_entries(palette, keybindings), &palette.query);
+            let (up, down, enter, escape) = ui.input(|input| {
+                (
+                    input.key_pressed(egui::Key::ArrowUp),
+                    input.key_pressed(egui::Key::ArrowDown),
+                    input.key_pressed(egui::Key::Enter),
+                    input.key_pressed(egui::Key::Escape),
+                )
+            });
+            if escape {
+                should_close = true;
+            }
+            if !entries.is_empty() {
+                if down {
+                    palette.selected = (palette.selected + 1) % entries.len();
+                }
+                if up {
+                    palette.selected = (palette.selected + entries.len() - 1) % entries.len();
+                }
+                palette.selected = palette.selected.min(entries.len() - 1);
+            }
+
+            ui.add_space(spacing);
+            ui.separator();
+
+            let mut chosen = if enter {
+                entries.get(palette.selected).cloned()
+            } else {
+                None
+            };
+            if entries.is_empty() {
+                ui.weak("No matching commands");
+            }
+            egui::ScrollArea::vertical()
+                .max_height(max_list_height)
+                .show(ui, |ui| {
+                    for (index, entry) in entries.iter().enumerate() {
+                        let is_selected = index == palette.selected;
+                        let response = ui
+                            .horizontal(|ui| {
+                                let response = ui.selectable_label(is_selected, entry.title());
+                                if let Some(keybinding) = &entry.keybinding {
+                                    ui.with_layout(
+                                        egui::Layout::right_to_left(egui::Align::Center),
+                                        |ui| ui.weak(keybinding),
+                                    );
+                                }
+                                response
+                            })
+                            .inner;
+                        if is_selected && (up || down) {
+                            response.scroll_to_me(None);
+                        }
+                        if response.clicked() {
+                            chosen = Some(entry.clone());
+                        }
+                    }
+                });
+
+            if let Some(entry) = chosen {
+                palette.pending = Some(entry.run.clone());
+                should_close = true;
+            }
+        });
+
+    if ctx.input(|i| i.pointer.any_click()) && !ctx.is_pointer_over_area() {
+        should_close = true;
+    }
+    should_close
+}
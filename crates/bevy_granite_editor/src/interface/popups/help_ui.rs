@@ -44,7 +44,12 @@ pub fn help_ui(
                             .show(ui, |ui| {
                                 ui.heading(HELP_CONFIG.header.to_string());
                                 ui.add_space(spacing);
-                                ui.label(HELP_CONFIG.body.to_string());
+                                ui.label(
+                                    editor_state
+                                        .config
+                                        .keybindings
+                                        .fill_help_text(&HELP_CONFIG.body),
+                                );
                                 ui.add_space(spacing);
                                 ui.label(HELP_CONFIG.youtube_text.to_string());
                                 ui.add_space(spacing);
@@ -2,8 +2,10 @@ pub mod add_entity_ui;
 pub mod relationship_ui;
 pub mod popup_requested_system;
 pub mod help_ui;
+pub mod command_palette_ui;
 
 pub use add_entity_ui::*;
 pub use relationship_ui::*;
 pub use popup_requested_system::*;
-pub use help_ui::*;
\ No newline at end of file
+pub use help_ui::*;
+pub use command_palette_ui::*;
\ No newline at end of file
@@ -2,7 +2,8 @@ use bevy::{
     ecs::{
         event::{EventReader, EventWriter},
         query::With,
-        system::{Query, ResMut},
+        system::{Commands, Query, ResMut, SystemParam},
+        world::World,
     },
     math::Vec2,
     prelude::Resource,
@@ -17,9 +18,10 @@ use bevy_granite_logging::{
 use crate::{
     editor_state::EditorState,
     interface::{
-        popups::{add_entity_ui, help_ui, relationship_ui},
+        popups::{add_entity_ui, command_palette_ui, help_ui, relationship_ui},
         EditorEvents, PopupMenuRequestedEvent, UserRequestGraniteTypeViaPopup,
     },
+    palette::CommandPalette,
 };
 
 #[derive(Debug, Clone)]
@@ -27,6 +29,7 @@ pub enum PopupType {
     AddRelationship,
     AddEntity,
     Help,
+    CommandPalette,
 }
 
 #[derive(Default, Resource)]
@@ -52,6 +55,13 @@ pub fn handle_popup_requests_system(
     }
 }
 
+/// The command palette and the commands its picked entry is queued on
+#[derive(SystemParam)]
+pub struct PaletteRunner<'w, 's> {
+    pub commands: Commands<'w, 's>,
+    pub palette: ResMut<'w, CommandPalette>,
+}
+
 pub fn show_active_popups_system(
     mut contexts: EguiContexts,
     mut popup_state: ResMut<PopupState>,
@@ -59,7 +69,12 @@ pub fn show_active_popups_system(
     entity_add_writer: EventWriter<UserRequestGraniteTypeViaPopup>,
     window_query: Query<&Window, With<PrimaryWindow>>,
     editor_state: ResMut<EditorState>,
+    runner: PaletteRunner,
 ) {
+    let PaletteRunner {
+        mut commands,
+        mut palette,
+    } = runner;
     if let Some(popup_type) = &popup_state.active_popup {
         let should_close = match popup_type {
             PopupType::AddEntity => {
@@ -75,10 +90,30 @@ pub fn show_active_popups_system(
                     false
                 }
             }
+            PopupType::CommandPalette => {
+                if let Ok(window) = window_query.single() {
+                    command_palette_ui(
+                        &mut contexts,
+                        window,
+                        &mut palette,
+                        &editor_state.config.keybindings,
+                    )
+                } else {
+                    false
+                }
+            }
         };
 
         if should_close {
+            if matches!(popup_type, PopupType::CommandPalette) {
+                palette.reset();
+            }
             popup_state.active_popup = None;
         }
     }
+
+    // Runs after the ui so the command sees the world as it is once the palette is gone
+    if let Some(run) = palette.pending.take() {
+        commands.queue(move |world: &mut World| run(world));
+    }
 }
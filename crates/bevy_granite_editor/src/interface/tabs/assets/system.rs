@@ -0,0 +1,54 @@
+use super::AssetsTabData;
+use crate::{
+    editor_state::EditorState,
+    interface::{BottomDockState, BottomTab},
+};
+use bevy::prelude::{EventReader, EventWriter, Res, ResMut};
+use bevy_granite_core::{
+    build_asset_dependency_report, plan_asset_rename, AssetRenameSuccessEvent,
+    RequestAssetRenameEvent,
+};
+
+pub fn update_assets_tab_system(
+    mut bottom_dock: ResMut<BottomDockState>,
+    editor_state: Res<EditorState>,
+    mut rename_writer: EventWriter<RequestAssetRenameEvent>,
+    mut rename_success_reader: EventReader<AssetRenameSuccessEvent>,
+) {
+    let renamed = rename_success_reader.read().count() > 0;
+    for (_, tab) in bottom_dock.dock_state.iter_all_tabs_mut() {
+        if let BottomTab::Assets { ref mut data, .. } = tab {
+            let rename = &mut data.rename;
+            if rename.preview_requested {
+                rename.preview = Some(plan_asset_rename(&rename.from, &rename.to));
+                rename.preview_requested = false;
+            }
+            if rename.apply_requested {
+                if let Some(Ok(plan)) = rename.preview.take() {
+                    rename_writer.write(RequestAssetRenameEvent(plan.from, plan.to));
+                }
+                rename.apply_requested = false;
+            }
+            if renamed {
+                rename.from.clear();
+                rename.to.clear();
+                data.refresh_requested = true;
+            }
+
+            if data.refresh_requested {
+                refresh_report(data, &editor_state);
+                data.refresh_requested = false;
+            }
+        }
+    }
+}
+
+fn refresh_report(data: &mut AssetsTabData, editor_state: &EditorState) {
+    let mut scenes: Vec<String> = editor_state.loaded_sources.iter().cloned().collect();
+    if scenes.is_empty() {
+        if let Some(file) = &editor_state.current_file {
+            scenes.push(file.to_string());
+        }
+    }
+    data.report = Some(build_asset_dependency_report(&scenes));
+}
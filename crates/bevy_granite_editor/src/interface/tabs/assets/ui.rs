@@ -0,0 +1,233 @@
+use bevy_egui::egui::{self, Color32};
+use bevy_granite_core::{
+    absolute_asset_to_rel, AssetDependency, AssetDependencyKind, AssetDependencyReport,
+    AssetRenamePlan,
+};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use native_dialog::FileDialog;
+
+#[derive(PartialEq, Clone)]
+pub struct AssetsTabData {
+    pub report: Option<AssetDependencyReport>,
+    pub refresh_requested: bool,
+    pub missing_only: bool,
+    pub search: String,
+    pub rename: AssetRenameData,
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct AssetRenameData {
+    pub from: String,
+    pub to: String,
+    pub preview: Option<Result<AssetRenamePlan, String>>,
+    pub preview_requested: bool,
+    pub apply_requested: bool,
+}
+
+impl Default for AssetsTabData {
+    fn default() -> Self {
+        Self {
+            report: None,
+            refresh_requested: true,
+            missing_only: false,
+            search: String::new(),
+            rename: AssetRenameData::default(),
+        }
+    }
+}
+
+pub fn assets_tab_ui(ui: &mut egui::Ui, data: &mut AssetsTabData) {
+    let spacing = crate::UI_CONFIG.spacing;
+    let small_spacing = crate::UI_CONFIG.small_spacing;
+
+    ui.horizontal(|ui| {
+        if ui.button("Refresh").clicked() {
+            data.refresh_requested = true;
+        }
+        if ui
+            .add_enabled(data.report.is_some(), egui::Button::new("Export Manifest"))
+            .clicked()
+        {
+            if let Some(report) = &data.report {
+                export_manifest(report);
+            }
+        }
+        ui.separator();
+        ui.checkbox(&mut data.missing_only, "Missing only");
+        ui.separator();
+        ui.label("Search:");
+        ui.text_edit_singleline(&mut data.search);
+    });
+    ui.add_space(spacing);
+
+    ui.collapsing("Rename / Move Asset", |ui| {
+        rename_ui(ui, &mut data.rename);
+    });
+    ui.add_space(small_spacing);
+
+    let Some(report) = &data.report else {
+        ui.label("No report yet. Load a scene and press Refresh.");
+        return;
+    };
+
+    let missing_count = report.missing_count();
+    ui.label(format!(
+        "{} scene(s), {} dependencies, {} missing, {} unused materials",
+        report.scenes.len(),
+        report.dependencies.len(),
+        missing_count,
+        report.unused_materials.len()
+    ));
+    ui.add_space(small_spacing);
+
+    let search = data.search.to_lowercase();
+    let matches_search =
+        |path: &str| search.is_empty() || path.to_lowercase().contains(search.as_str());
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            ui.collapsing(format!("Missing ({})", missing_count), |ui| {
+                if missing_count == 0 {
+                    ui.weak("Nothing missing");
+                }
+                for dependency in report.missing().filter(|dep| matches_search(&dep.path)) {
+                    dependency_row(ui, dependency);
+                }
+            });
+
+            for (label, kind) in [
+                ("Scenes", AssetDependencyKind::Scene),
+                ("Meshes", AssetDependencyKind::Mesh),
+                ("Materials", AssetDependencyKind::Material),
+                ("Textures", AssetDependencyKind::Texture),
+            ] {
+                let dependencies: Vec<&AssetDependency> = report
+                    .of_kind(kind)
+                    .filter(|dep| !data.missing_only || !dep.exists)
+                    .filter(|dep| matches_search(&dep.path))
+                    .collect();
+                ui.collapsing(format!("{} ({})", label, dependencies.len()), |ui| {
+                    for dependency in dependencies {
+                        dependency_row(ui, dependency);
+                    }
+                });
+            }
+
+            if !data.missing_only {
+                ui.collapsing(
+                    format!("Unused Materials ({})", report.unused_materials.len()),
+                    |ui| {
+                        ui.weak("Materials in materials/ not referenced by any loaded scene");
+                        ui.add_space(small_spacing);
+                        for path in report.unused_materials.iter().filter(|p| matches_search(p)) {
+                            ui.label(path);
+                        }
+                    },
+                );
+            }
+        });
+}
+
+fn rename_ui(ui: &mut egui::Ui, rename: &mut AssetRenameData) {
+    let small_spacing = crate::UI_CONFIG.small_spacing;
+    let mut paths_changed = false;
+
+    egui::Grid::new("asset_rename_grid")
+        .num_columns(2)
+        .spacing([small_spacing, small_spacing])
+        .show(ui, |ui| {
+            ui.label("From:");
+            ui.horizontal(|ui| {
+                paths_changed |= ui.text_edit_singleline(&mut rename.from).changed();
+                if ui.button("Browse").clicked() {
+                    if let Ok(Some(path)) = FileDialog::new().show_open_single_file() {
+                        rename.from = absolute_asset_to_rel(path.display().to_string()).to_string();
+                        if rename.to.is_empty() {
+                            rename.to = rename.from.clone();
+                        }
+                        paths_changed = true;
+                    }
+                }
+            });
+            ui.end_row();
+
+            ui.label("To:");
+            paths_changed |= ui.text_edit_singleline(&mut rename.to).changed();
+            ui.end_row();
+        });
+
+    // A stale preview is worse than none
+    if paths_changed {
+        rename.preview = None;
+    }
+
+    ui.horizontal(|ui| {
+        if ui.button("Preview").clicked() {
+            rename.preview_requested = true;
+        }
+        let can_apply = matches!(rename.preview, Some(Ok(_)));
+        if ui
+            .add_enabled(can_apply, egui::Button::new("Apply"))
+            .on_disabled_hover_text("Preview the rename first")
+            .clicked()
+        {
+            rename.apply_requested = true;
+        }
+    });
+
+    match &rename.preview {
+        Some(Ok(plan)) => {
+            ui.label(format!(
+                "{} -> {}: {} references in {} files",
+                plan.from,
+                plan.to,
+                plan.total_references(),
+                plan.edits.len()
+            ));
+            for edit in &plan.edits {
+                ui.weak(format!("{} ({})", edit.path, edit.occurrences));
+            }
+        }
+        Some(Err(e)) => {
+            ui.colored_label(Color32::LIGHT_RED, e);
+        }
+        None => {}
+    }
+}
+
+fn dependency_row(ui: &mut egui::Ui, dependency: &AssetDependency) {
+    let referenced_by = dependency
+        .referenced_by
+        .iter()
+        .cloned()
+        .collect::<Vec<_>>()
+        .join("\n");
+    let response = if dependency.exists {
+        ui.label(&dependency.path)
+    } else {
+        ui.colored_label(Color32::LIGHT_RED, format!("{} (missing)", dependency.path))
+    };
+    response.on_hover_text(format!("Referenced by:\n{}", referenced_by));
+}
+
+fn export_manifest(report: &AssetDependencyReport) {
+    let Ok(Some(path)) = FileDialog::new()
+        .add_filter("Asset Manifest", &["ron"])
+        .show_save_single_file()
+    else {
+        return;
+    };
+    if let Err(e) = report.export_manifest(&path.display().to_string()) {
+        log!(
+            LogType::Editor,
+            LogLevel::Error,
+            LogCategory::Asset,
+            "{}",
+            e
+        );
+    }
+}
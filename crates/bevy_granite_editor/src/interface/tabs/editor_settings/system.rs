@@ -4,6 +4,7 @@ use crate::{
         editor::save_editor_settings_from_widget_data,
         EditorState,
     },
+    input::{keybindings::is_modifier, KeyBindings, KeyChord},
     interface::{
         layout::{DockState, SidePanelPosition},
         panels::{BottomDockState, SideDockState, SideTab},
@@ -13,7 +14,13 @@ use crate::{
     viewport::{DebugRenderer, SelectionRenderer, ViewportState},
 };
 
-use bevy::{gizmos::config::GizmoConfigStore, math::Vec2, prelude::ResMut};
+use bevy::{
+    ecs::system::SystemParam,
+    gizmos::config::GizmoConfigStore,
+    input::{keyboard::KeyCode, ButtonInput},
+    math::Vec2,
+    prelude::ResMut,
+};
 use bevy_egui::egui::{self};
 use bevy_egui::EguiContexts;
 use bevy_granite_core::PromptImportSettings;
@@ -25,6 +32,7 @@ pub enum SettingsTab {
     Viewport,
     Interface,
     Import,
+    Keybindings,
 }
 
 #[derive(PartialEq, Clone, Serialize, Deserialize, Debug)]
@@ -51,6 +59,8 @@ pub struct EditorSettingsTabData {
     pub import_state: ImportState,
     pub dock: DockState,
     pub show_help_on_start: bool,
+    #[serde(default)]
+    pub keybindings: KeyBindings,
 
     #[serde(skip)]
     pub save_requested: bool,
@@ -70,20 +80,32 @@ impl Default for EditorSettingsTabData {
                 changed: true,
             },
             show_help_on_start: true,
+            keybindings: KeyBindings::default(),
             viewport: ViewportState::default(),
         }
     }
 }
 
+/// Both docks, the side one holds the settings tab and the layout is saved with the settings
+#[derive(SystemParam)]
+pub struct EditorDocks<'w> {
+    pub side_dock: ResMut<'w, SideDockState>,
+    pub bottom_dock: ResMut<'w, BottomDockState>,
+}
+
 pub fn update_editor_settings_tab_system(
     mut contexts: EguiContexts,
-    mut side_dock: ResMut<SideDockState>,
-    mut bottom_dock: ResMut<BottomDockState>,
+    docks: EditorDocks,
     mut editor_state: ResMut<EditorState>,
     mut gizmo_config_store: ResMut<GizmoConfigStore>,
     mut prompt_import_settings: ResMut<PromptImportSettings>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
     mut events: EditorEvents,
 ) {
+    let EditorDocks {
+        mut side_dock,
+        mut bottom_dock,
+    } = docks;
     let ctx = contexts.ctx_mut().expect("Egui context to exist");
 
     let side_dock_clone = side_dock.clone();
@@ -156,6 +178,14 @@ pub fn update_editor_settings_tab_system(
                 debug_config.line.width = data.viewport.visualizers.debug_line_thickness;
             }
 
+            capture_keybinding(&mut data.keybindings, &mut keyboard);
+            if data.keybindings.changed || settings_desynced {
+                data.keybindings.changed = false;
+                editor_state.config.keybindings = data.keybindings.clone();
+            }
+            // Shortcuts stay paused while a rebind waits for its key
+            editor_state.config.keybindings.listening = data.keybindings.listening;
+
             if data.save_requested {
                 save_editor_settings_from_widget_data(
                     &mut editor_state,
@@ -170,3 +200,27 @@ pub fn update_editor_settings_tab_system(
         }
     }
 }
+
+/// Adds the next key pressed to the action waiting in the settings tab, Esc cancels
+/// The key is consumed so the shortcut it now triggers doesn't fire on the same press
+fn capture_keybinding(keybindings: &mut KeyBindings, keyboard: &mut ButtonInput<KeyCode>) {
+    let Some(action) = keybindings.listening else {
+        return;
+    };
+    let Some(key) = keyboard
+        .get_just_pressed()
+        .copied()
+        .find(|key| !is_modifier(*key))
+    else {
+        return;
+    };
+    keyboard.clear_just_pressed(key);
+    if key == KeyCode::Escape {
+        keybindings.listening = None;
+        return;
+    }
+    if let Some(chord) = KeyChord::from_input(key, keyboard) {
+        keybindings.add(action, chord);
+        keybindings.listening = None;
+    }
+}
@@ -1,5 +1,6 @@
 use super::{EditorSettingsTabData, SettingsTab};
 use crate::{
+    input::{keybindings::fixed_chords, EditorAction, KeyBindings},
     interface::{
         layout::SidePanelPosition, tabs::editor_settings::ImportState, themes::ThemeState,
     },
@@ -559,6 +560,79 @@ fn build_import_settings_section(ui: &mut egui::Ui, data: &mut ImportState) {
     });
 }
 
+// One row per action, clicking the chord waits for the next key press
+fn build_keybindings_section(ui: &mut egui::Ui, keybindings: &mut KeyBindings) {
+    let large_spacing = crate::UI_CONFIG.large_spacing;
+    let spacing = crate::UI_CONFIG.spacing;
+    ui.vertical(|ui| {
+        ui.group(|ui| {
+            ui.add_space(spacing);
+            ui.label("Click + and press the new keys to add a shortcut. Esc cancels.");
+            ui.add_space(large_spacing);
+
+            for action in EditorAction::ALL {
+                let conflicts = keybindings.conflicts(action);
+                let listening = keybindings.listening == Some(action);
+                ui.columns(2, |columns| {
+                    let label_response = columns[0].label(action.label());
+                    if !conflicts.is_empty() {
+                        label_response
+                            .on_hover_text(format!("Also bound to: {}", conflicts.join(", ")));
+                    }
+
+                    columns[1].horizontal_wrapped(|ui| {
+                        for chord in keybindings.chords(action) {
+                            let text = egui::RichText::new(chord.to_string());
+                            let text = if conflicts.is_empty() {
+                                text
+                            } else {
+                                text.color(ui.visuals().error_fg_color)
+                            };
+                            ui.label(text);
+                            if ui.small_button("x").on_hover_text("Unbind").clicked() {
+                                keybindings.remove(action, chord);
+                            }
+                        }
+                        let text = if listening { "Press keys..." } else { "+" };
+                        if ui
+                            .selectable_label(listening, text)
+                            .on_hover_text("Add a shortcut")
+                            .clicked()
+                        {
+                            keybindings.listening = if listening { None } else { Some(action) };
+                        }
+                    });
+                });
+                ui.add_space(spacing);
+            }
+
+            let fixed: Vec<String> = fixed_chords()
+                .iter()
+                .map(|(chord, name)| format!("{} ({})", name, chord))
+                .collect();
+            ui.add_space(spacing);
+            ui.weak(format!(
+                "{} use the system clipboard shortcuts and can't be rebound",
+                fixed.join(" and ")
+            ));
+
+            if keybindings.has_conflicts() {
+                ui.add_space(spacing);
+                ui.colored_label(
+                    ui.visuals().error_fg_color,
+                    "Some shortcuts share keys, hover a name to see what with",
+                );
+            }
+
+            ui.add_space(large_spacing);
+            if ui.button("Reset to Defaults").clicked() {
+                keybindings.listening = None;
+                keybindings.reset();
+            }
+        });
+    });
+}
+
 // ---------------------------------------------------------------------------------------------------
 
 // Building the tabs
@@ -593,6 +667,14 @@ fn build_import_tab(ui: &mut egui::Ui, data: &mut ImportState) {
         });
 }
 
+fn build_keybindings_tab(ui: &mut egui::Ui, keybindings: &mut KeyBindings) {
+    egui::ScrollArea::vertical()
+        .auto_shrink([true; 2])
+        .show(ui, |ui| {
+            build_keybindings_section(ui, keybindings);
+        });
+}
+
 // ---------------------------------------------------------------------------------------------------
 
 // Main ui
@@ -630,7 +712,12 @@ pub fn editor_settings_tab_ui(ui: &mut egui::Ui, data: &mut EditorSettingsTabDat
                         SettingsTab::Interface,
                         "Interface",
                     );
-                    ui.selectable_value(&mut data.dock.active_tab, SettingsTab::Import, "Import")
+                    ui.selectable_value(&mut data.dock.active_tab, SettingsTab::Import, "Import");
+                    ui.selectable_value(
+                        &mut data.dock.active_tab,
+                        SettingsTab::Keybindings,
+                        "Keybindings",
+                    )
                 });
 
                 ui.add_space(spacing);
@@ -644,6 +731,7 @@ pub fn editor_settings_tab_ui(ui: &mut egui::Ui, data: &mut EditorSettingsTabDat
                         build_viewport_tab(ui, &mut data.viewport);
                     }
                     SettingsTab::Import => build_import_tab(ui, &mut data.import_state),
+                    SettingsTab::Keybindings => build_keybindings_tab(ui, &mut data.keybindings),
                 });
             });
         });
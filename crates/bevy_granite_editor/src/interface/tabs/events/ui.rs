@@ -67,7 +67,7 @@ pub fn events_tab_ui(ui: &mut egui::Ui, data: &mut EventsTabData) {
     }
 }
 
-fn clean_name(name: &str) -> String {
+pub fn clean_name(name: &str) -> String {
     let mut result = String::new();
     let mut chars = name.chars().peekable();
     let mut is_first = true;
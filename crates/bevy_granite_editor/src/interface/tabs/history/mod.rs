@@ -0,0 +1,5 @@
+pub mod system;
+pub mod ui;
+
+pub use system::*;
+pub use ui::*;
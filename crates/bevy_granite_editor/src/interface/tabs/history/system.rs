@@ -0,0 +1,33 @@
+use crate::{
+    history::{EditorHistory, RequestHistoryEvent},
+    interface::{BottomDockState, BottomTab},
+};
+use bevy::prelude::{EventWriter, ResMut};
+
+pub fn update_history_tab_system(
+    mut bottom_dock: ResMut<BottomDockState>,
+    mut history: ResMut<EditorHistory>,
+    mut history_writer: EventWriter<RequestHistoryEvent>,
+) {
+    for (_, tab) in bottom_dock.dock_state.iter_all_tabs_mut() {
+        if let BottomTab::History { ref mut data, .. } = tab {
+            if let Some(request) = data.request.take() {
+                history_writer.write(request);
+            }
+            if data.depth_changed {
+                history.depth = data.depth;
+                history.trim();
+                data.depth_changed = false;
+            }
+
+            data.applied = history.undo.len();
+            data.depth = history.depth;
+            data.entries = history
+                .undo
+                .iter()
+                .chain(history.redo.iter().rev())
+                .map(|entry| entry.label.clone())
+                .collect();
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::history::RequestHistoryEvent;
+use bevy_egui::egui::{self, RichText};
+
+#[derive(PartialEq, Clone, Default)]
+pub struct HistoryTabData {
+    /// Applied entries oldest first, then undone entries in the order they would be redone
+    pub entries: Vec<String>,
+    /// How many entries are applied
+    pub applied: usize,
+    pub depth: usize,
+    pub depth_changed: bool,
+    pub request: Option<RequestHistoryEvent>,
+}
+
+pub fn history_tab_ui(ui: &mut egui::Ui, data: &mut HistoryTabData) {
+    let spacing = crate::UI_CONFIG.spacing;
+
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(data.applied > 0, egui::Button::new("Undo"))
+            .clicked()
+        {
+            data.request = Some(RequestHistoryEvent::Undo);
+        }
+        if ui
+            .add_enabled(data.applied < data.entries.len(), egui::Button::new("Redo"))
+            .clicked()
+        {
+            data.request = Some(RequestHistoryEvent::Redo);
+        }
+        if ui
+            .add_enabled(!data.entries.is_empty(), egui::Button::new("Clear"))
+            .clicked()
+        {
+            data.request = Some(RequestHistoryEvent::Clear);
+        }
+        ui.separator();
+        ui.label("Depth:");
+        if ui
+            .add(egui::DragValue::new(&mut data.depth).range(1..=1000))
+            .changed()
+        {
+            data.depth_changed = true;
+        }
+    });
+    ui.add_space(spacing);
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            if ui.selectable_label(data.applied == 0, "Start").clicked() {
+                data.request = Some(RequestHistoryEvent::JumpTo(0));
+            }
+            for (index, label) in data.entries.iter().enumerate() {
+                let applied = index < data.applied;
+                let text = if applied {
+                    RichText::new(label)
+                } else {
+                    RichText::new(label).weak()
+                };
+                // Clicking an entry undoes or redoes up to and including it
+                if ui
+                    .selectable_label(data.applied == index + 1, text)
+                    .clicked()
+                {
+                    data.request = Some(RequestHistoryEvent::JumpTo(index + 1));
+                }
+            }
+        });
+}
@@ -1,10 +1,13 @@
+pub mod assets;
 pub mod debug;
 pub mod editor_settings;
 pub mod entity_editor;
 pub mod events;
+pub mod history;
 pub mod log;
 pub mod node_tree;
 
+pub use assets::{assets_tab_ui, update_assets_tab_system, AssetsTabData};
 pub use debug::{debug_tab_ui, update_debug_tab_ui_system, DebugTabData};
 pub use editor_settings::{update_editor_settings_tab_system, EditorSettingsTabData, SettingsTab};
 pub use entity_editor::{
@@ -13,5 +16,6 @@ pub use entity_editor::{
     update_material_handle_system, EntityEditorTabData,
 };
 pub use events::{events_tab_ui, send_queued_events_system, EventsTabData};
+pub use history::{history_tab_ui, update_history_tab_system, HistoryTabData};
 pub use log::{log_tab_ui, update_log_tab_system, LogTabData};
 pub use node_tree::{update_node_tree_tabs_system, NodeTreeTabData, RequestReparentEntityEvent};
@@ -1,4 +1,5 @@
 use bevy::prelude::{Entity, Event};
+use bevy_granite_core::CsgOperation;
 
 /// Pending actions from context menus to be processed by the system
 #[derive(Debug, Clone, PartialEq)]
@@ -88,6 +89,7 @@ pub struct FlattenedTreeNode {
     pub is_dummy_parent: bool,
     pub is_preserve_disk: bool,
     pub is_preserve_disk_transform: bool,
+    pub csg_operation: Option<CsgOperation>,
 }
 
 impl Default for NodeTreeTabData {
@@ -129,6 +131,7 @@ pub struct HierarchyEntry {
     pub is_dummy_parent: bool, 
     pub is_preserve_disk: bool, 
     pub is_preserve_disk_transform: bool, 
+    pub csg_operation: Option<CsgOperation>, // brush acting as a CSG operand on its parent
 }
 
 /// Events for node tree operations
@@ -1,6 +1,8 @@
 use super::data::{HierarchyEntry, NodeTreeTabData};
 use bevy::prelude::{ChildOf, Entity, Name};
-use bevy_granite_core::{GraniteType, IdentityData, SaveSettings, SpawnSource};
+use bevy_granite_core::{
+    entities::csg::brush_csg_operation, GraniteType, IdentityData, SaveSettings, SpawnSource,
+};
 use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
 use std::collections::HashMap;
 
@@ -101,6 +103,7 @@ pub fn update_hierarchy_data<'a>(
             is_dummy_parent: false,
             is_preserve_disk,
             is_preserve_disk_transform,
+            csg_operation: identity.and_then(|id| brush_csg_operation(&id.class)),
         };
 
         if let Some(spawn_source) = spawn_source {
@@ -143,6 +146,7 @@ fn create_file_grouped_hierarchy(
                 is_dummy_parent: true,
                 is_preserve_disk: false,
                 is_preserve_disk_transform: false,
+                csg_operation: None,
             };
 
             hierarchy_entries.push(dummy_entry);
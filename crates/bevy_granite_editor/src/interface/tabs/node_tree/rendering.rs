@@ -1,6 +1,7 @@
 use super::data::{FlattenedTreeNode, NodeTreeTabData, RowVisualState};
 use bevy::prelude::Entity;
 use bevy_egui::egui;
+use bevy_granite_core::CsgOperation;
 use std::collections::HashMap;
 
 /// Main UI entry point for the node tree tab
@@ -200,6 +201,7 @@ fn flatten_tree_recursive(
             is_dummy_parent: entry.is_dummy_parent,
             is_preserve_disk: entry.is_preserve_disk,
             is_preserve_disk_transform: entry.is_preserve_disk_transform,
+            csg_operation: entry.csg_operation,
         });
 
         // If expanded and has children, recursively add children
@@ -344,6 +346,7 @@ fn render_virtual_type_column(
         }
 
         ui.label(&node.entity_type);
+        render_csg_marker(ui, node.csg_operation);
     });
 }
 
@@ -419,6 +422,7 @@ fn render_search_result_node(
                 &mut columns[1],
                 entry.entity,
                 &entry.entity_type,
+                entry.csg_operation,
                 &visual_state,
                 !data.filtered_hierarchy,
             );
@@ -480,6 +484,7 @@ fn render_type_column(
     ui: &mut egui::Ui,
     entity: Entity,
     entity_type: &str,
+    csg_operation: Option<CsgOperation>,
     visual_state: &RowVisualState,
     verbose: bool,
 ) {
@@ -495,9 +500,23 @@ fn render_type_column(
 
         // Show entity type
         ui.label(entity_type);
+        render_csg_marker(ui, csg_operation);
     });
 }
 
+/// Marks brushes that are CSG operands of their parent, laid out right to left so it sits before the type
+fn render_csg_marker(ui: &mut egui::Ui, csg_operation: Option<CsgOperation>) {
+    let Some(operation) = csg_operation else {
+        return;
+    };
+    ui.label(
+        egui::RichText::new(operation.symbol())
+            .strong()
+            .color(egui::Color32::from_rgb(255, 140, 60)),
+    )
+    .on_hover_text(format!("CSG {} on parent brush", operation.name()));
+}
+
 /// Styling functions for visual elements
 pub mod styling {
     use super::*;
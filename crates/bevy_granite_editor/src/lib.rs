@@ -3,16 +3,22 @@ use bevy::{diagnostic::FrameTimeDiagnosticsPlugin, prelude::*};
 
 pub mod editor_state;
 pub mod entities;
+pub mod history;
 pub mod input;
 pub mod interface;
+pub mod palette;
+pub mod play;
 pub mod setup;
 pub mod utils;
 pub mod viewport;
 
 use editor_state::ConfigPlugin;
 use entities::AssetPlugin;
+use history::HistoryPlugin;
 use input::InputPlugin;
 use interface::InterfacePlugin;
+use palette::PalettePlugin;
+use play::PlayPlugin;
 use viewport::ViewportPlugin;
 
 pub use editor_state::{
@@ -41,6 +47,9 @@ impl Plugin for BevyGraniteEditor {
             // Internal plugins
             .add_plugins(InputPlugin)
             .add_plugins(InterfacePlugin)
+            .add_plugins(HistoryPlugin)
+            .add_plugins(PalettePlugin)
+            .add_plugins(PlayPlugin)
             .add_plugins(ViewportPlugin) // Required
             .add_plugins(AssetPlugin) // Required
             .add_plugins(ConfigPlugin {
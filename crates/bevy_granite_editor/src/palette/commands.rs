@@ -0,0 +1,108 @@
+use super::{fuzzy_score, CommandPalette, PaletteCommand};
+use crate::{
+    history::RequestHistoryEvent,
+    input::{keybindings::COPY_CHORD, EditorAction, KeyBindings},
+    interface::{
+        events::{RequestCopyEntities, RequestEditorAction, UserRequestGraniteTypeViaPopup},
+        tabs::events::ui::{clean_name, EventRequest, EVENT_REGISTRY, EVENT_REQUEST_QUEUE},
+    },
+};
+use bevy_granite_core::{GraniteType, GraniteTypes};
+use bevy_granite_gizmos::gizmos::{GizmoType, NewGizmoType};
+use std::cmp::Reverse;
+
+/// Commands that don't go through a keybinding, registered once by the palette plugin
+pub fn builtin_commands() -> Vec<PaletteCommand> {
+    let mut commands = Vec::new();
+
+    for class in GraniteTypes::all()
+        .into_iter()
+        .filter(|class| class.is_known())
+    {
+        let name = class.type_name();
+        commands.push(PaletteCommand::new("Add Entity", name, move |world| {
+            world.send_event(UserRequestGraniteTypeViaPopup {
+                class: class.clone(),
+            });
+        }));
+    }
+
+    commands.push(
+        PaletteCommand::new("Selection", "Copy", |world| {
+            world.send_event(RequestCopyEntities);
+        })
+        .with_keybinding(COPY_CHORD.to_string()),
+    );
+    commands.push(PaletteCommand::new("History", "Clear History", |world| {
+        world.send_event(RequestHistoryEvent::Clear);
+    }));
+
+    commands
+}
+
+/// Editor actions with their live keybinding, registered commands and #[ui_callable_events]
+pub fn palette_entries(palette: &CommandPalette, keybindings: &KeyBindings) -> Vec<PaletteCommand> {
+    let with_keybinding = |command: PaletteCommand, action: EditorAction| {
+        if keybindings.is_bound(action) {
+            command.with_keybinding(keybindings.display(action))
+        } else {
+            command
+        }
+    };
+
+    let mut entries: Vec<PaletteCommand> = EditorAction::ALL
+        .into_iter()
+        .filter(EditorAction::runs_from_palette)
+        .map(|action| {
+            let command = PaletteCommand::new("Editor", action.label(), move |world| {
+                world.send_event(RequestEditorAction(action));
+            });
+            with_keybinding(command, action)
+        })
+        .collect();
+
+    // Gizmo keys are handled by the gizmos crate, the palette sets the gizmo directly
+    for (gizmo, name, action) in [
+        (GizmoType::Pointer, "Pointer", EditorAction::PointerGizmo),
+        (GizmoType::Transform, "Move", EditorAction::MoveGizmo),
+        (GizmoType::Rotate, "Rotate", EditorAction::RotateGizmo),
+        (GizmoType::Scale, "Scale", EditorAction::ScaleGizmo),
+    ] {
+        let command = PaletteCommand::new("Gizmo", name, move |world| {
+            world.resource_mut::<NewGizmoType>().0 = gizmo;
+        });
+        entries.push(with_keybinding(command, action));
+    }
+
+    entries.extend(palette.commands.iter().cloned());
+
+    let registry = EVENT_REGISTRY.lock().unwrap();
+    for event_info in registry.iter() {
+        let struct_name = event_info.struct_name;
+        for event_name in event_info.event_names.iter().copied() {
+            entries.push(PaletteCommand::new(
+                clean_name(struct_name),
+                clean_name(event_name),
+                move |_| {
+                    EVENT_REQUEST_QUEUE.lock().unwrap().push(EventRequest {
+                        struct_name: struct_name.to_string(),
+                        event_name: event_name.to_string(),
+                    });
+                },
+            ));
+        }
+    }
+
+    entries
+}
+
+/// Entries matching the query, best first
+pub fn filter_entries(entries: Vec<PaletteCommand>, query: &str) -> Vec<PaletteCommand> {
+    let mut scored: Vec<(i32, PaletteCommand)> = entries
+        .into_iter()
+        .filter_map(|entry| fuzzy_score(query, &entry.title()).map(|score| (score, entry)))
+        .collect();
+    // Stable, so equal scores keep registration order
+    scored.sort_by_key(|(score, _)| Reverse(*score));
+    scored.into_iter().map(|(_, entry)| entry).collect()
+}
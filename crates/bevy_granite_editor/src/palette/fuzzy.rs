@@ -0,0 +1,47 @@
+/// Every query character has to appear in the text in order, None if they don't
+/// Matches at the start of words and runs of consecutive characters score higher
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let text: Vec<char> = text.chars().collect();
+    let mut score = 0;
+    let mut query_index = 0;
+    let mut last_match: Option<usize> = None;
+    for (index, ch) in text.iter().enumerate() {
+        if query_index == query.len() {
+            break;
+        }
+        if ch.to_lowercase().next() != Some(query[query_index]) {
+            continue;
+        }
+
+        score += 1;
+        let word_start = index == 0 || {
+            let previous = text[index - 1];
+            !previous.is_alphanumeric() || (previous.is_lowercase() && ch.is_uppercase())
+        };
+        if word_start {
+            score += 8;
+        }
+        match last_match {
+            Some(last) if last + 1 == index => score += 5,
+            Some(last) => score -= ((index - last - 1) as i32).min(3),
+            None => score -= (index as i32).min(3),
+        }
+        last_match = Some(index);
+        query_index += 1;
+    }
+
+    if query_index < query.len() {
+        return None;
+    }
+    // Shorter titles win ties
+    Some(score * 4 - text.len() as i32 / 8)
+}
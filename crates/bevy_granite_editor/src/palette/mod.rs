@@ -0,0 +1,9 @@
+pub mod commands;
+pub mod fuzzy;
+pub mod plugin;
+pub mod registry;
+
+pub use commands::{builtin_commands, filter_entries, palette_entries};
+pub use fuzzy::fuzzy_score;
+pub use plugin::PalettePlugin;
+pub use registry::{CommandPalette, PaletteCallback, PaletteCommand, RegisterPaletteCommand};
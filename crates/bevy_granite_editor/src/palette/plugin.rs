@@ -0,0 +1,20 @@
+use super::{builtin_commands, CommandPalette};
+use bevy::app::{App, Plugin};
+
+pub struct PalettePlugin;
+impl Plugin for PalettePlugin {
+    fn build(&self, app: &mut App) {
+        //
+        // Resources
+        // Extensions may have registered commands before this plugin was added
+        //
+        let mut palette = app
+            .world_mut()
+            .remove_resource::<CommandPalette>()
+            .unwrap_or_default();
+        let mut commands = builtin_commands();
+        commands.append(&mut palette.commands);
+        palette.commands = commands;
+        app.insert_resource(palette);
+    }
+}
@@ -0,0 +1,80 @@
+use bevy::{
+    app::App,
+    ecs::{resource::Resource, world::World},
+};
+use std::sync::Arc;
+
+/// What a palette command does once chosen, it gets full world access
+pub type PaletteCallback = Arc<dyn Fn(&mut World) + Send + Sync>;
+
+#[derive(Clone)]
+pub struct PaletteCommand {
+    pub category: String,
+    pub name: String,
+    /// Shown on the right of the row, like "Ctrl + S"
+    pub keybinding: Option<String>,
+    pub run: PaletteCallback,
+}
+
+impl PaletteCommand {
+    pub fn new(
+        category: impl Into<String>,
+        name: impl Into<String>,
+        run: impl Fn(&mut World) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            category: category.into(),
+            name: name.into(),
+            keybinding: None,
+            run: Arc::new(run),
+        }
+    }
+
+    pub fn with_keybinding(mut self, keybinding: impl Into<String>) -> Self {
+        self.keybinding = Some(keybinding.into());
+        self
+    }
+
+    /// Text the search matches against
+    pub fn title(&self) -> String {
+        format!("{}: {}", self.category, self.name)
+    }
+}
+
+/// Commands registered by the editor and by extensions, plus the palette's search state
+/// Editor actions and #[ui_callable_events] are added when the palette opens, so they are always current
+#[derive(Resource, Default)]
+pub struct CommandPalette {
+    pub commands: Vec<PaletteCommand>,
+    pub query: String,
+    pub selected: usize,
+    /// Chosen command, run by the popup system once the ui is done
+    pub pending: Option<PaletteCallback>,
+}
+
+impl CommandPalette {
+    pub fn register(&mut self, command: PaletteCommand) {
+        self.commands.push(command);
+    }
+
+    /// Clears the search so the next open starts fresh
+    pub fn reset(&mut self) {
+        self.query.clear();
+        self.selected = 0;
+    }
+}
+
+/// Lets editor extensions add their own commands to the palette
+/// Works before or after the editor plugin is added
+pub trait RegisterPaletteCommand {
+    fn register_palette_command(&mut self, command: PaletteCommand) -> &mut Self;
+}
+
+impl RegisterPaletteCommand for App {
+    fn register_palette_command(&mut self, command: PaletteCommand) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(CommandPalette::default)
+            .register(command);
+        self
+    }
+}
@@ -0,0 +1,15 @@
+use bevy::ecs::event::Event;
+
+/// Drive play mode from the editor, requests that don't fit the current mode are ignored
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub enum RequestPlayEvent {
+    /// Snapshot the scene and run the game, resumes when paused
+    Play,
+    /// Put the scene back to how it was when Play was pressed
+    Stop,
+    /// Play when editing, Stop when playing
+    TogglePlay,
+    TogglePause,
+    /// Advance a single frame, pauses first if needed
+    Step,
+}
@@ -0,0 +1,13 @@
+pub mod events;
+pub mod plugin;
+pub mod snapshot;
+pub mod state;
+pub mod system;
+pub mod ui;
+
+pub use events::RequestPlayEvent;
+pub use plugin::PlayPlugin;
+pub use snapshot::PlaySnapshot;
+pub use state::{is_playing, PlayMode, PlayState};
+pub use system::{play_request_system, play_step_system};
+pub use ui::play_bar_ui_system;
@@ -0,0 +1,35 @@
+use super::{
+    is_playing, play_bar_ui_system, play_request_system, play_step_system, PlayState,
+    RequestPlayEvent,
+};
+use bevy::{
+    app::{App, First, Plugin, Update},
+    ecs::schedule::IntoScheduleConfigs,
+    time::TimeSystem,
+};
+use bevy_egui::EguiPrimaryContextPass;
+
+pub struct PlayPlugin;
+impl Plugin for PlayPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            //
+            // Events
+            //
+            .add_event::<RequestPlayEvent>()
+            //
+            // Resources
+            //
+            .insert_resource(PlayState::default())
+            //
+            // Schedule system
+            // Requests are handled whether the editor is open or not, it's closed while playing
+            //
+            .add_systems(First, play_step_system.before(TimeSystem))
+            .add_systems(Update, play_request_system)
+            .add_systems(
+                EguiPrimaryContextPass,
+                play_bar_ui_system.run_if(is_playing),
+            );
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{
+    editor_state::EditorState,
+    history::{apply_changes, EntityChange, EntitySnapshot},
+    viewport::{CameraSyncState, ViewportCameraState},
+};
+use bevy::{
+    ecs::{
+        entity::Entity,
+        query::{Has, With},
+        world::World,
+    },
+    prelude::Transform,
+};
+use bevy_granite_core::{IdentityData, SpawnSource, UICamera};
+use bevy_granite_gizmos::{ActiveSelection, EntityEvent, GizmoVisibilityState, Selected};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// The scene and editor as they were when Play was pressed
+#[derive(Clone, Debug)]
+pub struct PlaySnapshot {
+    /// Every entity spawned from a source, stored the same way a save stores it
+    pub entities: Vec<EntitySnapshot>,
+    /// Active selection first
+    pub selection: Vec<Uuid>,
+    pub editor_active: bool,
+    pub ui_camera: Option<Transform>,
+    pub editor_camera: Option<Transform>,
+    pub camera_override: Option<Entity>,
+    pub stored_editor_transform: Option<Transform>,
+    pub ui_camera_has_control: bool,
+    pub ui_camera_old_position: Option<Transform>,
+    pub current_file: Option<String>,
+    pub loaded_sources: HashSet<String>,
+}
+
+impl PlaySnapshot {
+    pub fn capture(world: &mut World) -> Self {
+        let mut query = world.query_filtered::<Entity, (With<IdentityData>, With<SpawnSource>)>();
+        let scene: Vec<Entity> = query.iter(world).collect();
+        let entities: Vec<EntitySnapshot> = scene
+            .into_iter()
+            .filter_map(|entity| EntitySnapshot::capture(world, entity))
+            .collect();
+
+        let mut query =
+            world.query_filtered::<(&IdentityData, Has<ActiveSelection>), With<Selected>>();
+        let mut selected: Vec<(Uuid, bool)> = query
+            .iter(world)
+            .map(|(identity, active)| (identity.uuid, active))
+            .collect();
+        selected.sort_by_key(|(_, active)| !*active);
+
+        let mut query = world.query_filtered::<&Transform, With<UICamera>>();
+        let ui_camera = query.single(world).ok().copied();
+
+        let viewport = world.resource::<ViewportCameraState>();
+        let (editor_camera_entity, camera_override, stored_editor_transform) = (
+            viewport.editor_camera,
+            viewport.active_override,
+            viewport.stored_editor_transform,
+        );
+        let editor_camera = editor_camera_entity
+            .and_then(|entity| world.get::<Transform>(entity))
+            .copied();
+        let sync = world.resource::<CameraSyncState>();
+        let (ui_camera_has_control, ui_camera_old_position) =
+            (sync.ui_camera_has_control, sync.ui_camera_old_position);
+        let editor_state = world.resource::<EditorState>();
+
+        Self {
+            entities,
+            selection: selected.into_iter().map(|(uuid, _)| uuid).collect(),
+            editor_active: editor_state.active,
+            ui_camera,
+            editor_camera,
+            camera_override,
+            stored_editor_transform,
+            ui_camera_has_control,
+            ui_camera_old_position,
+            current_file: editor_state.current_file.clone(),
+            loaded_sources: editor_state.loaded_sources.clone(),
+        }
+    }
+
+    /// Despawns scene entities made during play and puts every changed or despawned one back
+    /// Entities the game didn't touch are left alone so their meshes aren't rebuilt
+    pub fn restore(self, world: &mut World) {
+        let mut query = world.query_filtered::<(Entity, &IdentityData), With<SpawnSource>>();
+        let current: HashMap<Uuid, Entity> = query
+            .iter(world)
+            .map(|(entity, identity)| (identity.uuid, entity))
+            .collect();
+        let kept: HashSet<Uuid> = self.entities.iter().map(EntitySnapshot::uuid).collect();
+
+        let mut changes: Vec<EntityChange> = current
+            .keys()
+            .filter(|uuid| !kept.contains(*uuid))
+            .map(|uuid| EntityChange {
+                uuid: *uuid,
+                before: None,
+                after: None,
+            })
+            .collect();
+        let despawned = changes.len();
+        for snapshot in self.entities {
+            let unchanged = current
+                .get(&snapshot.uuid())
+                .and_then(|entity| EntitySnapshot::capture(world, *entity))
+                .is_some_and(|now| now == snapshot);
+            if !unchanged {
+                changes.push(EntityChange {
+                    uuid: snapshot.uuid(),
+                    before: None,
+                    after: Some(snapshot),
+                });
+            }
+        }
+        if !changes.is_empty() {
+            apply_changes(world, &changes, true);
+        }
+        log!(
+            LogType::Editor,
+            LogLevel::OK,
+            LogCategory::System,
+            "Stopped playing, restored {} entities and removed {} spawned during play",
+            changes.len() - despawned,
+            despawned
+        );
+
+        {
+            let mut editor_state = world.resource_mut::<EditorState>();
+            editor_state.active = self.editor_active;
+            editor_state.current_file = self.current_file;
+            editor_state.loaded_sources = self.loaded_sources;
+        }
+        world.resource_mut::<GizmoVisibilityState>().active = self.editor_active;
+
+        restore_cameras(
+            world,
+            self.ui_camera,
+            self.editor_camera,
+            self.camera_override,
+            self.stored_editor_transform,
+        );
+        {
+            let mut sync = world.resource_mut::<CameraSyncState>();
+            sync.ui_camera_has_control = self.ui_camera_has_control;
+            sync.ui_camera_old_position = self.ui_camera_old_position;
+        }
+
+        // Flushed so the old selection is gone before selecting, selecting an active entity toggles it
+        world.trigger(EntityEvent::DeselectAll);
+        world.flush();
+        let mut query = world.query::<(Entity, &IdentityData)>();
+        let entities: HashMap<Uuid, Entity> = query
+            .iter(world)
+            .map(|(entity, identity)| (identity.uuid, entity))
+            .collect();
+        let range: Vec<Entity> = self
+            .selection
+            .iter()
+            .filter_map(|uuid| entities.get(uuid).copied())
+            .collect();
+        if !range.is_empty() {
+            world.trigger(EntityEvent::SelectRange {
+                range,
+                additive: false,
+            });
+        }
+    }
+}
+
+/// A camera override only comes back if that camera survived play, otherwise the editor camera takes over
+fn restore_cameras(
+    world: &mut World,
+    ui_camera: Option<Transform>,
+    editor_camera: Option<Transform>,
+    camera_override: Option<Entity>,
+    stored_editor_transform: Option<Transform>,
+) {
+    let camera_override = camera_override.filter(|camera| world.get_entity(*camera).is_ok());
+    let (ui_camera, editor_camera, stored_editor_transform) = match camera_override {
+        Some(_) => (ui_camera, editor_camera, stored_editor_transform),
+        None => {
+            let editor = stored_editor_transform.or(editor_camera);
+            (stored_editor_transform.or(ui_camera), editor, None)
+        }
+    };
+
+    if let Some(transform) = ui_camera {
+        let mut query = world.query_filtered::<&mut Transform, With<UICamera>>();
+        if let Ok(mut camera) = query.single_mut(world) {
+            *camera = transform;
+        }
+    }
+    let editor_entity = world.resource::<ViewportCameraState>().editor_camera;
+    if let (Some(entity), Some(transform)) = (editor_entity, editor_camera) {
+        if let Some(mut camera) = world.get_mut::<Transform>(entity) {
+            *camera = transform;
+        }
+    }
+
+    let mut viewport = world.resource_mut::<ViewportCameraState>();
+    viewport.active_override = camera_override;
+    viewport.stored_editor_transform = stored_editor_transform;
+}
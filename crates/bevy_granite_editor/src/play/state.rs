@@ -0,0 +1,44 @@
+use super::PlaySnapshot;
+use bevy::ecs::{resource::Resource, system::Res};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlayMode {
+    #[default]
+    Editing,
+    Playing,
+    /// Game time is frozen, Step advances it one frame at a time
+    Paused,
+}
+
+#[derive(Resource, Default)]
+pub struct PlayState {
+    pub mode: PlayMode,
+    /// The scene and editor as they were when Play was pressed, put back on Stop
+    pub snapshot: Option<PlaySnapshot>,
+    /// A single frame was asked for while paused
+    pub step_requested: bool,
+    /// Time is running for the requested frame and pauses again on the next one
+    pub stepping: bool,
+}
+
+impl PlayState {
+    pub fn is_playing(&self) -> bool {
+        self.mode != PlayMode::Editing
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.mode == PlayMode::Paused
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self.mode {
+            PlayMode::Editing => "Editing",
+            PlayMode::Playing => "Playing",
+            PlayMode::Paused => "Paused",
+        }
+    }
+}
+
+pub fn is_playing(play_state: Res<PlayState>) -> bool {
+    play_state.is_playing()
+}
@@ -0,0 +1,114 @@
+use super::{PlayMode, PlaySnapshot, PlayState, RequestPlayEvent};
+use crate::editor_state::EditorState;
+use bevy::{
+    ecs::{
+        event::EventReader,
+        system::{Commands, ResMut},
+        world::World,
+    },
+    time::{Time, Virtual},
+};
+use bevy_granite_gizmos::{EntityEvent, GizmoVisibilityState};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+
+pub fn play_request_system(
+    mut commands: Commands,
+    mut requests: EventReader<RequestPlayEvent>,
+    mut play_state: ResMut<PlayState>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    for request in requests.read() {
+        let request = match (*request, play_state.mode) {
+            (RequestPlayEvent::TogglePlay, PlayMode::Editing) => RequestPlayEvent::Play,
+            (RequestPlayEvent::TogglePlay, _) => RequestPlayEvent::Stop,
+            (request, _) => request,
+        };
+        match (request, play_state.mode) {
+            (RequestPlayEvent::Play, PlayMode::Editing) => {
+                play_state.mode = PlayMode::Playing;
+                time.unpause();
+                commands.queue(start_play);
+            }
+            (RequestPlayEvent::Play | RequestPlayEvent::TogglePause, PlayMode::Paused) => {
+                play_state.mode = PlayMode::Playing;
+                play_state.step_requested = false;
+                time.unpause();
+            }
+            (RequestPlayEvent::TogglePause, PlayMode::Playing) => {
+                play_state.mode = PlayMode::Paused;
+                time.pause();
+            }
+            (RequestPlayEvent::Step, PlayMode::Playing | PlayMode::Paused) => {
+                play_state.mode = PlayMode::Paused;
+                play_state.step_requested = true;
+                time.pause();
+            }
+            (RequestPlayEvent::Stop, PlayMode::Playing | PlayMode::Paused) => {
+                play_state.mode = PlayMode::Editing;
+                play_state.step_requested = false;
+                play_state.stepping = false;
+                time.unpause();
+                commands.queue(stop_play);
+            }
+            (request, mode) => {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Warning,
+                    LogCategory::System,
+                    "Ignoring play request {:?} while {:?}",
+                    request,
+                    mode
+                );
+            }
+        }
+    }
+}
+
+/// Lets virtual time run for exactly one frame while paused
+/// Runs before time updates, so the frame it unpauses is the one that advances
+pub fn play_step_system(mut play_state: ResMut<PlayState>, mut time: ResMut<Time<Virtual>>) {
+    if play_state.stepping {
+        play_state.stepping = false;
+        if play_state.is_paused() {
+            time.pause();
+        }
+    }
+    if play_state.step_requested && play_state.is_paused() {
+        play_state.step_requested = false;
+        play_state.stepping = true;
+        time.unpause();
+    }
+}
+
+fn start_play(world: &mut World) {
+    let snapshot = PlaySnapshot::capture(world);
+    log!(
+        LogType::Editor,
+        LogLevel::OK,
+        LogCategory::System,
+        "Playing, snapshot of {} entities taken",
+        snapshot.entities.len()
+    );
+    world.resource_mut::<PlayState>().snapshot = Some(snapshot);
+
+    // Same as toggling the editor off, the game runs without editor input
+    world.trigger(EntityEvent::DeselectAll);
+    world.resource_mut::<EditorState>().active = false;
+    world.resource_mut::<GizmoVisibilityState>().active = false;
+}
+
+fn stop_play(world: &mut World) {
+    let Some(snapshot) = world.resource_mut::<PlayState>().snapshot.take() else {
+        log!(
+            LogType::Editor,
+            LogLevel::Error,
+            LogCategory::System,
+            "Stopped playing without a snapshot, nothing to restore"
+        );
+        return;
+    };
+    snapshot.restore(world);
+}
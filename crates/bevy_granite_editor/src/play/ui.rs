@@ -0,0 +1,73 @@
+use super::{PlayState, RequestPlayEvent};
+use crate::{editor_state::EditorState, input::EditorAction, UI_CONFIG};
+use bevy::{
+    ecs::{event::EventWriter, system::Res},
+    time::{Time, Virtual},
+};
+use bevy_egui::{egui, EguiContexts};
+
+/// Small bar over the game while playing, shown whether the editor is open or not
+pub fn play_bar_ui_system(
+    mut contexts: EguiContexts,
+    play_state: Res<PlayState>,
+    editor_state: Res<EditorState>,
+    mut requests: EventWriter<RequestPlayEvent>,
+    time: Res<Time<Virtual>>,
+) {
+    let keybindings = &editor_state.config.keybindings;
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    // Clear of the editor's top bar when it is open
+    let offset = if editor_state.active { 48.0 } else { 8.0 };
+
+    egui::Area::new(egui::Id::new("play_bar"))
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, offset))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = UI_CONFIG.spacing;
+                    ui.strong(play_state.status());
+                    ui.separator();
+
+                    let pause_label = if play_state.is_paused() {
+                        "Resume"
+                    } else {
+                        "Pause"
+                    };
+                    if ui
+                        .button(format!(
+                            "{} ({})",
+                            pause_label,
+                            keybindings.display(EditorAction::PauseResume)
+                        ))
+                        .clicked()
+                    {
+                        requests.write(RequestPlayEvent::TogglePause);
+                    }
+                    if ui
+                        .button(format!(
+                            "Step ({})",
+                            keybindings.display(EditorAction::StepFrame)
+                        ))
+                        .clicked()
+                    {
+                        requests.write(RequestPlayEvent::Step);
+                    }
+                    if ui
+                        .button(format!(
+                            "Stop ({})",
+                            keybindings.display(EditorAction::PlayStop)
+                        ))
+                        .clicked()
+                    {
+                        requests.write(RequestPlayEvent::Stop);
+                    }
+
+                    ui.separator();
+                    ui.weak(format!("{:.2}s", time.elapsed_secs()));
+                });
+            });
+        });
+}
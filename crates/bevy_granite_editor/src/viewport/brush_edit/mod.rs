@@ -0,0 +1,48 @@
+pub mod system;
+pub mod ui;
+
+use crate::input::EditorAction;
+use bevy::{
+    ecs::{component::Component, entity::Entity, resource::Resource},
+    math::Vec3,
+};
+use bevy_granite_core::{ConvexBrushElement, ConvexBrushElementKind};
+
+/// Convex brush element editing. While a brush is set, its vertices, edges or faces get a handle each
+/// Selecting a handle puts the regular transform gizmo on it, dragging the handle moves that part of the brush
+#[derive(Resource, Default)]
+pub struct BrushEditState {
+    pub brush: Option<Entity>,
+    pub kind: ConvexBrushElementKind,
+    pub rebuild_handles: bool,
+}
+
+impl BrushEditState {
+    pub fn is_editing(&self) -> bool {
+        self.brush.is_some()
+    }
+}
+
+/// Shortcut that switches brush edit mode to this kind of element
+pub fn element_kind_action(kind: ConvexBrushElementKind) -> EditorAction {
+    match kind {
+        ConvexBrushElementKind::Vertex => EditorAction::BrushVertexMode,
+        ConvexBrushElementKind::Edge => EditorAction::BrushEdgeMode,
+        ConvexBrushElementKind::Face => EditorAction::BrushFaceMode,
+    }
+}
+
+/// Pickable marker for one element of the brush being edited
+/// last_position is the world position we last placed it at, any difference is a user drag
+#[derive(Component)]
+pub struct BrushElementHandle {
+    pub brush: Entity,
+    pub element: ConvexBrushElement,
+    pub last_position: Vec3,
+}
+
+pub use system::{
+    brush_edit_drag_system, brush_edit_draw_system, brush_edit_handles_system,
+    brush_edit_toggle_system,
+};
+pub use ui::brush_edit_ui;
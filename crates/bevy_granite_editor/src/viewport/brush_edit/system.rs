@@ -0,0 +1,322 @@
+use super::{element_kind_action, BrushEditState, BrushElementHandle};
+use crate::{editor_state::EditorState, input::EditorAction, viewport::SelectionRenderer};
+use bevy::{
+    asset::Assets,
+    color::Color,
+    ecs::{
+        entity::Entity,
+        query::With,
+        system::{Commands, Query, Res, ResMut, SystemParam},
+    },
+    gizmos::gizmos::Gizmos,
+    input::{keyboard::KeyCode, ButtonInput},
+    math::{primitives::Sphere, Isometry3d},
+    pbr::{MeshMaterial3d, NotShadowCaster, NotShadowReceiver, StandardMaterial},
+    prelude::Name,
+    render::{
+        mesh::{Mesh, Mesh3d},
+        view::RenderLayers,
+    },
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_granite_core::{
+    entities::{editable::RequestEntityUpdateFromClass, GraniteType},
+    ConvexBrushElementKind, GraniteTypes, IdentityData, TreeHiddenEntity, UserInput,
+};
+use bevy_granite_gizmos::{selection::events::EntityEvent, ActiveSelection};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+use std::collections::HashSet;
+
+const HANDLE_RADIUS: f32 = 0.05;
+// Smaller moves than this are float noise from the gizmo, not a drag
+const DRAG_EPSILON: f32 = 1e-5;
+
+/// Entities brush edit mode follows, the selection, the brush itself and its handles
+#[derive(SystemParam)]
+pub struct BrushEditTargets<'w, 's> {
+    pub active_query: Query<'w, 's, Entity, With<ActiveSelection>>,
+    pub brush_query: Query<'w, 's, &'static IdentityData>,
+    pub handle_query: Query<'w, 's, &'static BrushElementHandle>,
+}
+
+/// Tab (or its rebound chord) enters/leaves element editing for the selected convex brush
+/// 1/2/3 (or their rebound chords) pick vertex/edge/face
+pub fn brush_edit_toggle_system(
+    mut commands: Commands,
+    input: Res<UserInput>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    editor_state: Res<EditorState>,
+    mut state: ResMut<BrushEditState>,
+    targets: BrushEditTargets,
+) {
+    let BrushEditTargets {
+        active_query,
+        brush_query,
+        handle_query,
+    } = targets;
+    // Leave edit mode if the brush is gone or something unrelated got selected
+    if let Some(brush) = state.brush {
+        let brush_exists = matches!(
+            brush_query.get(brush).map(|identity| &identity.class),
+            Ok(GraniteTypes::ConvexBrush(_))
+        );
+        let selected_elsewhere = active_query.single().is_ok_and(|active| {
+            active != brush
+                && handle_query
+                    .get(active)
+                    .map_or(true, |handle| handle.brush != brush)
+        });
+        if !brush_exists || selected_elsewhere {
+            log!(
+                LogType::Editor,
+                LogLevel::Info,
+                LogCategory::Entity,
+                "Left convex brush edit mode"
+            );
+            state.brush = None;
+        }
+    }
+
+    if input.mouse_over_egui {
+        return;
+    }
+
+    if editor_state
+        .config
+        .keybindings
+        .just_pressed(EditorAction::BrushEdit, &keyboard)
+    {
+        if let Some(brush) = state.brush.take() {
+            log!(
+                LogType::Editor,
+                LogLevel::Info,
+                LogCategory::Input,
+                "(shortcut) Leaving convex brush edit mode"
+            );
+            commands.trigger(EntityEvent::Select {
+                target: brush,
+                additive: false,
+            });
+        } else if let Ok(active) = active_query.single() {
+            if let Ok(IdentityData {
+                class: GraniteTypes::ConvexBrush(_),
+                ..
+            }) = brush_query.get(active)
+            {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Info,
+                    LogCategory::Input,
+                    "(shortcut) Editing convex brush {} elements",
+                    state.kind.name()
+                );
+                state.brush = Some(active);
+                state.rebuild_handles = true;
+            }
+        }
+    }
+
+    if !state.is_editing() {
+        return;
+    }
+    for kind in ConvexBrushElementKind::all() {
+        let pressed = editor_state
+            .config
+            .keybindings
+            .just_pressed(element_kind_action(kind), &keyboard);
+        if pressed && state.kind != kind {
+            log!(
+                LogType::Editor,
+                LogLevel::Info,
+                LogCategory::Input,
+                "(shortcut) Convex brush edit mode: {}",
+                kind.name()
+            );
+            state.kind = kind;
+            state.rebuild_handles = true;
+        }
+    }
+}
+
+/// Keeps one handle per element of the brush being edited
+/// Handles are rebuilt when the mode changes or the brush topology no longer matches them
+pub fn brush_edit_handles_system(
+    mut commands: Commands,
+    mut state: ResMut<BrushEditState>,
+    brush_query: Query<(&IdentityData, &GlobalTransform)>,
+    handle_query: Query<(Entity, &BrushElementHandle)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let brush_data = state.brush.and_then(|brush| {
+        brush_query
+            .get(brush)
+            .ok()
+            .and_then(|(identity, transform)| match &identity.class {
+                GraniteTypes::ConvexBrush(data) => Some((brush, data, transform)),
+                _ => None,
+            })
+    });
+
+    let Some((brush, data, brush_transform)) = brush_data else {
+        for (handle, _) in handle_query.iter() {
+            commands.entity(handle).try_despawn();
+        }
+        return;
+    };
+
+    let elements = data.elements(state.kind);
+    let existing: HashSet<_> = handle_query
+        .iter()
+        .filter(|(_, handle)| handle.brush == brush)
+        .map(|(_, handle)| handle.element)
+        .collect();
+    let up_to_date = existing.len() == elements.len()
+        && elements.iter().all(|element| existing.contains(element));
+    if up_to_date && !state.rebuild_handles {
+        return;
+    }
+    state.rebuild_handles = false;
+
+    for (handle, _) in handle_query.iter() {
+        commands.entity(handle).try_despawn();
+    }
+
+    let color = match state.kind {
+        ConvexBrushElementKind::Vertex => Color::srgb(1.0, 1.0, 1.0),
+        ConvexBrushElementKind::Edge => Color::srgb(1.0, 0.85, 0.2),
+        ConvexBrushElementKind::Face => Color::srgb(0.2, 0.85, 1.0),
+    };
+    let mesh = meshes.add(Sphere::new(HANDLE_RADIUS));
+    let material = materials.add(StandardMaterial {
+        base_color: color,
+        unlit: true,
+        ..Default::default()
+    });
+
+    for element in elements {
+        let position = brush_transform.transform_point(data.element_center(element));
+        commands.spawn((
+            BrushElementHandle {
+                brush,
+                element,
+                last_position: position,
+            },
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(position),
+            Name::new(format!("Brush {} Handle", state.kind.name())),
+            TreeHiddenEntity,
+            RenderLayers::from_layers(&[14]), // 14 is our UI/Gizmo layer.
+            NotShadowCaster,
+            NotShadowReceiver,
+        ));
+    }
+}
+
+/// Applies handle drags to the brush class, then snaps every handle back onto its element
+/// Handles also follow the brush if it is moved or edited from the panel
+pub fn brush_edit_drag_system(
+    state: Res<BrushEditState>,
+    mut handle_query: Query<(&mut BrushElementHandle, &mut Transform)>,
+    mut brush_query: Query<(&mut IdentityData, &GlobalTransform)>,
+    mut request_update: RequestEntityUpdateFromClass,
+) {
+    let Some(brush) = state.brush else {
+        return;
+    };
+    let Ok((mut identity, brush_transform)) = brush_query.get_mut(brush) else {
+        return;
+    };
+    let GraniteTypes::ConvexBrush(data) = &identity.class else {
+        return;
+    };
+    let mut data = data.clone();
+    let to_brush_space = brush_transform.affine().inverse();
+
+    let mut changed = false;
+    for (handle, transform) in handle_query.iter() {
+        if handle.brush != brush
+            || transform.translation.distance(handle.last_position) < DRAG_EPSILON
+        {
+            continue;
+        }
+        let delta = to_brush_space.transform_point3(transform.translation)
+            - to_brush_space.transform_point3(handle.last_position);
+        data.translate_element(handle.element, delta);
+        changed = true;
+    }
+
+    if changed {
+        identity.class = GraniteTypes::ConvexBrush(data.clone());
+        identity.class.push_to_entity(brush, &mut request_update);
+    }
+
+    for (mut handle, mut transform) in handle_query.iter_mut() {
+        if handle.brush != brush {
+            continue;
+        }
+        let position = brush_transform.transform_point(data.element_center(handle.element));
+        if transform.translation.distance(position) > DRAG_EPSILON {
+            transform.translation = position;
+        }
+        handle.last_position = transform.translation;
+    }
+}
+
+/// Wireframe of the brush being edited, with the selected element highlighted
+pub fn brush_edit_draw_system(
+    state: Res<BrushEditState>,
+    brush_query: Query<(&IdentityData, &GlobalTransform)>,
+    active_handle: Query<&BrushElementHandle, With<ActiveSelection>>,
+    mut gizmos: Gizmos<SelectionRenderer>,
+) {
+    let Some(brush) = state.brush else {
+        return;
+    };
+    let Ok((identity, brush_transform)) = brush_query.get(brush) else {
+        return;
+    };
+    let GraniteTypes::ConvexBrush(data) = &identity.class else {
+        return;
+    };
+
+    let wire_color = if data.is_convex() {
+        Color::srgb(1.0, 0.6, 0.1)
+    } else {
+        Color::srgb(1.0, 0.2, 0.2)
+    };
+    let world = |index: u32| {
+        brush_transform.transform_point(
+            data.vertices
+                .get(index as usize)
+                .copied()
+                .unwrap_or_default(),
+        )
+    };
+    for (a, b) in data.edges() {
+        gizmos.line(world(a), world(b), wire_color);
+    }
+
+    let Ok(handle) = active_handle.single() else {
+        return;
+    };
+    if handle.brush != brush {
+        return;
+    }
+    let highlight = Color::srgb(1.0, 1.0, 0.0);
+    let indices = data.element_vertex_indices(handle.element);
+    for (i, index) in indices.iter().enumerate() {
+        if indices.len() > 1 {
+            let next = indices[(i + 1) % indices.len()];
+            gizmos.line(world(*index), world(next), highlight);
+        }
+    }
+    gizmos.sphere(
+        Isometry3d::from_translation(
+            brush_transform.transform_point(data.element_center(handle.element)),
+        ),
+        HANDLE_RADIUS * 2.0,
+        highlight,
+    );
+}
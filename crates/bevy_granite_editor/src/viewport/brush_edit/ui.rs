@@ -0,0 +1,53 @@
+use super::{element_kind_action, BrushEditState};
+use crate::{editor_state::EditorState, input::EditorAction};
+use bevy::ecs::system::{Commands, Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+use bevy_granite_core::ConvexBrushElementKind;
+use bevy_granite_gizmos::selection::events::EntityEvent;
+
+/// Small overlay while a convex brush is in element edit mode
+pub fn brush_edit_ui(
+    mut commands: Commands,
+    mut contexts: EguiContexts,
+    editor_state: Res<EditorState>,
+    mut state: ResMut<BrushEditState>,
+) {
+    let Some(brush) = state.brush else {
+        return;
+    };
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let keybindings = &editor_state.config.keybindings;
+
+    egui::Window::new("Brush Edit")
+        .resizable(false)
+        .title_bar(false)
+        .default_pos(egui::pos2(20.0, 300.0))
+        .show(ctx, |ui| {
+            ui.set_max_width(100.);
+            ui.label(egui::RichText::new("Brush Edit").italics());
+            ui.separator();
+            let mut kind = state.kind;
+            for option in ConvexBrushElementKind::all() {
+                ui.radio_value(&mut kind, option, option.name())
+                    .on_hover_text(format!(
+                        "Shortcut: {}",
+                        keybindings.display(element_kind_action(option))
+                    ));
+            }
+            if kind != state.kind {
+                state.kind = kind;
+                state.rebuild_handles = true;
+            }
+            ui.separator();
+            let done_hover = format!("Shortcut: {}", keybindings.display(EditorAction::BrushEdit));
+            if ui.button("Done").on_hover_text(done_hover).clicked() {
+                state.brush = None;
+                commands.trigger(EntityEvent::Select {
+                    target: brush,
+                    additive: false,
+                });
+            }
+        });
+}
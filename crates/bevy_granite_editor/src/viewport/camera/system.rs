@@ -1,23 +1,27 @@
-use bevy::core_pipeline::core_3d::Camera3d;
+use bevy::core_pipeline::{core_3d::Camera3d, Skybox};
 use crate::{
     editor_state::INPUT_CONFIG,
     entities::bounds::get_entity_bounds_world,
     interface::events::{RequestCameraEntityFrame, RequestToggleCameraSync, RequestViewportCameraOverride},
-    viewport::camera::{handle_movement, handle_zoom, rotate_camera_towards, ViewportCameraState},
+    viewport::camera::{
+        handle_movement, handle_zoom, rotate_camera_towards, EditorViewportCamera,
+        ViewportCameraState,
+    },
 };
 use bevy::{
     asset::Assets,
     ecs::entity::Entity,
     input::mouse::{MouseMotion, MouseWheel},
+    pbr::environment_map::EnvironmentMapLight,
     prelude::{
-        EventReader, Local, Query, Res, ResMut, Resource, Time, Transform, Vec2, Vec3, Window,
-        With, Without,
+        Commands, EventReader, Local, Query, Real, Res, ResMut, Resource, Time, Transform, Vec2,
+        Vec3, Window, With, Without,
     },
     render::{camera::{Camera, RenderTarget}, mesh::{Mesh, Mesh3d}},
     transform::components::GlobalTransform,
     window::{CursorGrabMode, PrimaryWindow},
 };
-use bevy_granite_core::{MainCamera, UICamera, UserInput};
+use bevy_granite_core::{GraniteTypes, IdentityData, MainCamera, UICamera, UserInput};
 use bevy_granite_gizmos::{ActiveSelection, DragState, Selected};
 use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
 
@@ -192,6 +196,84 @@ pub fn restore_runtime_camera_state(
     }
 }
 
+type SceneCameraEnvironmentQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static IdentityData,
+        Option<&'static Skybox>,
+        Option<&'static EnvironmentMapLight>,
+    ),
+    (
+        With<Camera3d>,
+        Without<EditorViewportCamera>,
+        Without<UICamera>,
+    ),
+>;
+
+/// Mirror the active scene camera's skybox and environment lighting onto the editor viewport camera
+/// so authored image based lighting can be previewed while flying around
+pub fn preview_camera_environment_system(
+    mut commands: Commands,
+    viewport_camera_state: Res<ViewportCameraState>,
+    scene_camera_query: SceneCameraEnvironmentQuery,
+    editor_camera_query: Query<
+        (Option<&Skybox>, Option<&EnvironmentMapLight>),
+        With<EditorViewportCamera>,
+    >,
+) {
+    let Some(editor_entity) = viewport_camera_state.editor_camera else {
+        return;
+    };
+    let Ok((editor_skybox, editor_environment)) = editor_camera_query.get(editor_entity) else {
+        return;
+    };
+
+    // The Camera component is toggled by the editor, so use the class data to find the scene's camera
+    let (skybox, environment) = scene_camera_query
+        .iter()
+        .find(|(identity, ..)| {
+            matches!(&identity.class, GraniteTypes::Camera3D(camera) if camera.is_active)
+        })
+        .map(|(_, skybox, environment)| (skybox, environment))
+        .unwrap_or((None, None));
+
+    let skybox_matches = match (editor_skybox, skybox) {
+        (None, None) => true,
+        (Some(current), Some(wanted)) => {
+            current.image == wanted.image
+                && current.brightness == wanted.brightness
+                && current.rotation == wanted.rotation
+        }
+        _ => false,
+    };
+    if !skybox_matches {
+        match skybox {
+            Some(skybox) => commands.entity(editor_entity).insert(skybox.clone()),
+            None => commands.entity(editor_entity).remove::<Skybox>(),
+        };
+    }
+
+    let environment_matches = match (editor_environment, environment) {
+        (None, None) => true,
+        (Some(current), Some(wanted)) => {
+            current.diffuse_map == wanted.diffuse_map
+                && current.specular_map == wanted.specular_map
+                && current.intensity == wanted.intensity
+                && current.rotation == wanted.rotation
+        }
+        _ => false,
+    };
+    if !environment_matches {
+        match environment {
+            Some(environment) => commands.entity(editor_entity).insert(environment.clone()),
+            None => commands
+                .entity(editor_entity)
+                .remove::<EnvironmentMapLight>(),
+        };
+    }
+}
+
 pub fn handle_viewport_camera_override_requests(
     mut requests: EventReader<RequestViewportCameraOverride>,
     mut viewport_camera_state: ResMut<ViewportCameraState>,
@@ -431,7 +513,8 @@ pub fn mouse_button_iter(
     mut mouse_wheel_events: EventReader<MouseWheel>,
     mut query: Query<&mut Transform, With<UICamera>>,
     mut input_state: ResMut<InputState>,
-    time: Res<Time>,
+    // Real time so the camera still flies while play mode is paused
+    time: Res<Time<Real>>,
     mut target_pos: ResMut<CameraTarget>,
     user_input: Res<UserInput>,
     movement_speed: Local<f32>,
@@ -7,7 +7,7 @@ use bevy::{
     core_pipeline::core_3d::Camera3d,
     input::mouse::{MouseMotion, MouseWheel},
     prelude::{
-        Camera, Commands, EulerRot, EventReader, Local, Name, Quat, Query, Res, ResMut, Time,
+        Camera, Commands, EulerRot, EventReader, Local, Name, Quat, Query, Real, Res, ResMut, Time,
         Transform, Vec2, Vec3, With,
     },
     render::view::RenderLayers,
@@ -92,7 +92,7 @@ pub fn handle_movement(
     mouse_motion_events: &mut EventReader<MouseMotion>,
     mouse_wheel_events: &mut EventReader<MouseWheel>,
     _target_pos: &mut ResMut<CameraTarget>,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mut movement_speed: Local<f32>,
 ) {
     let delta_time = time.delta_secs();
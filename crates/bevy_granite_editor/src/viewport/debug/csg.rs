@@ -0,0 +1,47 @@
+use super::DebugRenderer;
+use crate::editor_state::EditorState;
+use bevy::{
+    color::Color,
+    ecs::system::{Query, Res},
+    gizmos::gizmos::Gizmos,
+    transform::components::GlobalTransform,
+};
+use bevy_granite_core::{entities::csg::brush_csg_solid, CsgOperand, CsgOperation, IdentityData};
+use std::collections::BTreeSet;
+
+/// CSG operands are hidden, so they are always drawn as wireframes while the editor is open
+/// Not tied to the debug visualizer toggle, otherwise cutters would be impossible to find in the viewport
+pub fn show_csg_operand_system(
+    query: Query<(&CsgOperand, &IdentityData, &GlobalTransform)>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    editor_state: Res<EditorState>,
+) {
+    if !editor_state.active {
+        return;
+    }
+    for (operand, identity, global_transform) in query.iter() {
+        let Some((vertices, faces)) = brush_csg_solid(&identity.class) else {
+            continue;
+        };
+        let color = match operand.operation {
+            CsgOperation::Union => Color::srgb(0.3, 0.9, 0.3),
+            CsgOperation::Subtract => Color::srgb(1.0, 0.3, 0.3),
+            CsgOperation::Intersect => Color::srgb(0.3, 0.6, 1.0),
+        };
+
+        let mut edges = BTreeSet::new();
+        for face in &faces {
+            for (i, a) in face.iter().enumerate() {
+                let (a, b) = (*a, face[(i + 1) % face.len()]);
+                edges.insert((a.min(b), a.max(b)));
+            }
+        }
+        for (a, b) in edges {
+            gizmos.line(
+                global_transform.transform_point(vertices[a as usize]),
+                global_transform.transform_point(vertices[b as usize]),
+                color,
+            );
+        }
+    }
+}
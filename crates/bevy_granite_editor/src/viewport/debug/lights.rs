@@ -1,15 +1,19 @@
 use super::DebugRenderer;
 use crate::editor_state::EditorState;
 use bevy::{
-    color::Color,
+    color::{Alpha, Color},
     ecs::{entity::Entity, system::Query},
     gizmos::gizmos::Gizmos,
-    pbr::{DirectionalLight, PointLight},
+    math::Isometry3d,
+    pbr::{DirectionalLight, PointLight, SpotLight},
     prelude::{Res, With},
     transform::components::GlobalTransform,
 };
 use bevy_granite_gizmos::Selected;
 
+/// Cone angles are clamped to this when drawn, tan() blows up as the angle nears 90°
+const MAX_CONE_ANGLE: f32 = 89.0_f32.to_radians();
+
 pub fn show_directional_light_forward_system(
     query: Query<(Entity, &GlobalTransform, &DirectionalLight)>,
     active_query: Query<Entity, With<Selected>>,
@@ -70,3 +74,51 @@ pub fn show_point_light_range_system(
         gizmos.sphere(pos, range, color);
     }
 }
+
+pub fn show_spot_light_cone_system(
+    query: Query<(Entity, &GlobalTransform, &SpotLight)>,
+    active_query: Query<Entity, With<Selected>>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    editor_state: Res<EditorState>,
+) {
+    if !editor_state.active {
+        return;
+    }
+    let config = editor_state.config.viewport.visualizers;
+    if !config.debug_enabled {
+        return;
+    }
+    for (entity, global_transform, spot_light) in query.iter() {
+        if config.debug_selected_only {
+            match active_query.single() {
+                Ok(selected_entity) if selected_entity != entity => continue,
+                Err(_) => return,
+                _ => {}
+            }
+        }
+        let color = Color::srgb_from_array(config.debug_color);
+        let start = global_transform.translation();
+        let rotation = global_transform.to_scale_rotation_translation().1;
+        let forward = global_transform.forward();
+        let right = global_transform.right();
+        let up = global_transform.up();
+
+        // Cap the cone at range, circle faces along forward since its drawn in the local XY plane
+        let range = spot_light.range;
+        let end = start + forward * range;
+        let outer_radius = range * spot_light.outer_angle.min(MAX_CONE_ANGLE).tan();
+        gizmos.circle(Isometry3d::new(end, rotation), outer_radius, color);
+        for edge in [right, -right, up, -up] {
+            gizmos.line(start, end + edge * outer_radius, color);
+        }
+
+        if spot_light.inner_angle > 0.0 {
+            let inner_radius = range * spot_light.inner_angle.min(MAX_CONE_ANGLE).tan();
+            gizmos.circle(
+                Isometry3d::new(end, rotation),
+                inner_radius,
+                color.with_alpha(0.4),
+            );
+        }
+    }
+}
@@ -3,6 +3,9 @@ pub mod relationships;
 pub mod lights;
 pub mod cameras;
 pub mod entities;
+pub mod csg;
+pub mod splines;
+pub mod volumes;
 
 use bevy::{
     gizmos::config::GizmoConfigGroup,
@@ -23,3 +26,6 @@ pub use relationships::*;
 pub use lights::*;
 pub use cameras::*;
 pub use entities::*;
+pub use csg::*;
+pub use splines::*;
+pub use volumes::*;
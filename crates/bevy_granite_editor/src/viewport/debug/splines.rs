@@ -0,0 +1,63 @@
+use super::DebugRenderer;
+use crate::editor_state::EditorState;
+use bevy::{
+    color::Color,
+    ecs::{entity::Entity, system::Query},
+    gizmos::gizmos::Gizmos,
+    math::Isometry3d,
+    prelude::{Res, With},
+    transform::components::GlobalTransform,
+};
+use bevy_granite_core::{GraniteTypes, IdentityData, SplineMode};
+use bevy_granite_gizmos::Selected;
+
+// Line pieces drawn per segment, enough to look smooth on tight curves
+const SPLINE_DRAW_STEPS: usize = 24;
+
+/// Draws the path of every spline, with its control points and Bézier tangents
+pub fn show_spline_system(
+    query: Query<(Entity, &GlobalTransform, &IdentityData)>,
+    active_query: Query<Entity, With<Selected>>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    editor_state: Res<EditorState>,
+) {
+    if !editor_state.active {
+        return;
+    }
+    let config = editor_state.config.viewport.visualizers;
+    if !config.debug_enabled {
+        return;
+    }
+    for (entity, global_transform, identity_data) in query.iter() {
+        let GraniteTypes::Spline(spline) = &identity_data.class else {
+            continue;
+        };
+        if config.debug_selected_only {
+            match active_query.single() {
+                Ok(selected_entity) if selected_entity != entity => continue,
+                Err(_) => return,
+                _ => {}
+            }
+        }
+
+        let color = Color::srgb_from_array(config.debug_color);
+        let path = spline
+            .polyline(SPLINE_DRAW_STEPS)
+            .into_iter()
+            .map(|point| global_transform.transform_point(point));
+        gizmos.linestrip(path, color);
+
+        let tangent_color = Color::srgb(0.2, 0.85, 1.0);
+        for point in &spline.points {
+            let position = global_transform.transform_point(point.position);
+            gizmos.sphere(Isometry3d::from_translation(position), 0.08, color);
+
+            if spline.mode == SplineMode::Bezier {
+                for tangent in [point.in_tangent, point.out_tangent] {
+                    let handle = global_transform.transform_point(point.position + tangent);
+                    gizmos.line(position, handle, tangent_color);
+                }
+            }
+        }
+    }
+}
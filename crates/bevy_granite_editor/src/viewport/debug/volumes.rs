@@ -0,0 +1,106 @@
+use super::DebugRenderer;
+use crate::editor_state::EditorState;
+use bevy::{
+    color::Color,
+    ecs::{entity::Entity, system::Query},
+    gizmos::gizmos::Gizmos,
+    math::Vec3,
+    prelude::{Res, Transform, With},
+    transform::components::GlobalTransform,
+};
+use bevy_granite_core::{GraniteTypes, IdentityData, Volume, VolumeShape};
+use bevy_granite_gizmos::Selected;
+use std::f32::consts::{PI, TAU};
+
+const CIRCLE_STEPS: usize = 32;
+
+/// Draws the region of every volume. Disabled volumes are drawn grey
+pub fn show_volume_system(
+    query: Query<(Entity, &GlobalTransform, &IdentityData)>,
+    active_query: Query<Entity, With<Selected>>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    editor_state: Res<EditorState>,
+) {
+    if !editor_state.active {
+        return;
+    }
+    let config = editor_state.config.viewport.visualizers;
+    if !config.debug_enabled {
+        return;
+    }
+    for (entity, global_transform, identity_data) in query.iter() {
+        let GraniteTypes::Volume(volume) = &identity_data.class else {
+            continue;
+        };
+        if config.debug_selected_only {
+            match active_query.single() {
+                Ok(selected_entity) if selected_entity != entity => continue,
+                Err(_) => return,
+                _ => {}
+            }
+        }
+
+        let color = if volume.enabled {
+            Color::srgb_from_array(config.debug_color)
+        } else {
+            Color::srgb(0.5, 0.5, 0.5)
+        };
+        draw_volume(&mut gizmos, volume, global_transform, color);
+    }
+}
+
+fn draw_volume(
+    gizmos: &mut Gizmos<DebugRenderer>,
+    volume: &Volume,
+    global_transform: &GlobalTransform,
+    color: Color,
+) {
+    // Everything is built in local space then placed with the full transform, so scale shows as it is tested
+    let mut strips = Vec::new();
+    match volume.shape {
+        VolumeShape::Box => {
+            let box_transform = Transform::from_scale(volume.half_extents.abs() * 2.0);
+            gizmos.cuboid(global_transform.mul_transform(box_transform), color);
+        }
+        VolumeShape::Sphere => {
+            for (a, b) in [(Vec3::X, Vec3::Y), (Vec3::Y, Vec3::Z), (Vec3::X, Vec3::Z)] {
+                strips.push(arc(Vec3::ZERO, a, b, volume.radius, 0.0, TAU));
+            }
+        }
+        VolumeShape::Capsule => {
+            let radius = volume.radius;
+            let top = Vec3::Y * volume.half_length.max(0.0);
+            let bottom = -top;
+
+            strips.push(arc(top, Vec3::X, Vec3::Z, radius, 0.0, TAU));
+            strips.push(arc(bottom, Vec3::X, Vec3::Z, radius, 0.0, TAU));
+            for side in [Vec3::X, Vec3::Z] {
+                strips.push(arc(top, side, Vec3::Y, radius, 0.0, PI));
+                strips.push(arc(bottom, side, Vec3::Y, radius, PI, PI));
+            }
+            for side in [Vec3::X, -Vec3::X, Vec3::Z, -Vec3::Z] {
+                strips.push(vec![top + side * radius, bottom + side * radius]);
+            }
+        }
+    }
+
+    for points in strips {
+        gizmos.linestrip(
+            points
+                .into_iter()
+                .map(|point| global_transform.transform_point(point)),
+            color,
+        );
+    }
+}
+
+/// Points of an arc around center in the plane of the two axes, starting at start and sweeping by sweep radians
+fn arc(center: Vec3, a: Vec3, b: Vec3, radius: f32, start: f32, sweep: f32) -> Vec<Vec3> {
+    let steps = ((CIRCLE_STEPS as f32 * sweep / TAU).ceil() as usize).max(1);
+    (0..=steps)
+        .map(|step| {
+            let angle = start + sweep * step as f32 / steps as f32;
+            center + (a * angle.cos() + b * angle.sin()) * radius
+        })
+        .collect()
+}
@@ -1,11 +1,14 @@
+pub mod brush_edit;
 pub mod camera;
 pub mod config;
 pub mod debug;
 pub mod grid;
 pub mod icons;
 pub mod plugin;
+pub mod spline_edit;
 pub mod state;
 
+pub use brush_edit::{BrushEditState, BrushElementHandle};
 pub use camera::{
     add_editor_camera,
     add_ui_camera,
@@ -20,13 +23,15 @@ pub use camera::{
     InputState,
     ViewportCameraState,
 };
+pub use spline_edit::{SplineHandleKind, SplinePointHandle};
 pub use state::ViewportState;
 
 pub use config::VisualizationConfig;
 pub use debug::{
     relationship_line_system, show_active_selection_bounds_system, show_camera_forward_system,
-    show_directional_light_forward_system, show_empty_origin_system, show_point_light_range_system,
-    show_selected_entities_bounds_system, DebugRenderer, SelectionRenderer,
+    show_csg_operand_system, show_directional_light_forward_system, show_empty_origin_system,
+    show_point_light_range_system, show_selected_entities_bounds_system, show_spline_system,
+    show_spot_light_cone_system, show_volume_system, DebugRenderer, SelectionRenderer,
 };
 pub use grid::update_grid_system;
 pub use icons::{
@@ -1,3 +1,7 @@
+use super::brush_edit::{
+    brush_edit_drag_system, brush_edit_draw_system, brush_edit_handles_system,
+    brush_edit_toggle_system, brush_edit_ui, BrushEditState,
+};
 use super::camera::{
     add_editor_camera,
     add_ui_camera,
@@ -6,6 +10,7 @@ use super::camera::{
     enforce_viewport_camera_state,
     handle_viewport_camera_override_requests,
     mouse_button_iter,
+    preview_camera_environment_system,
     restore_runtime_camera_state,
     sync_cameras_system,
     CameraSyncState,
@@ -13,15 +18,16 @@ use super::camera::{
     InputState,
     ViewportCameraState,
 };
+use super::spline_edit::{spline_edit_drag_system, spline_edit_handles_system};
 use crate::{
     setup::is_editor_active,
     viewport::{
         cleanup_icon_entities_system, icons::register_embedded_class_icons,
         relationship_line_system, show_active_selection_bounds_system, show_camera_forward_system,
-        show_directional_light_forward_system, show_empty_origin_system,
-        show_point_light_range_system, show_selected_entities_bounds_system,
-        spawn_icon_entities_system, update_grid_system, update_icon_entities_system, DebugRenderer,
-        SelectionRenderer,
+        show_csg_operand_system, show_directional_light_forward_system, show_empty_origin_system,
+        show_point_light_range_system, show_selected_entities_bounds_system, show_spline_system,
+        show_spot_light_cone_system, show_volume_system, spawn_icon_entities_system,
+        update_grid_system, update_icon_entities_system, DebugRenderer, SelectionRenderer,
     },
 };
 use bevy::{
@@ -35,6 +41,7 @@ use bevy::{
     render::view::RenderLayers,
     transform::TransformSystem,
 };
+use bevy_egui::EguiPrimaryContextPass;
 
 pub struct ViewportPlugin;
 impl Plugin for ViewportPlugin {
@@ -47,6 +54,7 @@ impl Plugin for ViewportPlugin {
             .insert_resource(CameraSyncState::default())
             .insert_resource(InputState::default()) // FIX: Use UserInput
             .insert_resource(ViewportCameraState::default())
+            .insert_resource(BrushEditState::default())
             //
             // Debug gizmo groups/config
             //
@@ -89,6 +97,7 @@ impl Plugin for ViewportPlugin {
                     mouse_button_iter, // FIX: Use UserInput
                     camera_frame_system,
                     camera_sync_toggle_system,
+                    preview_camera_environment_system,
                 )
                     .run_if(is_editor_active),
             )
@@ -102,6 +111,26 @@ impl Plugin for ViewportPlugin {
                 Update,
                 restore_runtime_camera_state.run_if(not(is_editor_active)),
             )
+            .add_systems(
+                Update,
+                (
+                    brush_edit_toggle_system,
+                    brush_edit_handles_system,
+                    brush_edit_drag_system,
+                )
+                    .chain()
+                    .run_if(is_editor_active),
+            )
+            .add_systems(
+                Update,
+                (spline_edit_handles_system, spline_edit_drag_system)
+                    .chain()
+                    .run_if(is_editor_active),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                brush_edit_ui.run_if(is_editor_active),
+            )
             // No run if here because this will hide the gizmos if editor is not active
             .add_systems(Update, update_icon_entities_system)
             .add_systems(
@@ -116,9 +145,14 @@ impl Plugin for ViewportPlugin {
                     show_camera_forward_system,
                     relationship_line_system,
                     show_point_light_range_system,
+                    show_spot_light_cone_system,
                     show_empty_origin_system,
+                    show_csg_operand_system,
+                    show_spline_system,
+                    show_volume_system,
                     show_active_selection_bounds_system,
                     show_selected_entities_bounds_system,
+                    brush_edit_draw_system,
                 )
                     .after(TransformSystem::TransformPropagate)
                     .run_if(is_editor_active),
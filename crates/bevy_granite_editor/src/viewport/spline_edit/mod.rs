@@ -0,0 +1,26 @@
+pub mod system;
+
+use bevy::{
+    ecs::{component::Component, entity::Entity},
+    math::Vec3,
+};
+
+/// Which part of a spline point a handle drags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SplineHandleKind {
+    Position,
+    InTangent,
+    OutTangent,
+}
+
+/// Pickable marker for a control point, or a Bézier tangent, of the selected spline
+/// last_position is the world position we last placed it at, any difference is a user drag
+#[derive(Component)]
+pub struct SplinePointHandle {
+    pub spline: Entity,
+    pub index: usize,
+    pub kind: SplineHandleKind,
+    pub last_position: Vec3,
+}
+
+pub use system::{spline_edit_drag_system, spline_edit_handles_system};
@@ -0,0 +1,192 @@
+use super::{SplineHandleKind, SplinePointHandle};
+use bevy::{
+    asset::Assets,
+    color::Color,
+    ecs::{
+        entity::Entity,
+        query::With,
+        system::{Commands, Query, ResMut},
+    },
+    math::{primitives::Sphere, Vec3},
+    pbr::{MeshMaterial3d, NotShadowCaster, NotShadowReceiver, StandardMaterial},
+    prelude::Name,
+    render::{
+        mesh::{Mesh, Mesh3d},
+        view::RenderLayers,
+    },
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_granite_core::{
+    entities::{editable::RequestEntityUpdateFromClass, GraniteType},
+    GraniteTypes, IdentityData, Spline, SplineMode, TreeHiddenEntity,
+};
+use bevy_granite_gizmos::ActiveSelection;
+use std::collections::HashSet;
+
+const HANDLE_RADIUS: f32 = 0.06;
+// Smaller moves than this are float noise from the gizmo, not a drag
+const DRAG_EPSILON: f32 = 1e-5;
+
+/// Spline space position of a handle, None if its point no longer exists
+fn handle_local_position(spline: &Spline, index: usize, kind: SplineHandleKind) -> Option<Vec3> {
+    let point = spline.points.get(index)?;
+    Some(match kind {
+        SplineHandleKind::Position => point.position,
+        SplineHandleKind::InTangent => point.position + point.in_tangent,
+        SplineHandleKind::OutTangent => point.position + point.out_tangent,
+    })
+}
+
+/// Every handle the spline needs, tangents only matter in Bézier mode
+fn wanted_handles(spline: &Spline) -> Vec<(usize, SplineHandleKind)> {
+    let mut kinds = vec![SplineHandleKind::Position];
+    if spline.mode == SplineMode::Bezier {
+        kinds.extend([SplineHandleKind::InTangent, SplineHandleKind::OutTangent]);
+    }
+    (0..spline.points.len())
+        .flat_map(|index| kinds.iter().map(move |kind| (index, *kind)))
+        .collect()
+}
+
+/// Keeps one handle per control point while a spline, or one of its handles, is the active selection
+pub fn spline_edit_handles_system(
+    mut commands: Commands,
+    active_query: Query<Entity, With<ActiveSelection>>,
+    spline_query: Query<(&IdentityData, &GlobalTransform)>,
+    handle_query: Query<(Entity, &SplinePointHandle)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let spline_data = active_query.single().ok().and_then(|active| {
+        let spline = handle_query
+            .get(active)
+            .map_or(active, |(_, handle)| handle.spline);
+        let (identity, transform) = spline_query.get(spline).ok()?;
+        match &identity.class {
+            GraniteTypes::Spline(data) => Some((spline, data, transform)),
+            _ => None,
+        }
+    });
+
+    let Some((spline, data, spline_transform)) = spline_data else {
+        for (handle, _) in handle_query.iter() {
+            commands.entity(handle).try_despawn();
+        }
+        return;
+    };
+
+    let wanted = wanted_handles(data);
+    let existing: HashSet<_> = handle_query
+        .iter()
+        .filter(|(_, handle)| handle.spline == spline)
+        .map(|(_, handle)| (handle.index, handle.kind))
+        .collect();
+    let up_to_date = existing.len() == wanted.len()
+        && handle_query.iter().count() == wanted.len()
+        && wanted.iter().all(|handle| existing.contains(handle));
+    if up_to_date {
+        return;
+    }
+
+    for (handle, _) in handle_query.iter() {
+        commands.entity(handle).try_despawn();
+    }
+
+    let mesh = meshes.add(Sphere::new(HANDLE_RADIUS));
+    let point_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(1.0, 1.0, 1.0),
+        unlit: true,
+        ..Default::default()
+    });
+    let tangent_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.2, 0.85, 1.0),
+        unlit: true,
+        ..Default::default()
+    });
+
+    for (index, kind) in wanted {
+        let Some(local) = handle_local_position(data, index, kind) else {
+            continue;
+        };
+        let position = spline_transform.transform_point(local);
+        let material = match kind {
+            SplineHandleKind::Position => point_material.clone(),
+            _ => tangent_material.clone(),
+        };
+        commands.spawn((
+            SplinePointHandle {
+                spline,
+                index,
+                kind,
+                last_position: position,
+            },
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material),
+            Transform::from_translation(position),
+            Name::new(format!("Spline Point {} Handle", index)),
+            TreeHiddenEntity,
+            RenderLayers::from_layers(&[14]), // 14 is our UI/Gizmo layer.
+            NotShadowCaster,
+            NotShadowReceiver,
+        ));
+    }
+}
+
+/// Applies handle drags to the spline class, then snaps every handle back onto its point
+/// Handles also follow the spline if it is moved or edited from the panel
+pub fn spline_edit_drag_system(
+    mut handle_query: Query<(&mut SplinePointHandle, &mut Transform)>,
+    mut spline_query: Query<(&mut IdentityData, &GlobalTransform)>,
+    mut request_update: RequestEntityUpdateFromClass,
+) {
+    let Some(spline) = handle_query.iter().next().map(|(handle, _)| handle.spline) else {
+        return;
+    };
+    let Ok((mut identity, spline_transform)) = spline_query.get_mut(spline) else {
+        return;
+    };
+    let GraniteTypes::Spline(data) = &identity.class else {
+        return;
+    };
+    let mut data = data.clone();
+    let to_spline_space = spline_transform.affine().inverse();
+
+    let mut changed = false;
+    for (handle, transform) in handle_query.iter() {
+        if handle.spline != spline
+            || transform.translation.distance(handle.last_position) < DRAG_EPSILON
+        {
+            continue;
+        }
+        let Some(point) = data.points.get_mut(handle.index) else {
+            continue;
+        };
+        let local = to_spline_space.transform_point3(transform.translation);
+        match handle.kind {
+            // Tangents are offsets, so they come along with the point
+            SplineHandleKind::Position => point.position = local,
+            SplineHandleKind::InTangent => point.in_tangent = local - point.position,
+            SplineHandleKind::OutTangent => point.out_tangent = local - point.position,
+        }
+        changed = true;
+    }
+
+    if changed {
+        identity.class = GraniteTypes::Spline(data.clone());
+        identity.class.push_to_entity(spline, &mut request_update);
+    }
+
+    for (mut handle, mut transform) in handle_query.iter_mut() {
+        if handle.spline != spline {
+            continue;
+        }
+        let Some(local) = handle_local_position(&data, handle.index, handle.kind) else {
+            continue;
+        };
+        let position = spline_transform.transform_point(local);
+        if transform.translation.distance(position) > DRAG_EPSILON {
+            transform.translation = position;
+        }
+        handle.last_position = transform.translation;
+    }
+}
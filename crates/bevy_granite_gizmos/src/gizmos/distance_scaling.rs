@@ -63,6 +63,10 @@ pub fn scale_gizmo_by_camera_distance_system(
                 GizmoConfig::Transform {
                     ref mut distance_scale,
                     ..
+                }
+                | GizmoConfig::Scale {
+                    ref mut distance_scale,
+                    ..
                 } => {
                     *distance_scale = final_scale;
                 }
@@ -83,7 +87,7 @@ pub fn scale_gizmo_by_camera_distance_system(
         } else {
             // Transform Gizmo should have higher upper limit on speed
             match **selected_gizmo {
-                GizmoType::Pointer | GizmoType::None => {
+                GizmoType::Pointer | GizmoType::None | GizmoType::Scale => {
                     default_config.speed_scale = 1.0;
                 }
                 GizmoType::Transform => {
@@ -1,7 +1,8 @@
 use super::{
-    despawn_rotate_gizmo, despawn_transform_gizmo, spawn_rotate_gizmo, spawn_transform_gizmo,
-    DespawnGizmoEvent, GizmoType, LastSelectedGizmo, NewGizmoConfig, RotateGizmo,
-    RotateGizmoParent, SpawnGizmoEvent, TransformGizmo, TransformGizmoParent,
+    despawn_rotate_gizmo, despawn_scale_gizmo, despawn_transform_gizmo, spawn_rotate_gizmo,
+    spawn_scale_gizmo, spawn_transform_gizmo, DespawnGizmoEvent, GizmoType, LastSelectedGizmo,
+    NewGizmoConfig, RotateGizmo, RotateGizmoParent, ScaleGizmo, ScaleGizmoParent, SpawnGizmoEvent,
+    TransformGizmoParent,
 };
 use crate::{gizmos::NewGizmoType, selection::ActiveSelection};
 use bevy::prelude::{
@@ -19,11 +20,13 @@ pub fn gizmo_events(
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut transform_query: Query<&GlobalTransform, Without<TransformGizmoParent>>,
     mut rotate_query: Query<&GlobalTransform, Without<RotateGizmoParent>>,
+    mut scale_query: Query<&GlobalTransform, Without<ScaleGizmoParent>>,
     selected_gizmo: Res<NewGizmoType>,
     mut spawn_events: EventReader<SpawnGizmoEvent>,
     mut despawn_events: EventReader<DespawnGizmoEvent>,
-    mut transform_gizmo_query: Query<(Entity, &TransformGizmo, &Children)>,
+    mut transform_gizmo_query: Query<Entity, With<TransformGizmoParent>>,
     mut rotate_gizmo_query: Query<(Entity, &RotateGizmo, &Children)>,
+    mut scale_gizmo_query: Query<(Entity, &ScaleGizmo, &Children)>,
     new_config: Res<NewGizmoConfig>,
 ) {
     for SpawnGizmoEvent(entity) in spawn_events.read() {
@@ -45,6 +48,15 @@ pub fn gizmo_events(
                 &mut meshes,
                 new_config.rotation(),
             );
+        } else if matches!(**selected_gizmo, GizmoType::Scale) {
+            spawn_scale_gizmo(
+                *entity,
+                &mut scale_query,
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                new_config.scale(),
+            );
         }
     }
 
@@ -53,6 +65,8 @@ pub fn gizmo_events(
             despawn_transform_gizmo(&mut commands, &mut transform_gizmo_query);
         } else if matches!(gizmo_type, GizmoType::Rotate) {
             despawn_rotate_gizmo(&mut commands, &mut rotate_gizmo_query);
+        } else if matches!(gizmo_type, GizmoType::Scale) {
+            despawn_scale_gizmo(&mut commands, &mut scale_gizmo_query);
         }
     }
 }
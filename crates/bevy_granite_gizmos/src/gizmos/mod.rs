@@ -1,5 +1,6 @@
 use bevy::{
     ecs::{component::Component, entity::Entity, resource::Resource},
+    math::Quat,
     prelude::{Deref, DerefMut},
     render::view::RenderLayers,
 };
@@ -7,14 +8,19 @@ use bevy::{
 pub mod distance_scaling;
 pub mod events;
 pub mod manager;
+pub mod modal;
+pub mod orientation;
+pub mod pivot;
 pub mod plugin;
 pub mod rotate;
+pub mod scale;
 pub mod transform;
 
 #[derive(Clone, Default, Debug, Copy, PartialEq)]
 pub enum GizmoType {
     Transform,
     Rotate,
+    Scale,
     #[default]
     Pointer,
     None,
@@ -28,6 +34,34 @@ pub enum GizmoMode {
     Local,
     #[default]
     Global,
+    /// Axes of the selected entity's parent, world axes when it has none
+    Parent,
+    /// Axes of the camera, Z points out of the screen
+    View,
+    /// Rotation captured into NewGizmoConfig::custom_orientation
+    Custom,
+}
+
+impl GizmoMode {
+    pub fn all() -> [GizmoMode; 5] {
+        [
+            GizmoMode::Global,
+            GizmoMode::Local,
+            GizmoMode::Parent,
+            GizmoMode::View,
+            GizmoMode::Custom,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GizmoMode::Local => "Local",
+            GizmoMode::Global => "Global",
+            GizmoMode::Parent => "Parent",
+            GizmoMode::View => "View",
+            GizmoMode::Custom => "Custom",
+        }
+    }
 }
 
 #[derive(Resource)]
@@ -35,6 +69,8 @@ pub struct NewGizmoConfig {
     pub speed_scale: f32,
     pub distance_scale: f32,
     pub mode: GizmoMode,
+    /// World rotation used by GizmoMode::Custom
+    pub custom_orientation: Quat,
 }
 
 impl NewGizmoConfig {
@@ -51,6 +87,12 @@ impl NewGizmoConfig {
             mode: self.mode,
         }
     }
+    pub fn scale(&self) -> GizmoConfig {
+        GizmoConfig::Scale {
+            distance_scale: self.distance_scale,
+            mode: self.mode,
+        }
+    }
 }
 
 #[derive(Component, Clone, Copy)]
@@ -66,6 +108,10 @@ pub enum GizmoConfig {
         distance_scale: f32,
         mode: GizmoMode,
     },
+    Scale {
+        distance_scale: f32,
+        mode: GizmoMode,
+    },
 }
 
 impl GizmoConfig {
@@ -75,6 +121,7 @@ impl GizmoConfig {
             GizmoConfig::None => GizmoType::None,
             GizmoConfig::Rotate { .. } => GizmoType::Rotate,
             GizmoConfig::Transform { .. } => GizmoType::Transform,
+            GizmoConfig::Scale { .. } => GizmoType::Scale,
         }
     }
 
@@ -84,6 +131,7 @@ impl GizmoConfig {
             GizmoConfig::None => GizmoMode::Global,
             GizmoConfig::Rotate { mode, .. } => *mode,
             GizmoConfig::Transform { mode, .. } => *mode,
+            GizmoConfig::Scale { mode, .. } => *mode,
         }
     }
 
@@ -100,6 +148,10 @@ impl GizmoConfig {
                 distance_scale: default_config.distance_scale,
                 mode: default_config.mode,
             },
+            GizmoType::Scale => GizmoConfig::Scale {
+                distance_scale: default_config.distance_scale,
+                mode: default_config.mode,
+            },
         }
     }
 
@@ -113,6 +165,9 @@ impl GizmoConfig {
             GizmoConfig::Transform { ref mut mode, .. } => {
                 *mode = new_mode;
             }
+            GizmoConfig::Scale { ref mut mode, .. } => {
+                *mode = new_mode;
+            }
         }
     }
 }
@@ -133,6 +188,10 @@ pub struct GizmoChildren(Vec<Entity>);
 pub struct GizmoSnap {
     pub rotate_value: f32,
     pub transform_value: f32,
+    /// Increment the scale factor snaps to while dragging, 0 is off
+    pub scale_value: f32,
+    /// Surface snapping also turns the selection's up axis onto the hit normal
+    pub align_to_surface: bool,
 }
 
 #[derive(Component, Deref, Clone, Copy)]
@@ -175,12 +234,21 @@ pub use events::{
     SpawnGizmoEvent, TransformDraggingEvent, TransformInitDragEvent, TransformResetDragEvent,
 };
 pub use manager::{gizmo_changed_watcher, gizmo_events};
+pub use modal::{
+    start_modal_transform_system, update_modal_transform_system, ModalAxisLock, ModalTransformKind,
+    ModalTransformState,
+};
+pub use orientation::{align_gizmo_to_mode_system, GizmoOrientation};
+pub use pivot::{draw_pivot_cursor_system, place_gizmo_at_pivot_system, GizmoPivot, PivotMode};
 pub use plugin::GizmoPlugin;
 pub use rotate::{
     despawn_rotate_gizmo, handle_init_rotate_drag, handle_rotate_dragging, handle_rotate_input,
     handle_rotate_reset, register_embedded_rotate_gizmo_mesh, spawn_rotate_gizmo, RotateGizmo,
     RotateGizmoParent,
 };
+pub use scale::{
+    despawn_scale_gizmo, spawn_scale_gizmo, ScaleDragState, ScaleGizmo, ScaleGizmoParent,
+};
 pub use transform::{
     despawn_transform_gizmo, spawn_transform_gizmo, PreviousTransformGizmo, TransformGizmo,
     TransformGizmoParent,
@@ -0,0 +1,471 @@
+use super::{
+    pivot::{rotate_about, PivotSelection},
+    scale::drag::{dominant_local_axis, snap_scale},
+    transform::drag::snap_gizmo,
+    GizmoPivot, GizmoSnap,
+};
+use crate::{
+    input::{GizmoAction, GizmoAxis, GizmoKeys},
+    selection::ActiveSelection,
+    GizmoCamera,
+};
+use bevy::{
+    color::Color,
+    ecs::{
+        entity::Entity,
+        event::EventReader,
+        query::{With, Without},
+        resource::Resource,
+        system::{Query, Res, ResMut, SystemParam},
+    },
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    math::{primitives::InfinitePlane3d, Quat, Vec2, Vec3},
+    render::camera::Camera,
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_granite_core::UserInput;
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+
+const MIN_SCALE_FACTOR: f32 = 0.01; // never collapse or mirror the selection
+
+/// What a modal transform does, started with its shortcut (G, T or S by default) over the viewport
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ModalTransformKind {
+    Move,
+    Rotate,
+    Scale,
+}
+
+impl ModalTransformKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ModalTransformKind::Move => "Move",
+            ModalTransformKind::Rotate => "Rotate",
+            ModalTransformKind::Scale => "Scale",
+        }
+    }
+}
+
+/// World axis constraint, X/Y/Z locks an axis and Shift + X/Y/Z locks the plane without it
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ModalAxisLock {
+    #[default]
+    Free,
+    Axis(GizmoAxis),
+    /// The normal of the plane, the other two axes stay free
+    Plane(GizmoAxis),
+}
+
+/// One selected root entity as it was when the modal transform started
+pub struct ModalTransformStart {
+    pub entity: Entity,
+    pub transform: Transform,
+    pub parent: Option<GlobalTransform>,
+    pub world_translation: Vec3,
+    pub world_rotation: Quat,
+}
+
+/// Every frame the transform is rebuilt from the start, so Esc can put everything back
+#[derive(Resource, Default)]
+pub struct ModalTransformState {
+    pub kind: Option<ModalTransformKind>,
+    pub lock: ModalAxisLock,
+    /// Exact amount typed while transforming, used instead of the mouse once it parses
+    pub typed: String,
+    pub pivot: Vec3,
+    pub start_cursor: Vec2,
+    pub initial: Vec<ModalTransformStart>,
+    /// A left click confirmed the transform, its release shouldn't select anything
+    pub swallow_click: bool,
+}
+
+impl ModalTransformState {
+    pub fn is_active(&self) -> bool {
+        self.kind.is_some()
+    }
+
+    fn typed_value(&self) -> Option<f32> {
+        self.typed.parse::<f32>().ok()
+    }
+
+    fn finish(&mut self) {
+        self.kind = None;
+        self.lock = ModalAxisLock::Free;
+        self.typed.clear();
+        self.initial.clear();
+    }
+}
+
+pub fn start_modal_transform_system(
+    user_input: Res<UserInput>,
+    keys: GizmoKeys,
+    selection: PivotSelection,
+    active_selection: Query<Entity, With<ActiveSelection>>,
+    transforms: Query<&Transform>,
+    global_transforms: Query<&GlobalTransform>,
+    mut state: ResMut<ModalTransformState>,
+) {
+    if user_input.mouse_left.just_pressed && !state.is_active() {
+        state.swallow_click = false;
+    }
+    if state.is_active()
+        || user_input.mouse_over_egui
+        || user_input.mouse_left.any
+        || user_input.mouse_right.any
+    {
+        return;
+    }
+    // Chords match modifiers exactly, so Ctrl+S still saves instead of scaling
+    let kind = if keys.just_pressed(GizmoAction::ModalMove) {
+        ModalTransformKind::Move
+    } else if keys.just_pressed(GizmoAction::ModalRotate) {
+        ModalTransformKind::Rotate
+    } else if keys.just_pressed(GizmoAction::ModalScale) {
+        ModalTransformKind::Scale
+    } else {
+        return;
+    };
+    let Some(active_global) = active_selection
+        .iter()
+        .next()
+        .and_then(|active| global_transforms.get(active).ok())
+    else {
+        return;
+    };
+
+    let origins: Vec<Vec3> = selection
+        .selected
+        .iter()
+        .filter_map(|entity| global_transforms.get(entity).ok())
+        .map(|global| global.translation())
+        .collect();
+
+    let mut initial = Vec::new();
+    for entity in selection.roots() {
+        let (Ok(transform), Ok(global)) = (transforms.get(entity), global_transforms.get(entity))
+        else {
+            continue;
+        };
+        let (_, world_rotation, world_translation) = global.to_scale_rotation_translation();
+        initial.push(ModalTransformStart {
+            entity,
+            transform: *transform,
+            parent: selection
+                .parents
+                .get(entity)
+                .ok()
+                .and_then(|parent| global_transforms.get(parent.parent()).ok())
+                .copied(),
+            world_translation,
+            world_rotation,
+        });
+    }
+
+    *state = ModalTransformState {
+        kind: Some(kind),
+        lock: ModalAxisLock::Free,
+        typed: String::new(),
+        pivot: selection.pivot.point(active_global.translation(), &origins),
+        start_cursor: user_input.mouse_pos,
+        initial,
+        swallow_click: false,
+    };
+    log!(
+        LogType::Editor,
+        LogLevel::Info,
+        LogCategory::Input,
+        "(shortcut) Modal {} started",
+        kind.name()
+    );
+}
+
+/// Keys held and text typed while a modal transform runs
+#[derive(SystemParam)]
+pub struct ModalInput<'w, 's> {
+    pub user_input: Res<'w, UserInput>,
+    pub keyboard_events: EventReader<'w, 's, KeyboardInput>,
+}
+
+pub fn update_modal_transform_system(
+    input: ModalInput,
+    camera_query: Query<(&GlobalTransform, &Camera), With<GizmoCamera>>,
+    mut objects: Query<&mut Transform, Without<GizmoCamera>>,
+    pivot_config: Res<GizmoPivot>,
+    gizmo_snap: Res<GizmoSnap>,
+    mut state: ResMut<ModalTransformState>,
+    mut gizmos: bevy::gizmos::gizmos::Gizmos,
+) {
+    let ModalInput {
+        user_input,
+        mut keyboard_events,
+    } = input;
+    let Some(kind) = state.kind else {
+        keyboard_events.clear();
+        return;
+    };
+
+    // Esc and right click put everything back
+    if user_input.key_escape.just_pressed || user_input.mouse_right.just_pressed {
+        for start in &state.initial {
+            if let Ok(mut transform) = objects.get_mut(start.entity) {
+                *transform = start.transform;
+            }
+        }
+        state.finish();
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Input,
+            "(shortcut) Modal {} cancelled",
+            kind.name()
+        );
+        return;
+    }
+    if user_input.key_enter.just_pressed || user_input.mouse_left.just_pressed {
+        state.swallow_click = user_input.mouse_left.just_pressed;
+        state.finish();
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Input,
+            "(shortcut) Modal {} confirmed",
+            kind.name()
+        );
+        return;
+    }
+
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(text) => {
+                for character in text.chars() {
+                    match character {
+                        '0'..='9' | '.' => state.typed.push(character),
+                        // Minus flips the sign wherever it's typed
+                        '-' => {
+                            if state.typed.starts_with('-') {
+                                state.typed.remove(0);
+                            } else {
+                                state.typed.insert(0, '-');
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Key::Backspace => {
+                state.typed.pop();
+            }
+            _ => {}
+        }
+    }
+
+    for (pressed, axis) in [
+        (user_input.key_x.just_pressed, GizmoAxis::X),
+        (user_input.key_y.just_pressed, GizmoAxis::Y),
+        (user_input.key_z.just_pressed, GizmoAxis::Z),
+    ] {
+        if !pressed {
+            continue;
+        }
+        let lock = if user_input.shift_left.any {
+            ModalAxisLock::Plane(axis)
+        } else {
+            ModalAxisLock::Axis(axis)
+        };
+        // Pressing the same lock again frees it
+        state.lock = if state.lock == lock {
+            ModalAxisLock::Free
+        } else {
+            lock
+        };
+    }
+
+    let Ok((camera_transform, camera)) = camera_query.single() else {
+        return;
+    };
+    let camera_forward = camera_transform.forward().as_vec3();
+    let pivot = state.pivot;
+    let pivot_screen = camera
+        .world_to_viewport(camera_transform, pivot)
+        .unwrap_or(state.start_cursor);
+    draw_lock(&mut gizmos, state.lock, pivot);
+
+    match kind {
+        ModalTransformKind::Move => {
+            let world_delta = if let Some(value) = state.typed_value() {
+                match state.lock {
+                    ModalAxisLock::Axis(axis) => axis.to_vec3() * value,
+                    // Without an axis the typed distance goes along the first free one
+                    ModalAxisLock::Plane(axis) => axis.plane().0.to_vec3() * value,
+                    ModalAxisLock::Free => Vec3::X * value,
+                }
+            } else {
+                let normal = match state.lock {
+                    ModalAxisLock::Free => camera_forward,
+                    ModalAxisLock::Plane(axis) => axis.to_vec3(),
+                    ModalAxisLock::Axis(axis) => {
+                        let direction = axis.to_vec3();
+                        direction.cross(camera_forward).cross(direction)
+                    }
+                };
+                if normal.length_squared() <= 1e-6 {
+                    return;
+                }
+                let plane = InfinitePlane3d::new(normal.normalize());
+                let hit = |cursor: Vec2| {
+                    let ray = camera.viewport_to_world(camera_transform, cursor).ok()?;
+                    let distance = ray.intersect_plane(pivot, plane)?;
+                    Some(ray.get_point(distance))
+                };
+                let (Some(start), Some(current)) =
+                    (hit(state.start_cursor), hit(user_input.mouse_pos))
+                else {
+                    return;
+                };
+                let raw_delta = current - start;
+                let directions = match state.lock {
+                    ModalAxisLock::Free => vec![Vec3::X, Vec3::Y, Vec3::Z],
+                    ModalAxisLock::Axis(axis) => vec![axis.to_vec3()],
+                    ModalAxisLock::Plane(axis) => {
+                        let (a, b) = axis.plane();
+                        vec![a.to_vec3(), b.to_vec3()]
+                    }
+                };
+                directions
+                    .iter()
+                    .map(|direction| {
+                        *direction
+                            * snap_gizmo(raw_delta.dot(*direction), gizmo_snap.transform_value)
+                    })
+                    .sum::<Vec3>()
+            };
+
+            for start in &state.initial {
+                let Ok(mut transform) = objects.get_mut(start.entity) else {
+                    continue;
+                };
+                let world_translation = start.world_translation + world_delta;
+                transform.translation = match start.parent {
+                    Some(parent) => parent
+                        .affine()
+                        .inverse()
+                        .transform_point3(world_translation),
+                    None => world_translation,
+                };
+            }
+        }
+        ModalTransformKind::Rotate => {
+            let degrees = if let Some(value) = state.typed_value() {
+                value
+            } else {
+                let from = state.start_cursor - pivot_screen;
+                let to = user_input.mouse_pos - pivot_screen;
+                if from.length_squared() <= 1.0 || to.length_squared() <= 1.0 {
+                    return;
+                }
+                // Screen y points down, so a positive angle is clockwise on screen
+                let clockwise = from.perp_dot(to).atan2(from.dot(to)).to_degrees();
+                snap_gizmo(-clockwise, gizmo_snap.rotate_value)
+            };
+            let axis = match state.lock {
+                ModalAxisLock::Free => -camera_forward,
+                ModalAxisLock::Axis(axis) | ModalAxisLock::Plane(axis) => {
+                    // Keep the turn following the mouse when the axis points away from the camera
+                    let axis = axis.to_vec3();
+                    if axis.dot(-camera_forward) < 0.0 {
+                        -axis
+                    } else {
+                        axis
+                    }
+                }
+            };
+            let rotation = Quat::from_axis_angle(axis, degrees.to_radians());
+
+            for start in &state.initial {
+                let Ok(mut transform) = objects.get_mut(start.entity) else {
+                    continue;
+                };
+                let center = if pivot_config.individual() {
+                    start.world_translation
+                } else {
+                    pivot
+                };
+                *transform = start.transform;
+                rotate_about(&mut transform, start.parent.as_ref(), center, rotation);
+            }
+        }
+        ModalTransformKind::Scale => {
+            let factor = if let Some(value) = state.typed_value() {
+                value
+            } else {
+                let from = (state.start_cursor - pivot_screen).length();
+                if from <= 1.0 {
+                    return;
+                }
+                let to = (user_input.mouse_pos - pivot_screen).length();
+                snap_scale(to / from, gizmo_snap.scale_value)
+            };
+            let factor = factor.max(MIN_SCALE_FACTOR);
+
+            for start in &state.initial {
+                let Ok(mut transform) = objects.get_mut(start.entity) else {
+                    continue;
+                };
+                let (mask, world_mask) = match state.lock {
+                    ModalAxisLock::Free => (Vec3::ONE, Vec3::ONE),
+                    ModalAxisLock::Axis(axis) => (
+                        dominant_local_axis(start.world_rotation, axis.to_vec3()),
+                        axis.to_vec3(),
+                    ),
+                    ModalAxisLock::Plane(axis) => (
+                        Vec3::ONE - dominant_local_axis(start.world_rotation, axis.to_vec3()),
+                        Vec3::ONE - axis.to_vec3(),
+                    ),
+                };
+                transform.scale = start.transform.scale * (Vec3::ONE + mask * (factor - 1.0));
+
+                // Other selected entities spread out from the pivot along the free axes
+                if pivot_config.individual() {
+                    continue;
+                }
+                let offset = start.world_translation - pivot;
+                let world_translation = pivot + offset + offset * world_mask * (factor - 1.0);
+                transform.translation = match start.parent {
+                    Some(parent) => parent
+                        .affine()
+                        .inverse()
+                        .transform_point3(world_translation),
+                    None => world_translation,
+                };
+            }
+        }
+    }
+}
+
+fn draw_lock(gizmos: &mut bevy::gizmos::gizmos::Gizmos, lock: ModalAxisLock, pivot: Vec3) {
+    let axes = match lock {
+        ModalAxisLock::Free => return,
+        ModalAxisLock::Axis(axis) => vec![axis],
+        ModalAxisLock::Plane(axis) => {
+            let (a, b) = axis.plane();
+            vec![a, b]
+        }
+    };
+    for axis in axes {
+        let color: Color = axis.color();
+        gizmos.line(
+            pivot - axis.to_vec3() * 1000.,
+            pivot + axis.to_vec3() * 1000.,
+            color,
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use super::{
+    GizmoConfig, GizmoMode, GizmoRoot, NewGizmoConfig, RotateGizmoParent, ScaleGizmoParent,
+    TransformGizmoParent,
+};
+use crate::GizmoCamera;
+use bevy::{
+    ecs::{
+        entity::Entity,
+        hierarchy::ChildOf,
+        query::{Or, With},
+        system::{Query, Res, SystemParam},
+    },
+    math::Quat,
+    transform::components::{GlobalTransform, Transform},
+};
+
+type AlignedGizmoQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Transform,
+        &'static GizmoConfig,
+        &'static ChildOf,
+    ),
+    Or<(
+        With<TransformGizmoParent>,
+        With<RotateGizmoParent>,
+        With<ScaleGizmoParent>,
+    )>,
+>;
+
+/// Works out which way a gizmo's axes point for its mode
+#[derive(SystemParam)]
+pub struct GizmoOrientation<'w, 's> {
+    pub configs: Query<'w, 's, &'static GizmoConfig>,
+    pub new_config: Res<'w, NewGizmoConfig>,
+    pub global_transforms: Query<'w, 's, &'static GlobalTransform>,
+    pub parents: Query<'w, 's, &'static ChildOf>,
+    pub camera: Query<'w, 's, &'static GlobalTransform, With<GizmoCamera>>,
+}
+
+impl GizmoOrientation<'_, '_> {
+    /// Mode of the gizmo a handle belongs to, or the one new gizmos get
+    pub fn mode(&self, root: Option<&GizmoRoot>) -> GizmoMode {
+        root.and_then(|GizmoRoot(root)| self.configs.get(*root).ok())
+            .map_or(self.new_config.mode, |config| config.mode())
+    }
+
+    /// World rotation of the gizmo axes around target
+    pub fn rotation(&self, mode: GizmoMode, target: Entity) -> Quat {
+        match mode {
+            GizmoMode::Global => Quat::IDENTITY,
+            GizmoMode::Local => self.world_rotation(target),
+            GizmoMode::Parent => self.parents.get(target).map_or(Quat::IDENTITY, |parent| {
+                self.world_rotation(parent.parent())
+            }),
+            GizmoMode::View => self
+                .camera
+                .single()
+                .map_or(Quat::IDENTITY, |camera| camera.rotation()),
+            GizmoMode::Custom => self.new_config.custom_orientation,
+        }
+    }
+
+    fn world_rotation(&self, entity: Entity) -> Quat {
+        self.global_transforms
+            .get(entity)
+            .map_or(Quat::IDENTITY, |global| global.rotation())
+    }
+}
+
+/// Keeps gizmos lined up with their mode as the entity or camera turns, or the mode changes
+/// The root is parented to the entity, so its rotation cancels the entity's out first
+pub fn align_gizmo_to_mode_system(
+    mut gizmo_query: AlignedGizmoQuery,
+    orientation: GizmoOrientation,
+) {
+    for (mut transform, config, child_of) in gizmo_query.iter_mut() {
+        let target = child_of.parent();
+        let rotation = orientation.world_rotation(target).inverse()
+            * orientation.rotation(config.mode(), target);
+        if !transform.rotation.abs_diff_eq(rotation, 1e-5) {
+            transform.rotation = rotation;
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use super::{
+    GizmoMesh, GizmoRoot, RotateGizmo, RotateGizmoParent, ScaleGizmo, ScaleGizmoParent,
+    TransformGizmoParent,
+};
+use crate::selection::Selected;
+use bevy::{
+    color::Color,
+    ecs::{
+        entity::Entity,
+        hierarchy::ChildOf,
+        observer::Trigger,
+        query::{Or, With},
+        resource::Resource,
+        system::{Query, Res, ResMut, SystemParam},
+    },
+    math::{Isometry3d, Quat, Vec3},
+    picking::{
+        events::{Click, DragEnd, DragStart, Pointer},
+        pointer::PointerButton,
+    },
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_granite_core::UserInput;
+
+const CURSOR_RADIUS: f32 = 0.25;
+
+type GizmoRootQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut Transform, &'static ChildOf),
+    Or<(
+        With<TransformGizmoParent>,
+        With<RotateGizmoParent>,
+        With<ScaleGizmoParent>,
+    )>,
+>;
+
+type PivotHandleQuery<'w, 's> =
+    Query<'w, 's, &'static GizmoRoot, (With<GizmoMesh>, Or<(With<RotateGizmo>, With<ScaleGizmo>)>)>;
+
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub enum PivotMode {
+    /// Origin of the ActiveSelection entity
+    #[default]
+    ActiveElement,
+    /// Average of every selected origin
+    MedianPoint,
+    /// Middle of the box around every selected origin
+    BoundingBoxCenter,
+    /// Each entity rotates and scales around its own origin
+    IndividualOrigins,
+    /// The user placed 3D cursor, shift + right click to place it
+    Cursor,
+}
+
+impl PivotMode {
+    pub fn all() -> [PivotMode; 5] {
+        [
+            PivotMode::ActiveElement,
+            PivotMode::MedianPoint,
+            PivotMode::BoundingBoxCenter,
+            PivotMode::IndividualOrigins,
+            PivotMode::Cursor,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PivotMode::ActiveElement => "Active",
+            PivotMode::MedianPoint => "Median",
+            PivotMode::BoundingBoxCenter => "Bounds",
+            PivotMode::IndividualOrigins => "Individual",
+            PivotMode::Cursor => "3D Cursor",
+        }
+    }
+}
+
+/// Point the gizmo sits on and multi-selection transforms happen around
+#[derive(Resource, Default)]
+pub struct GizmoPivot {
+    pub mode: PivotMode,
+    /// World position of the 3D cursor
+    pub cursor: Vec3,
+    /// Held while a rotate or scale handle is dragged, so the pivot can't wander mid drag
+    pub locked: Option<Vec3>,
+}
+
+impl GizmoPivot {
+    /// World space pivot from the active origin and every selected origin
+    pub fn point(&self, active: Vec3, origins: &[Vec3]) -> Vec3 {
+        if let Some(locked) = self.locked {
+            return locked;
+        }
+        match self.mode {
+            PivotMode::ActiveElement | PivotMode::IndividualOrigins => active,
+            PivotMode::Cursor => self.cursor,
+            PivotMode::MedianPoint if !origins.is_empty() => {
+                origins.iter().sum::<Vec3>() / origins.len() as f32
+            }
+            PivotMode::BoundingBoxCenter if !origins.is_empty() => {
+                let (min, max) = origins.iter().fold(
+                    (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+                    |(min, max), origin| (min.min(*origin), max.max(*origin)),
+                );
+                (min + max) * 0.5
+            }
+            PivotMode::MedianPoint | PivotMode::BoundingBoxCenter => active,
+        }
+    }
+
+    pub fn individual(&self) -> bool {
+        self.mode == PivotMode::IndividualOrigins
+    }
+}
+
+/// Selected entities without a selected ancestor
+/// Anything below a selected entity already follows it, transforming it too would apply it twice
+pub fn selection_roots(selected: &[Entity], parents: &Query<&ChildOf>) -> Vec<Entity> {
+    selected
+        .iter()
+        .copied()
+        .filter(|entity| {
+            !parents
+                .iter_ancestors(*entity)
+                .any(|ancestor| selected.contains(&ancestor))
+        })
+        .collect()
+}
+
+/// Selection and pivot a rotate or scale drag starts from
+#[derive(SystemParam)]
+pub struct PivotSelection<'w, 's> {
+    pub pivot: Res<'w, GizmoPivot>,
+    pub selected: Query<'w, 's, Entity, With<Selected>>,
+    pub parents: Query<'w, 's, &'static ChildOf>,
+}
+
+impl PivotSelection<'_, '_> {
+    /// Selected entities without a selected ancestor
+    pub fn roots(&self) -> Vec<Entity> {
+        let selected: Vec<Entity> = self.selected.iter().collect();
+        selection_roots(&selected, &self.parents)
+    }
+}
+
+/// Rotates an entity around a world space point, writing back in its parent's space
+pub fn rotate_about(
+    transform: &mut Transform,
+    parent: Option<&GlobalTransform>,
+    center: Vec3,
+    rotation: Quat,
+) {
+    let world = match parent {
+        Some(parent) => parent.mul_transform(*transform),
+        None => GlobalTransform::from(*transform),
+    };
+    let (_, world_rotation, world_translation) = world.to_scale_rotation_translation();
+    let new_translation = center + rotation * (world_translation - center);
+    let new_rotation = rotation * world_rotation;
+    match parent {
+        Some(parent) => {
+            transform.translation = parent.affine().inverse().transform_point3(new_translation);
+            transform.rotation = parent.rotation().inverse() * new_rotation;
+        }
+        None => {
+            transform.translation = new_translation;
+            transform.rotation = new_rotation;
+        }
+    }
+}
+
+/// Moves every gizmo root onto the pivot, the root stays parented to the active entity
+pub fn place_gizmo_at_pivot_system(
+    pivot: Res<GizmoPivot>,
+    mut gizmo_query: GizmoRootQuery,
+    global_transforms: Query<&GlobalTransform>,
+    selected: Query<Entity, With<Selected>>,
+) {
+    if gizmo_query.is_empty() {
+        return;
+    }
+    let origins: Vec<Vec3> = selected
+        .iter()
+        .filter_map(|entity| global_transforms.get(entity).ok())
+        .map(|global| global.translation())
+        .collect();
+
+    for (mut transform, child_of) in gizmo_query.iter_mut() {
+        let Ok(parent_global) = global_transforms.get(child_of.parent()) else {
+            continue;
+        };
+        let world = pivot.point(parent_global.translation(), &origins);
+        let local = parent_global.affine().inverse().transform_point3(world);
+        if !transform.translation.abs_diff_eq(local, 1e-5) {
+            transform.translation = local;
+        }
+    }
+}
+
+/// Rotation and scale keep their pivot for the whole drag
+/// A bounding box center would otherwise drift as the selection turns
+pub fn lock_pivot_on_drag_start(
+    event: Trigger<Pointer<DragStart>>,
+    handles: PivotHandleQuery,
+    global_transforms: Query<&GlobalTransform>,
+    mut pivot: ResMut<GizmoPivot>,
+) {
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    let Ok(GizmoRoot(root)) = handles.get(event.target) else {
+        return;
+    };
+    if let Ok(root_global) = global_transforms.get(*root) {
+        pivot.locked = Some(root_global.translation());
+    }
+}
+
+pub fn unlock_pivot_on_drag_end(_event: Trigger<Pointer<DragEnd>>, mut pivot: ResMut<GizmoPivot>) {
+    if pivot.locked.is_some() {
+        pivot.locked = None;
+    }
+}
+
+/// Shift + right click on a surface puts the 3D cursor there
+pub fn place_pivot_cursor(
+    event: Trigger<Pointer<Click>>,
+    user_input: Res<UserInput>,
+    mut pivot: ResMut<GizmoPivot>,
+) {
+    if event.button != PointerButton::Secondary
+        || !user_input.shift_left.any
+        || user_input.mouse_over_egui
+    {
+        return;
+    }
+    if let Some(position) = event.hit.position {
+        pivot.cursor = position;
+    }
+}
+
+pub fn draw_pivot_cursor_system(pivot: Res<GizmoPivot>, mut gizmos: bevy::gizmos::gizmos::Gizmos) {
+    if pivot.mode != PivotMode::Cursor {
+        return;
+    }
+    let color = Color::srgb(1.0, 0.4, 0.2);
+    gizmos.circle(
+        Isometry3d::new(pivot.cursor, Quat::from_rotation_arc(Vec3::Z, Vec3::Y)),
+        CURSOR_RADIUS,
+        color,
+    );
+    for axis in [Vec3::X, Vec3::Y, Vec3::Z] {
+        gizmos.line(
+            pivot.cursor - axis * CURSOR_RADIUS * 1.5,
+            pivot.cursor + axis * CURSOR_RADIUS * 1.5,
+            color,
+        );
+    }
+}
@@ -1,16 +1,22 @@
-use super::register_embedded_rotate_gizmo_mesh;
 use super::{
-    gizmo_changed_watcher, gizmo_events, handle_init_rotate_drag, 
-    handle_rotate_input, handle_rotate_reset, scale_gizmo_by_camera_distance_system,
-    DespawnGizmoEvent, GizmoSnap, GizmoType, LastSelectedGizmo, NewGizmoConfig,
-    PreviousTransformGizmo, RotateDraggingEvent, RotateInitDragEvent, RotateResetDragEvent,
-    SpawnGizmoEvent, TransformDraggingEvent, TransformInitDragEvent, TransformResetDragEvent,
+    align_gizmo_to_mode_system, draw_pivot_cursor_system, place_gizmo_at_pivot_system,
+    register_embedded_rotate_gizmo_mesh, start_modal_transform_system,
+    update_modal_transform_system, GizmoPivot, ModalTransformState,
+};
+use super::{
+    gizmo_changed_watcher, gizmo_events, handle_init_rotate_drag, handle_rotate_input,
+    handle_rotate_reset, scale_gizmo_by_camera_distance_system, DespawnGizmoEvent, GizmoSnap,
+    GizmoType, LastSelectedGizmo, NewGizmoConfig, PreviousTransformGizmo, RotateDraggingEvent,
+    RotateInitDragEvent, RotateResetDragEvent, ScaleDragState, SpawnGizmoEvent,
+    TransformDraggingEvent, TransformInitDragEvent, TransformResetDragEvent,
 };
 use crate::gizmos::{GizmoMode, NewGizmoType};
 use crate::is_gizmos_active;
 use bevy::{
     app::{App, Plugin, PostUpdate, Startup, Update},
     ecs::schedule::IntoScheduleConfigs,
+    math::Quat,
+    transform::TransformSystem,
 };
 
 pub struct GizmoPlugin;
@@ -28,13 +34,20 @@ impl Plugin for GizmoPlugin {
                 speed_scale: 1.,
                 distance_scale: 1.,
                 mode: GizmoMode::Global,
+                custom_orientation: Quat::IDENTITY,
             })
             .insert_resource(NewGizmoType(GizmoType::Pointer))
             .insert_resource(GizmoSnap {
                 transform_value: 0.,
                 rotate_value: 0.,
+                scale_value: 0.,
+                align_to_surface: false,
             })
             .insert_resource(super::transform::drag::TransformDuplicationState::default())
+            .insert_resource(super::transform::drag::TransformGrabState::default())
+            .insert_resource(ScaleDragState::default())
+            .insert_resource(GizmoPivot::default())
+            .insert_resource(ModalTransformState::default())
             //
             // Events
             //
@@ -64,13 +77,31 @@ impl Plugin for GizmoPlugin {
                 )
                     .run_if(is_gizmos_active),
             )
+            .add_systems(
+                Update,
+                (start_modal_transform_system, update_modal_transform_system)
+                    .chain()
+                    .run_if(is_gizmos_active),
+            )
             .add_systems(
                 PostUpdate,
                 (
                     scale_gizmo_by_camera_distance_system.run_if(is_gizmos_active),
+                    (
+                        place_gizmo_at_pivot_system,
+                        align_gizmo_to_mode_system,
+                        super::transform::face_screen_handle_system,
+                    )
+                        .chain()
+                        .before(TransformSystem::TransformPropagate)
+                        .run_if(is_gizmos_active),
                     super::transform::cleanup_axis_line,
+                    draw_pivot_cursor_system.run_if(is_gizmos_active),
                 ),
             );
         app.add_observer(super::transform::draw_axis_lines);
+        app.add_observer(super::pivot::lock_pivot_on_drag_start);
+        app.add_observer(super::pivot::unlock_pivot_on_drag_end);
+        app.add_observer(super::pivot::place_pivot_cursor);
     }
 }
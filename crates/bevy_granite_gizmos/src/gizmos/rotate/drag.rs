@@ -2,9 +2,10 @@
 // Children inherit rotation automatically through hierarchy
 use crate::{
     gizmos::{
-        GizmoConfig, GizmoMesh, GizmoOf, GizmoSnap, GizmoType, NewGizmoConfig, NewGizmoType,
-        RotateDraggingEvent, RotateGizmo, RotateGizmoParent, RotateInitDragEvent,
-        RotateResetDragEvent,
+        pivot::{rotate_about, selection_roots},
+        GizmoConfig, GizmoMesh, GizmoOf, GizmoOrientation, GizmoPivot, GizmoRoot, GizmoSnap,
+        GizmoType, NewGizmoConfig, NewGizmoType, RotateDraggingEvent, RotateGizmo,
+        RotateGizmoParent, RotateInitDragEvent, RotateResetDragEvent,
     },
     input::{DragState, GizmoAxis},
     selection::{
@@ -242,13 +243,15 @@ pub fn handle_rotate_dragging(
     parents: Query<&ChildOf>,
     gizmo_snap: Res<GizmoSnap>,
     selected: Res<NewGizmoConfig>,
-    gizmo_data: Query<(&GizmoAxis, Option<&GizmoConfig>)>,
+    pivot: Res<GizmoPivot>,
+    orientation: GizmoOrientation,
+    gizmo_data: Query<(&GizmoAxis, Option<&GizmoConfig>, Option<&GizmoRoot>)>,
     mut accrued: Local<Vec2>,
 ) {
     if event.button != PointerButton::Primary {
         return;
     }
-    let Ok((gizmo_axis, config)) = gizmo_data.get(event.target) else {
+    let Ok((gizmo_axis, config, gizmo_root)) = gizmo_data.get(event.target) else {
         log!(
             LogType::Editor,
             LogLevel::Warning,
@@ -288,7 +291,7 @@ pub fn handle_rotate_dragging(
 
     let delta_x = x_step.to_radians();
     let delta_y = y_step.to_radians();
-    let Ok(_target) = targets.get(event.target) else {
+    let Ok(GizmoOf(target)) = targets.get(event.target) else {
         log(
             LogType::Editor,
             LogLevel::Error,
@@ -331,21 +334,15 @@ pub fn handle_rotate_dragging(
         return;
     }
 
-    let mut root_entities = Vec::new();
-    for &entity in &all_selected_entities {
-        let mut is_child_of_selected = false;
-        if let Ok(parent) = parents.get(entity) {
-            if all_selected_entities.contains(&parent.parent()) {
-                is_child_of_selected = true;
-            }
-        }
-        if !is_child_of_selected {
-            root_entities.push(entity);
-        }
-    }
+    let root_entities = selection_roots(&all_selected_entities, &parents);
 
+    // The gizmo sits on the pivot, fall back to the active entity if it can't be found
     let origin = {
-        if let Some(active_entity) = active_selection.iter().next() {
+        if let Some(root_global) =
+            gizmo_root.and_then(|GizmoRoot(root)| global_transforms.get(*root).ok())
+        {
+            root_global.translation()
+        } else if let Some(active_entity) = active_selection.iter().next() {
             if let Ok(active_global_transform) = global_transforms.get(active_entity) {
                 active_global_transform.translation()
             } else {
@@ -356,40 +353,45 @@ pub fn handle_rotate_dragging(
         }
     };
 
+    // Rings follow the gizmo's orientation mode, so spin around its axes rather than the world's
+    let axes = orientation.rotation(orientation.mode(gizmo_root), *target);
+    let (axis_x, axis_y, axis_z) = (axes * Vec3::X, axes * Vec3::Y, axes * Vec3::Z);
+    let camera_position = camera_transform.translation();
+
     let final_rotation = match gizmo_axis {
         GizmoAxis::All => rotation_delta,
         GizmoAxis::X => {
             let mut delta = effective_delta_y;
-            if origin.x > camera_transform.translation().x {
+            if (origin - camera_position).dot(axis_x) > 0. {
                 delta = -delta;
             }
 
-            Quat::from_rotation_x(delta)
+            Quat::from_axis_angle(axis_x, delta)
         }
         GizmoAxis::Y => {
             let mut delta = effective_delta_x;
-            if origin.y > camera_transform.translation().y {
+            if (origin - camera_position).dot(axis_y) > 0. {
                 delta = -delta;
             }
 
-            Quat::from_rotation_y(delta)
+            Quat::from_axis_angle(axis_y, delta)
         }
         GizmoAxis::Z => {
             let (pitch, roll, yaw) = rotation_delta.to_euler(bevy::math::EulerRot::XZY);
             let mut delta = roll;
-            if let Some(hit_distance) = click_ray
-                .intersect_plane(Vec3::new(0., 0., origin.z), InfinitePlane3d::new(Vec3::Z))
+            if let Some(hit_distance) =
+                click_ray.intersect_plane(origin, InfinitePlane3d::new(axis_z))
             {
-                let hit_point = camera_transform.translation() + click_ray.direction * hit_distance;
-                let y_diff = origin.y - hit_point.y;
-                let x_diff = origin.x - hit_point.x;
+                let hit_point = camera_position + click_ray.direction * hit_distance;
+                let y_diff = (origin - hit_point).dot(axis_y);
+                let x_diff = (origin - hit_point).dot(axis_x);
                 delta += yaw * y_diff.signum();
                 delta += pitch * x_diff.signum();
             }
-            if origin.z > camera_transform.translation().z {
+            if (origin - camera_position).dot(axis_z) > 0. {
                 delta = -delta;
             }
-            Quat::from_rotation_z(delta)
+            Quat::from_axis_angle(axis_z, delta)
         }
         GizmoAxis::None => {
             log!(
@@ -402,11 +404,22 @@ pub fn handle_rotate_dragging(
         }
     };
     for &entity in &root_entities {
+        let parent_global = parents
+            .get(entity)
+            .ok()
+            .and_then(|parent| global_transforms.get(parent.parent()).ok());
         if let Ok(mut entity_transform) = objects.get_mut(entity) {
-            let relative_pos = entity_transform.translation - origin;
-            let rotated_relative_pos = final_rotation * relative_pos;
-            entity_transform.translation = origin + rotated_relative_pos;
-            entity_transform.rotation = final_rotation * entity_transform.rotation;
+            let center = if pivot.individual() {
+                match parent_global {
+                    Some(parent_global) => {
+                        parent_global.transform_point(entity_transform.translation)
+                    }
+                    None => entity_transform.translation,
+                }
+            } else {
+                origin
+            };
+            rotate_about(&mut entity_transform, parent_global, center, final_rotation);
         }
     }
     *accrued = Vec2::ZERO;
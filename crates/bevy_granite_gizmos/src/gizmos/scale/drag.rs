@@ -0,0 +1,235 @@
+use super::{ScaleGizmo, SCALE_LINE_LENGTH};
+use crate::{
+    gizmos::{pivot::PivotSelection, GizmoConfig, GizmoOf, GizmoOrientation, GizmoRoot, GizmoSnap},
+    input::GizmoAxis,
+    GizmoCamera,
+};
+use bevy::{
+    ecs::{hierarchy::ChildOf, observer::Trigger},
+    picking::{
+        events::{Drag, DragStart, Pointer},
+        pointer::PointerButton,
+    },
+    prelude::{
+        Entity, GlobalTransform, Quat, Query, Res, ResMut, Resource, Transform, Vec3, With, Without,
+    },
+    render::camera::Camera,
+};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+
+const UNIFORM_DRAG_SPEED: f32 = 0.005; // scale factor per pixel on the center handle
+const MIN_SCALE_FACTOR: f32 = 0.01; // never collapse or mirror the selection
+
+/// Where one selected root entity was when the drag started
+pub struct ScaleDragStart {
+    pub entity: Entity,
+    pub transform: Transform,
+    pub world_translation: Vec3,
+    pub world_rotation: Quat,
+}
+
+/// Scale is applied from the drag start each frame, so snapping lands on exact factors
+#[derive(Resource, Default)]
+pub struct ScaleDragState {
+    pub pivot: Vec3,
+    /// World space direction being scaled, zero for the uniform handle
+    pub axis: Vec3,
+    /// World length of the handle, dragging by this much on screen doubles the scale
+    pub handle_length: f32,
+    pub accumulated: f32,
+    /// Each entity scales around its own origin instead of spreading out from the pivot
+    pub individual: bool,
+    pub initial: Vec<ScaleDragStart>,
+}
+
+pub fn dragstart_scale_gizmo(
+    event: Trigger<Pointer<DragStart>>,
+    gizmo_data: Query<(&GizmoAxis, &GizmoOf, &GizmoRoot), With<ScaleGizmo>>,
+    orientation: GizmoOrientation,
+    transforms: Query<(&Transform, &GlobalTransform)>,
+    pivot_selection: PivotSelection,
+    mut drag_state: ResMut<ScaleDragState>,
+) {
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    let Ok((axis, GizmoOf(target), GizmoRoot(root))) = gizmo_data.get(event.target) else {
+        log!(
+            LogType::Editor,
+            LogLevel::Warning,
+            LogCategory::Input,
+            "Gizmo Axis data not found for Scale Gizmo entity {:?}",
+            event.target
+        );
+        return;
+    };
+    let Ok((_, target_global)) = transforms.get(*target) else {
+        log! {
+            LogType::Editor,
+            LogLevel::Error,
+            LogCategory::Input,
+            "Gizmo target transform not found for entity {:?}",
+            target
+        };
+        return;
+    };
+
+    let config = orientation
+        .configs
+        .get(*root)
+        .copied()
+        .unwrap_or_else(|_| orientation.new_config.scale());
+    let GizmoConfig::Scale {
+        distance_scale,
+        mode,
+    } = config
+    else {
+        log!(
+            LogType::Editor,
+            LogLevel::Warning,
+            LogCategory::Input,
+            "Gizmo Config for scale was not a Scale Config",
+        );
+        return;
+    };
+
+    let target_translation = target_global.translation();
+    // The gizmo sits on the pivot, which isn't always the target's origin
+    let pivot = transforms
+        .get(*root)
+        .map_or(target_translation, |(_, root_global)| {
+            root_global.translation()
+        });
+    let world_axis = match axis {
+        GizmoAxis::All | GizmoAxis::None => Vec3::ZERO,
+        _ => orientation.rotation(mode, *target) * axis.to_vec3(),
+    };
+
+    // Children follow their selected parent, scaling them too would apply it twice
+    let mut initial = Vec::new();
+    for entity in pivot_selection.roots() {
+        let Ok((transform, global)) = transforms.get(entity) else {
+            continue;
+        };
+        let (_, world_rotation, world_translation) = global.to_scale_rotation_translation();
+        initial.push(ScaleDragStart {
+            entity,
+            transform: *transform,
+            world_translation,
+            world_rotation,
+        });
+    }
+
+    *drag_state = ScaleDragState {
+        pivot,
+        axis: world_axis,
+        handle_length: SCALE_LINE_LENGTH * distance_scale,
+        accumulated: 0.0,
+        individual: pivot_selection.pivot.individual(),
+        initial,
+    };
+}
+
+pub fn drag_scale_gizmo(
+    event: Trigger<Pointer<Drag>>,
+    camera_query: Query<(&GlobalTransform, &Camera), With<GizmoCamera>>,
+    mut objects: Query<&mut Transform, Without<GizmoCamera>>,
+    global_transforms: Query<&GlobalTransform>,
+    parents: Query<&ChildOf>,
+    gizmo_snap: Res<GizmoSnap>,
+    mut drag_state: ResMut<ScaleDragState>,
+) {
+    if event.button != PointerButton::Primary || drag_state.initial.is_empty() {
+        return;
+    }
+    let Ok((camera_transform, camera)) = camera_query.single() else {
+        log!(
+            LogType::Editor,
+            LogLevel::Error,
+            LogCategory::Input,
+            "Gizmo Camera not found for scale drag"
+        );
+        return;
+    };
+
+    let uniform_amount = (event.delta.x - event.delta.y) * UNIFORM_DRAG_SPEED;
+    let amount = if drag_state.axis == Vec3::ZERO {
+        uniform_amount
+    } else {
+        // Follow the handle as drawn on screen, so dragging outwards always grows
+        let handle_end = drag_state.pivot + drag_state.axis * drag_state.handle_length;
+        match (
+            camera.world_to_viewport(camera_transform, drag_state.pivot),
+            camera.world_to_viewport(camera_transform, handle_end),
+        ) {
+            (Ok(start), Ok(end)) if start.distance(end) > 1.0 => {
+                let screen_axis = end - start;
+                event.delta.dot(screen_axis) / screen_axis.length_squared()
+            }
+            // Handle points straight at the camera
+            _ => uniform_amount,
+        }
+    };
+    drag_state.accumulated += amount;
+    let factor =
+        snap_scale(1.0 + drag_state.accumulated, gizmo_snap.scale_value).max(MIN_SCALE_FACTOR);
+
+    let uniform = drag_state.axis == Vec3::ZERO;
+    for start in &drag_state.initial {
+        let Ok(mut transform) = objects.get_mut(start.entity) else {
+            continue;
+        };
+        let mask = if uniform {
+            Vec3::ONE
+        } else {
+            dominant_local_axis(start.world_rotation, drag_state.axis)
+        };
+        transform.scale = start.transform.scale * (Vec3::ONE + mask * (factor - 1.0));
+
+        // Other selected entities spread out from the pivot along the same axis
+        let offset = start.world_translation - drag_state.pivot;
+        if drag_state.individual || offset.length_squared() <= f32::EPSILON {
+            continue;
+        }
+        let scaled_offset = if uniform {
+            offset * factor
+        } else {
+            offset + drag_state.axis * offset.dot(drag_state.axis) * (factor - 1.0)
+        };
+        let world_translation = drag_state.pivot + scaled_offset;
+        transform.translation = match parents
+            .get(start.entity)
+            .ok()
+            .and_then(|parent| global_transforms.get(parent.parent()).ok())
+        {
+            Some(parent_global) => parent_global
+                .affine()
+                .inverse()
+                .transform_point3(world_translation),
+            None => world_translation,
+        };
+    }
+}
+
+/// Scale can only stretch an entity along its own axes, so pick the one closest to the world axis
+pub(crate) fn dominant_local_axis(world_rotation: Quat, world_axis: Vec3) -> Vec3 {
+    let local = (world_rotation.inverse() * world_axis).abs();
+    if local.x >= local.y && local.x >= local.z {
+        Vec3::X
+    } else if local.y >= local.z {
+        Vec3::Y
+    } else {
+        Vec3::Z
+    }
+}
+
+pub(crate) fn snap_scale(value: f32, inc: f32) -> f32 {
+    if inc == 0.0 {
+        value
+    } else {
+        (value / inc).round() * inc
+    }
+}
@@ -0,0 +1,221 @@
+use bevy::{
+    ecs::hierarchy::{ChildOf, Children},
+    pbr::{MeshMaterial3d, NotShadowCaster, NotShadowReceiver},
+    prelude::{
+        AlphaMode, Assets, Color, Commands, Component, Cuboid, Cylinder, Entity, GlobalTransform,
+        Mesh, Name, Quat, Query, ResMut, StandardMaterial, Transform, Vec3, Visibility, Without,
+    },
+    render::mesh::Mesh3d,
+};
+use bevy_granite_core::TreeHiddenEntity;
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+
+use crate::{
+    gizmos::{GizmoConfig, GizmoMesh, GizmoMode, GizmoOf, GizmoRoot},
+    input::GizmoAxis,
+};
+
+#[derive(Component)]
+pub struct ScaleGizmo;
+
+#[derive(Component)]
+pub struct ScaleGizmoParent;
+
+const GIZMO_SCALE: f32 = 1.35;
+
+pub(crate) const SCALE_LINE_LENGTH: f32 = 0.6 * GIZMO_SCALE; // length of line
+const SCALE_LINE_WIDTH: f32 = 0.04 * GIZMO_SCALE; // width of line
+const SCALE_HANDLE_SIZE: f32 = 0.13 * GIZMO_SCALE; // cube handle at the end of each line
+const SCALE_UNIFORM_SIZE: f32 = 0.16 * GIZMO_SCALE; // middle cube of gizmo (uniform scale)
+
+pub fn spawn_scale_gizmo(
+    parent: Entity,
+    query: &mut Query<&GlobalTransform, Without<ScaleGizmoParent>>,
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    config: GizmoConfig,
+) {
+    if let Ok(parent_global_transform) = query.get(parent) {
+        // Local mode follows the entity, the rest start on world axes until align_gizmo_to_mode_system runs
+        let rotation = match config.mode() {
+            GizmoMode::Local => Quat::IDENTITY,
+            _ => parent_global_transform
+                .to_scale_rotation_translation()
+                .1
+                .inverse(),
+        };
+
+        let gizmo_entity = commands
+            .spawn((
+                Transform {
+                    rotation,
+                    ..Default::default()
+                },
+                Visibility::default(),
+                GizmoOf(parent),
+                ChildOf(parent),
+                config,
+            ))
+            .insert(Name::new("ScaleGizmo"))
+            .insert(ScaleGizmo)
+            .insert(ScaleGizmoParent)
+            .insert(TreeHiddenEntity)
+            .id();
+
+        build_uniform_cube(
+            parent,
+            commands,
+            meshes,
+            materials,
+            gizmo_entity,
+            Color::srgba(0.8, 0.8, 0.8, 1.),
+        );
+
+        for (axis, color) in [
+            (GizmoAxis::X, Color::srgba(1., 0., 0., 1.)),
+            (GizmoAxis::Y, Color::srgba(0., 1., 0., 1.)),
+            (GizmoAxis::Z, Color::srgba(0., 0., 1., 1.)),
+        ] {
+            build_axis_handle(
+                parent,
+                commands,
+                meshes,
+                materials,
+                gizmo_entity,
+                axis,
+                color,
+            );
+        }
+
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Scale Gizmo spawned"
+        );
+    } else {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Failed to spawn Scale Gizmo. Parent Entity {:?} not found or missing Transform.",
+            parent
+        );
+    }
+}
+
+pub fn despawn_scale_gizmo(
+    commands: &mut Commands,
+    query: &mut Query<(Entity, &ScaleGizmo, &Children)>,
+) {
+    for (entity, _, _) in query.iter() {
+        commands.entity(entity).try_despawn();
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Despawned Scale Gizmo"
+        );
+    }
+}
+
+fn build_uniform_cube(
+    root: Entity,
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    parent: Entity,
+    color: Color,
+) {
+    let cube_handle = meshes.add(Cuboid::from_length(SCALE_UNIFORM_SIZE));
+
+    let material = materials.add(StandardMaterial {
+        base_color: color,
+        unlit: true,
+        alpha_mode: AlphaMode::AlphaToCoverage,
+        ..Default::default()
+    });
+
+    commands
+        .spawn((
+            Mesh3d(cube_handle),
+            MeshMaterial3d(material),
+            NotShadowCaster,
+            NotShadowReceiver,
+            Name::new("Gizmo Scale Uniform"),
+            GizmoAxis::All,
+            ScaleGizmo,
+            GizmoMesh,
+            GizmoOf(root),
+            GizmoRoot(parent),
+            ChildOf(parent),
+        ))
+        .observe(super::drag::dragstart_scale_gizmo)
+        .observe(super::drag::drag_scale_gizmo);
+}
+
+fn build_axis_handle(
+    root: Entity,
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    parent: Entity,
+    axis: GizmoAxis,
+    color: Color,
+) {
+    let line_mesh = meshes.add(Mesh::from(Cylinder {
+        radius: SCALE_LINE_WIDTH,
+        half_height: SCALE_LINE_LENGTH * 0.5,
+    }));
+
+    let cube_mesh = meshes.add(Cuboid::from_length(SCALE_HANDLE_SIZE));
+
+    let material = materials.add(StandardMaterial {
+        base_color: color,
+        unlit: true,
+        alpha_mode: AlphaMode::AlphaToCoverage,
+        ..Default::default()
+    });
+
+    commands
+        .spawn((
+            Mesh3d(line_mesh),
+            MeshMaterial3d(material.clone()),
+            Transform {
+                translation: axis.to_vec3() * SCALE_LINE_LENGTH * 0.5,
+                rotation: Quat::from_rotation_arc(Vec3::Y, axis.to_vec3()),
+                ..Default::default()
+            },
+            NotShadowCaster,
+            NotShadowReceiver,
+            Name::new("Gizmo Scale Line"),
+            axis,
+            ScaleGizmo,
+            GizmoMesh,
+            GizmoOf(root),
+            GizmoRoot(parent),
+            ChildOf(parent),
+        ))
+        .observe(super::drag::dragstart_scale_gizmo)
+        .observe(super::drag::drag_scale_gizmo)
+        .with_child((
+            Mesh3d(cube_mesh),
+            MeshMaterial3d(material),
+            Transform {
+                translation: Vec3::Y * (SCALE_LINE_LENGTH * 0.5),
+                ..Default::default()
+            },
+            NotShadowCaster,
+            NotShadowReceiver,
+            Name::new("Gizmo Scale Handle"),
+            axis,
+            ScaleGizmo,
+            GizmoMesh,
+            GizmoOf(root),
+            GizmoRoot(parent),
+        ));
+}
@@ -0,0 +1,5 @@
+pub mod drag;
+pub mod gizmo;
+
+pub use drag::*;
+pub use gizmo::*;
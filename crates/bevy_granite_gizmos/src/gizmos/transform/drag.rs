@@ -1,19 +1,25 @@
-use super::TransformGizmo;
+use super::{DragSnapInput, TransformGizmo};
 use crate::{
-    gizmos::{GizmoOf, GizmoSnap},
+    gizmos::{pivot::selection_roots, GizmoOf, GizmoOrientation, GizmoRoot},
     input::GizmoAxis,
-    selection::{ActiveSelection, Selected, RequestDuplicateAllSelectionEvent},
+    selection::{ActiveSelection, RequestDuplicateAllSelectionEvent, Selected},
     GizmoCamera,
 };
 use bevy::{
     asset::Assets,
     ecs::{
-        component::Component, event::EventWriter, hierarchy::ChildOf, observer::Trigger,
-        system::Commands,
+        component::Component,
+        event::EventWriter,
+        hierarchy::ChildOf,
+        observer::Trigger,
+        system::{Commands, SystemParam},
     },
     gizmos::{retained::Gizmo, GizmoAsset},
+    math::primitives::InfinitePlane3d,
     picking::events::{Drag, DragStart, Pointer, Pressed},
-    prelude::{Entity, GlobalTransform, Query, Res, ResMut, Resource, Transform, Vec3, With, Without},
+    prelude::{
+        Entity, GlobalTransform, Quat, Query, Res, ResMut, Resource, Transform, Vec3, With, Without,
+    },
 };
 use bevy_granite_core::UserInput;
 use bevy_granite_logging::{
@@ -26,6 +32,13 @@ pub struct TransformDuplicationState {
     pub just_duplicated: bool,
 }
 
+/// Offset from the gizmo origin to where the handle was grabbed
+/// Plane, screen and local axis drags keep it so the entity doesn't jump onto the cursor
+#[derive(Resource, Default)]
+pub struct TransformGrabState {
+    pub offset: Vec3,
+}
+
 pub fn drag_transform_gizmo(
     event: Trigger<Pointer<Drag>>,
     targets: Query<&GizmoOf>,
@@ -38,20 +51,24 @@ pub fn drag_transform_gizmo(
     parents: Query<&ChildOf>,
     active_selection: Query<Entity, With<ActiveSelection>>,
     other_selected: Query<Entity, (With<Selected>, Without<ActiveSelection>)>,
-    gizmo_snap: Res<GizmoSnap>,
-    gizmo_data: Query<(&GizmoAxis, &TransformGizmo)>,
-    user_input: Res<UserInput>,
-    mut duplication_state: ResMut<TransformDuplicationState>,
+    gizmo_data: Query<(&GizmoAxis, &TransformGizmo, Option<&GizmoRoot>)>,
+    orientation: GizmoOrientation,
+    grab_state: Res<TransformGrabState>,
+    mut input: DragSnapInput,
 ) {
     if event.button != bevy::picking::pointer::PointerButton::Primary {
         return;
     }
-    
-    if duplication_state.just_duplicated {
-        duplication_state.just_duplicated = false;
+    // snap_drag_transform_gizmo handles the drag instead
+    if input.mode().is_some() {
         return;
     }
-    let Ok((axis, typ)) = gizmo_data.get(event.target) else {
+
+    if input.duplication_state.just_duplicated {
+        input.duplication_state.just_duplicated = false;
+        return;
+    }
+    let Ok((axis, typ, root)) = gizmo_data.get(event.target) else {
         log!(
             LogType::Editor,
             LogLevel::Warning,
@@ -98,19 +115,8 @@ pub fn drag_transform_gizmo(
     all_selected_entities.extend(active_selection.iter());
     all_selected_entities.extend(other_selected.iter());
 
-    // Filter out entities that are children of other selected entities
-    let mut root_entities = Vec::new();
-    for &entity in &all_selected_entities {
-        let mut is_child_of_selected = false;
-        if let Ok(parent) = parents.get(entity) {
-            if all_selected_entities.contains(&parent.parent()) {
-                is_child_of_selected = true;
-            }
-        }
-        if !is_child_of_selected {
-            root_entities.push(entity);
-        }
-    }
+    // Filter out entities that are below other selected entities
+    let root_entities = selection_roots(&all_selected_entities, &parents);
 
     if root_entities.is_empty() {
         log! {
@@ -134,157 +140,234 @@ pub fn drag_transform_gizmo(
             return;
         };
 
-        if let Ok(global_transform) = global_transforms.get(*target) {
+        // The gizmo sits on the pivot, which isn't always the target's origin
+        let gizmo_global = match root {
+            Some(GizmoRoot(root)) => global_transforms.get(*root),
+            None => global_transforms.get(*target),
+        };
+        if let Ok(global_transform) = gizmo_global {
             global_transform.translation()
         } else {
             target_transform.translation
         }
     };
 
-    let world_delta = match (axis, typ) {
-        (GizmoAxis::None, _) => Vec3::ZERO,
-        (GizmoAxis::X, TransformGizmo::Axis) => {
-            let Some(click_distance) = click_ray.intersect_plane(
-                Vec3::new(0., current_world_pos.y, 0.),
-                bevy::math::primitives::InfinitePlane3d::new(Vec3::Y),
-            ) else {
-                return;
-            };
-            let hit = camera_transform.translation() + (click_ray.direction * click_distance);
-            let raw_delta_x = hit.x - current_world_pos.x;
-            let delta_x = snap_gizmo(raw_delta_x, gizmo_snap.transform_value);
-            Vec3::new(delta_x, 0.0, 0.0)
-        }
-        (GizmoAxis::Y, TransformGizmo::Axis) => {
-            let mut normal = camera_transform.forward().as_vec3();
-            normal.y = 0.0;
-            let Some(click_distance) = click_ray.intersect_plane(
-                Vec3::new(current_world_pos.x, 0., current_world_pos.z),
-                bevy::math::primitives::InfinitePlane3d::new(normal.normalize()),
-            ) else {
-                return;
-            };
-            let hit = camera_transform.translation() - (click_ray.direction * -click_distance);
-            let raw_delta_y = hit.y - current_world_pos.y;
-            let delta_y = snap_gizmo(raw_delta_y, gizmo_snap.transform_value);
-            Vec3::new(0.0, delta_y, 0.0)
-        }
-        (GizmoAxis::Z, TransformGizmo::Axis) => {
-            let Some(click_distance) = click_ray.intersect_plane(
-                Vec3::new(0., current_world_pos.y, 0.),
-                bevy::math::primitives::InfinitePlane3d::new(Vec3::Y),
-            ) else {
-                return;
-            };
-            let hit = camera_transform.translation() - (click_ray.direction * -click_distance);
-            let raw_delta_z = hit.z - current_world_pos.z;
-            let delta_z = snap_gizmo(raw_delta_z, gizmo_snap.transform_value);
-            Vec3::new(0.0, 0.0, delta_z)
-        }
-        (GizmoAxis::X, TransformGizmo::Plane) => {
-            let Some(click_distance) = click_ray.intersect_plane(
-                Vec3::new(current_world_pos.x, 0., 0.),
-                bevy::math::primitives::InfinitePlane3d::new(Vec3::X),
-            ) else {
-                return;
-            };
-            let hit = camera_transform.translation() - (click_ray.direction * -click_distance);
-            let raw_delta_y = hit.y - current_world_pos.y;
-            let raw_delta_z = hit.z - current_world_pos.z;
-            let delta_y = snap_gizmo(raw_delta_y, gizmo_snap.transform_value);
-            let delta_z = snap_gizmo(raw_delta_z, gizmo_snap.transform_value);
-            Vec3::new(0.0, delta_y, delta_z)
-        }
-        (GizmoAxis::Y, TransformGizmo::Plane) => {
-            let Some(click_distance) = click_ray.intersect_plane(
-                Vec3::new(0., current_world_pos.y, 0.),
-                bevy::math::primitives::InfinitePlane3d::new(Vec3::Y),
-            ) else {
-                return;
-            };
-            let hit = camera_transform.translation() - (click_ray.direction * -click_distance);
-            let raw_delta_x = hit.x - current_world_pos.x;
-            let raw_delta_z = hit.z - current_world_pos.z;
-            let delta_x = snap_gizmo(raw_delta_x, gizmo_snap.transform_value);
-            let delta_z = snap_gizmo(raw_delta_z, gizmo_snap.transform_value);
-            Vec3::new(delta_x, 0.0, delta_z)
-        }
-        (GizmoAxis::Z, TransformGizmo::Plane) => {
-            let Some(click_distance) = click_ray.intersect_plane(
-                Vec3::new(0., 0., current_world_pos.z),
-                bevy::math::primitives::InfinitePlane3d::new(Vec3::Z),
-            ) else {
-                return;
-            };
-            let hit = camera_transform.translation() - (click_ray.direction * -click_distance);
-            let raw_delta_x = hit.x - current_world_pos.x;
-            let raw_delta_y = hit.y - current_world_pos.y;
-            let delta_x = snap_gizmo(raw_delta_x, gizmo_snap.transform_value);
-            let delta_y = snap_gizmo(raw_delta_y, gizmo_snap.transform_value);
-            Vec3::new(delta_x, delta_y, 0.0)
-        }
-        (GizmoAxis::All, _) => {
-            let camera_right = camera_transform.rotation() * Vec3::X;
-            let camera_up = camera_transform.rotation() * Vec3::Y;
-            let movement_scale = 0.005;
-            let world_delta =
-                (camera_right * event.delta.x + camera_up * -event.delta.y) * movement_scale;
-            Vec3::new(
-                snap_gizmo(world_delta.x, gizmo_snap.transform_value),
-                snap_gizmo(world_delta.y, gizmo_snap.transform_value),
-                snap_gizmo(world_delta.z, gizmo_snap.transform_value),
-            )
-        }
+    let mode = orientation.mode(root);
+    let rotation = orientation.rotation(mode, *target);
+    // The old world axis maths below only holds while the axes are the world's
+    let uses_drag_plane = match (axis, typ) {
+        (GizmoAxis::All | GizmoAxis::None, TransformGizmo::Axis) => false,
+        (_, TransformGizmo::Axis) => rotation != Quat::IDENTITY,
+        (_, TransformGizmo::Plane | TransformGizmo::Screen) => true,
     };
 
-    // Apply the delta to all root selected entities
-    for &entity in &root_entities {
-        if let Ok(mut entity_transform) = objects.get_mut(entity) {
-            if let Ok(parent) = parents.get(entity) {
-                if let Ok(parent_global) = global_transforms.get(parent.parent()) {
-                    let parent_rotation_inv =
-                        parent_global.to_scale_rotation_translation().1.inverse();
-                    let parent_local_delta = parent_rotation_inv * world_delta;
-                    entity_transform.translation += parent_local_delta;
-                } else {
-                    entity_transform.translation += world_delta;
-                }
-            } else {
-                entity_transform.translation += world_delta;
+    let world_delta = if uses_drag_plane {
+        let Some((normal, directions)) =
+            drag_plane(*axis, typ, rotation, camera_transform.forward().as_vec3())
+        else {
+            return;
+        };
+        let Some(distance) =
+            click_ray.intersect_plane(current_world_pos, InfinitePlane3d::new(normal))
+        else {
+            return;
+        };
+        let raw_delta = click_ray.get_point(distance) - grab_state.offset - current_world_pos;
+        directions
+            .iter()
+            .map(|direction| {
+                *direction * snap_gizmo(raw_delta.dot(*direction), input.gizmo_snap.transform_value)
+            })
+            .sum::<Vec3>()
+    } else {
+        match (axis, typ) {
+            (GizmoAxis::None, _) => Vec3::ZERO,
+            (GizmoAxis::X, TransformGizmo::Axis) => {
+                let Some(click_distance) = click_ray.intersect_plane(
+                    Vec3::new(0., current_world_pos.y, 0.),
+                    bevy::math::primitives::InfinitePlane3d::new(Vec3::Y),
+                ) else {
+                    return;
+                };
+                let hit = camera_transform.translation() + (click_ray.direction * click_distance);
+                let raw_delta_x = hit.x - current_world_pos.x;
+                let delta_x = snap_gizmo(raw_delta_x, input.gizmo_snap.transform_value);
+                Vec3::new(delta_x, 0.0, 0.0)
+            }
+            (GizmoAxis::Y, TransformGizmo::Axis) => {
+                let mut normal = camera_transform.forward().as_vec3();
+                normal.y = 0.0;
+                let Some(click_distance) = click_ray.intersect_plane(
+                    Vec3::new(current_world_pos.x, 0., current_world_pos.z),
+                    bevy::math::primitives::InfinitePlane3d::new(normal.normalize()),
+                ) else {
+                    return;
+                };
+                let hit = camera_transform.translation() - (click_ray.direction * -click_distance);
+                let raw_delta_y = hit.y - current_world_pos.y;
+                let delta_y = snap_gizmo(raw_delta_y, input.gizmo_snap.transform_value);
+                Vec3::new(0.0, delta_y, 0.0)
+            }
+            (GizmoAxis::Z, TransformGizmo::Axis) => {
+                let Some(click_distance) = click_ray.intersect_plane(
+                    Vec3::new(0., current_world_pos.y, 0.),
+                    bevy::math::primitives::InfinitePlane3d::new(Vec3::Y),
+                ) else {
+                    return;
+                };
+                let hit = camera_transform.translation() - (click_ray.direction * -click_distance);
+                let raw_delta_z = hit.z - current_world_pos.z;
+                let delta_z = snap_gizmo(raw_delta_z, input.gizmo_snap.transform_value);
+                Vec3::new(0.0, 0.0, delta_z)
+            }
+            (GizmoAxis::All, _) => {
+                let camera_right = camera_transform.rotation() * Vec3::X;
+                let camera_up = camera_transform.rotation() * Vec3::Y;
+                let movement_scale = 0.005;
+                let world_delta =
+                    (camera_right * event.delta.x + camera_up * -event.delta.y) * movement_scale;
+                Vec3::new(
+                    snap_gizmo(world_delta.x, input.gizmo_snap.transform_value),
+                    snap_gizmo(world_delta.y, input.gizmo_snap.transform_value),
+                    snap_gizmo(world_delta.z, input.gizmo_snap.transform_value),
+                )
             }
+            // Plane and screen handles always go through the drag plane above
+            (_, TransformGizmo::Plane | TransformGizmo::Screen) => Vec3::ZERO,
         }
-    }
-    
-    if user_input.ctrl_left.any {
+    };
+
+    // Apply the delta to all root selected entities
+    apply_world_delta(
+        &root_entities,
+        world_delta,
+        &mut objects,
+        &parents,
+        &global_transforms,
+    );
+
+    if input.user_input.ctrl_left.any {
         if let Ok(mut camera_transform) = objects.get_mut(c_entity) {
             camera_transform.translation += world_delta;
         }
     }
 }
 
+/// Gizmo orientation and camera lookups for resolving where a handle was picked up
+#[derive(SystemParam)]
+pub struct TransformGrabContext<'w, 's> {
+    pub orientation: GizmoOrientation<'w, 's>,
+    pub camera_query: Query<
+        'w,
+        's,
+        (
+            &'static GlobalTransform,
+            &'static bevy::render::camera::Camera,
+        ),
+        With<GizmoCamera>,
+    >,
+    pub global_transforms: Query<'w, 's, &'static GlobalTransform>,
+}
+
 pub fn dragstart_transform_gizmo(
     event: Trigger<Pointer<DragStart>>,
-    targets: Query<&GizmoOf>,
-    gizmo_data: Query<(&GizmoAxis, &TransformGizmo)>,
+    gizmo_data: Query<(&GizmoAxis, &TransformGizmo, &GizmoOf, Option<&GizmoRoot>)>,
+    context: TransformGrabContext,
     user_input: Res<UserInput>,
+    mut grab_state: ResMut<TransformGrabState>,
     mut dispatch: EventWriter<RequestDuplicateAllSelectionEvent>,
     mut duplication_state: ResMut<TransformDuplicationState>,
 ) {
-    if user_input.mouse_middle.any || !user_input.shift_left.pressed {
-        return;
-    }
-    let Ok(_) = gizmo_data.get(event.target) else {
+    let Ok((axis, typ, GizmoOf(target), root)) = gizmo_data.get(event.target) else {
         return;
     };
-    let Ok(GizmoOf(_target)) = targets.get(event.target) else {
+
+    // Remember where on the drag plane the handle was picked up
+    grab_state.offset = Vec3::ZERO;
+    if let (Ok((camera_transform, camera)), Ok(target_global)) = (
+        context.camera_query.single(),
+        context.global_transforms.get(*target),
+    ) {
+        let orientation = &context.orientation;
+        let rotation = orientation.rotation(orientation.mode(root), *target);
+        let origin = root
+            .and_then(|GizmoRoot(root)| context.global_transforms.get(*root).ok())
+            .map_or(target_global.translation(), |root_global| {
+                root_global.translation()
+            });
+        if let (Ok(ray), Some((normal, _))) = (
+            camera.viewport_to_world(camera_transform, event.pointer_location.position),
+            drag_plane(*axis, typ, rotation, camera_transform.forward().as_vec3()),
+        ) {
+            if let Some(distance) = ray.intersect_plane(origin, InfinitePlane3d::new(normal)) {
+                grab_state.offset = ray.get_point(distance) - origin;
+            }
+        }
+    }
+
+    if user_input.mouse_middle.any || !user_input.shift_left.pressed {
         return;
-    };
+    }
     log!("Attempting Drag Duplicate");
     dispatch.write(RequestDuplicateAllSelectionEvent);
     duplication_state.just_duplicated = true;
 }
 
-fn snap_gizmo(value: f32, inc: f32) -> f32 {
+/// Plane the pointer is projected onto for a handle, and the world directions it may move along
+/// Axis handles use the plane through their axis that faces the camera the most
+fn drag_plane(
+    axis: GizmoAxis,
+    typ: &TransformGizmo,
+    rotation: Quat,
+    camera_forward: Vec3,
+) -> Option<(Vec3, Vec<Vec3>)> {
+    match (axis, typ) {
+        (_, TransformGizmo::Screen) => Some((camera_forward, vec![Vec3::X, Vec3::Y, Vec3::Z])),
+        (GizmoAxis::All | GizmoAxis::None, _) => None,
+        (_, TransformGizmo::Plane) => {
+            let (a, b) = axis.plane();
+            Some((
+                rotation * axis.to_vec3(),
+                vec![rotation * a.to_vec3(), rotation * b.to_vec3()],
+            ))
+        }
+        (_, TransformGizmo::Axis) => {
+            let direction = rotation * axis.to_vec3();
+            let normal = direction.cross(camera_forward).cross(direction);
+            (normal.length_squared() > 1e-6).then(|| (normal.normalize(), vec![direction]))
+        }
+    }
+}
+
+/// Moves each entity by a world space delta, whatever its parent
+pub fn apply_world_delta(
+    entities: &[Entity],
+    world_delta: Vec3,
+    objects: &mut Query<&mut Transform>,
+    parents: &Query<&ChildOf>,
+    global_transforms: &Query<&GlobalTransform>,
+) {
+    for &entity in entities {
+        if let Ok(mut entity_transform) = objects.get_mut(entity) {
+            if let Ok(parent) = parents.get(entity) {
+                if let Ok(parent_global) = global_transforms.get(parent.parent()) {
+                    // Through the parent's rotation and scale, so the entity moves exactly world_delta
+                    let parent_local_delta = parent_global
+                        .affine()
+                        .inverse()
+                        .transform_vector3(world_delta);
+                    entity_transform.translation += parent_local_delta;
+                } else {
+                    entity_transform.translation += world_delta;
+                }
+            } else {
+                entity_transform.translation += world_delta;
+            }
+        }
+    }
+}
+
+pub(crate) fn snap_gizmo(value: f32, inc: f32) -> f32 {
     if inc == 0.0 {
         value
     } else {
@@ -294,7 +377,11 @@ fn snap_gizmo(value: f32, inc: f32) -> f32 {
 
 pub fn draw_axis_lines(
     event: Trigger<Pointer<Pressed>>,
-    gizmo_data: Query<(&GizmoAxis, &GizmoOf, &TransformGizmo), With<TransformGizmo>>,
+    gizmo_data: Query<
+        (&GizmoAxis, &GizmoOf, &TransformGizmo, Option<&GizmoRoot>),
+        With<TransformGizmo>,
+    >,
+    orientation: GizmoOrientation,
     mut bevy_gizmo: ResMut<Assets<GizmoAsset>>,
     mut commands: Commands,
     origin: Query<&GlobalTransform>,
@@ -302,13 +389,16 @@ pub fn draw_axis_lines(
     if event.button != bevy::picking::pointer::PointerButton::Primary {
         return;
     }
-    
-    let Ok((axis, root, transform)) = gizmo_data.get(event.target) else {
+
+    let Ok((axis, root, transform, gizmo_root)) = gizmo_data.get(event.target) else {
         return;
     };
     if let GizmoAxis::All = axis {
         return;
     }
+    let gizmo_position = gizmo_root
+        .and_then(|GizmoRoot(gizmo_root)| origin.get(*gizmo_root).ok())
+        .map(|global| global.translation());
     let Ok(origin) = origin.get(root.get()) else {
         log! {
             LogType::Editor,
@@ -319,28 +409,31 @@ pub fn draw_axis_lines(
         };
         return;
     };
+    let rotation = orientation.rotation(orientation.mode(gizmo_root), root.get());
+    let center = gizmo_position.unwrap_or(origin.translation());
     let mut asset = GizmoAsset::new();
     match transform {
         TransformGizmo::Axis => {
             asset.line(
-                origin.translation() + axis.to_vec3() * 1000.,
-                origin.translation() + axis.to_vec3() * -1000.,
+                center + rotation * axis.to_vec3() * 1000.,
+                center + rotation * axis.to_vec3() * -1000.,
                 axis.color(),
             );
         }
         TransformGizmo::Plane => {
             let (a, b) = axis.plane();
             asset.line(
-                origin.translation() + a.to_vec3() * 1000.,
-                origin.translation() + a.to_vec3() * -1000.,
+                center + rotation * a.to_vec3() * 1000.,
+                center + rotation * a.to_vec3() * -1000.,
                 a.color(),
             );
             asset.line(
-                origin.translation() + b.to_vec3() * 1000.,
-                origin.translation() + b.to_vec3() * -1000.,
+                center + rotation * b.to_vec3() * 1000.,
+                center + rotation * b.to_vec3() * -1000.,
                 b.color(),
             );
         }
+        TransformGizmo::Screen => return,
     }
 
     commands.spawn((
@@ -1,10 +1,10 @@
 use bevy::{
-    ecs::hierarchy::{ChildOf, Children},
+    ecs::hierarchy::ChildOf,
     pbr::{MeshMaterial3d, NotShadowCaster, NotShadowReceiver},
     prelude::{
-        AlphaMode, Assets, Color, Commands, Component, Cone, Cylinder, Entity, GlobalTransform,
-        Mesh, Meshable, Name, Quat, Query, ResMut, Resource, Sphere, StandardMaterial, Transform,
-        Vec3, Visibility, Without,
+        AlphaMode, Assets, Color, Commands, Component, Cone, Cuboid, Cylinder, Entity,
+        GlobalTransform, Mesh, Meshable, Name, Quat, Query, ResMut, Resource, Sphere,
+        StandardMaterial, Torus, Transform, Vec3, Visibility, With, Without,
     },
     render::mesh::Mesh3d,
 };
@@ -13,16 +13,21 @@ use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
     log,
 };
+use std::f32::consts::FRAC_PI_2;
 
 use crate::{
-    gizmos::{GizmoConfig, GizmoMesh, GizmoOf, GizmoRoot},
+    gizmos::{GizmoConfig, GizmoMesh, GizmoMode, GizmoOf, GizmoRoot},
     input::GizmoAxis,
+    GizmoCamera,
 };
 
 #[derive(Component)]
 pub enum TransformGizmo {
     Axis,
+    /// Moves within the plane whose normal is the handle's GizmoAxis
     Plane,
+    /// Moves within the plane facing the camera
+    Screen,
 }
 
 #[derive(Resource, Default, Component)]
@@ -40,6 +45,9 @@ const TRANSFORM_LINE_LENGTH: f32 = 0.6 * GIZMO_SCALE; // length of line
 const TRANSFORM_LINE_WIDTH: f32 = 0.04 * GIZMO_SCALE; // width of line
 const TRANSFORM_HANDLE_LENGTH: f32 = 0.22 * GIZMO_SCALE; // cone handle length
 const TRANSFORM_HANDLE_WIDTH: f32 = 0.09 * GIZMO_SCALE; // cone handle width
+const TRANSFORM_PLANE_SIZE: f32 = 0.18 * GIZMO_SCALE; // square plane handle between two axes
+const TRANSFORM_PLANE_OFFSET: f32 = 0.2 * GIZMO_SCALE; // distance of the plane handle from each axis
+const TRANSFORM_SCREEN_RADIUS: f32 = 0.17 * GIZMO_SCALE; // ring around the middle sphere
 
 pub fn spawn_transform_gizmo(
     parent: Entity,
@@ -54,14 +62,20 @@ pub fn spawn_transform_gizmo(
     if let Ok(parent_global_transform) = query.get(parent) {
         let gizmo_translation = offset;
 
+        // Local mode follows the entity, the rest start on world axes until align_gizmo_to_mode_system runs
+        let rotation = match config.mode() {
+            GizmoMode::Local => Quat::IDENTITY,
+            _ => parent_global_transform
+                .to_scale_rotation_translation()
+                .1
+                .inverse(),
+        };
+
         let gizmo_entity = commands
             .spawn((
                 Transform {
                     translation: gizmo_translation,
-                    rotation: parent_global_transform
-                        .to_scale_rotation_translation()
-                        .1
-                        .inverse(),
+                    rotation,
                     ..Default::default()
                 },
                 Visibility::default(),
@@ -111,6 +125,31 @@ pub fn spawn_transform_gizmo(
             GizmoAxis::Z,
             Color::srgba(0., 0., 1., 1.),
         );
+
+        for (axis, color) in [
+            (GizmoAxis::X, Color::srgba(1., 0., 0., 0.5)),
+            (GizmoAxis::Y, Color::srgba(0., 1., 0., 0.5)),
+            (GizmoAxis::Z, Color::srgba(0., 0., 1., 0.5)),
+        ] {
+            build_plane_handle(
+                parent,
+                commands,
+                meshes,
+                materials,
+                gizmo_entity,
+                axis,
+                color,
+            );
+        }
+
+        build_screen_handle(
+            parent,
+            commands,
+            meshes,
+            materials,
+            gizmo_entity,
+            Color::srgba(0.9, 0.9, 0.9, 1.),
+        );
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -161,6 +200,7 @@ fn build_gizmo_sphere(
             ChildOf(parent),
         ))
         .observe(super::drag::drag_transform_gizmo)
+        .observe(super::snap::snap_drag_transform_gizmo)
         .observe(super::drag::dragstart_transform_gizmo);
 }
 
@@ -183,12 +223,6 @@ fn build_axis_cylinder(
         height: TRANSFORM_HANDLE_LENGTH,
     }));
 
-    let plane_mesh = meshes.add(Mesh::from(bevy::prelude::Cuboid::new(
-        TRANSFORM_LINE_LENGTH * 0.33,
-        TRANSFORM_LINE_WIDTH,
-        TRANSFORM_LINE_LENGTH * 0.33,
-    )));
-
     let material = materials.add(StandardMaterial {
         base_color: color,
         unlit: true,
@@ -216,6 +250,7 @@ fn build_axis_cylinder(
         .insert(GizmoMesh)
         .insert(ChildOf(parent))
         .observe(super::drag::drag_transform_gizmo)
+        .observe(super::snap::snap_drag_transform_gizmo)
         .observe(super::drag::dragstart_transform_gizmo)
         .with_child((
             Mesh3d(cone_mesh),
@@ -232,30 +267,122 @@ fn build_axis_cylinder(
             axis,
             TransformGizmo::Axis,
             GizmoMesh,
-        ))
-        .with_child((
+        ));
+}
+
+// Square between the two other axes, so the X handle moves in YZ
+fn build_plane_handle(
+    root: Entity,
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    parent: Entity,
+    normal: GizmoAxis,
+    color: Color,
+) {
+    let (a, b) = normal.plane();
+    let size = (Vec3::ONE - normal.to_vec3()) * TRANSFORM_PLANE_SIZE
+        + normal.to_vec3() * TRANSFORM_LINE_WIDTH * 0.5;
+    let plane_mesh = meshes.add(Cuboid::from_size(size));
+
+    let material = materials.add(StandardMaterial {
+        base_color: color,
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        double_sided: true,
+        cull_mode: None,
+        ..Default::default()
+    });
+
+    commands
+        .spawn((
             Mesh3d(plane_mesh),
-            MeshMaterial3d(material.clone()),
-            Transform {
-                translation: plane_translation(axis),
-                ..Default::default()
-            },
+            MeshMaterial3d(material),
+            Transform::from_translation((a.to_vec3() + b.to_vec3()) * TRANSFORM_PLANE_OFFSET),
             NotShadowCaster,
             NotShadowReceiver,
-            Name::new("Gizmo Transform Arrow"),
+            Name::new("Gizmo Transform Plane"),
+            normal,
+            TransformGizmo::Plane,
+            GizmoMesh,
             GizmoOf(root),
             GizmoRoot(parent),
-            axis,
-            TransformGizmo::Plane,
+            ChildOf(parent),
+        ))
+        .observe(super::drag::drag_transform_gizmo)
+        .observe(super::snap::snap_drag_transform_gizmo)
+        .observe(super::drag::dragstart_transform_gizmo);
+}
+
+// Turned to face the camera every frame by face_screen_handle_system
+fn build_screen_handle(
+    root: Entity,
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    parent: Entity,
+    color: Color,
+) {
+    let ring_mesh = meshes.add(Torus::new(
+        TRANSFORM_SCREEN_RADIUS - TRANSFORM_LINE_WIDTH * 0.5,
+        TRANSFORM_SCREEN_RADIUS + TRANSFORM_LINE_WIDTH * 0.5,
+    ));
+
+    let material = materials.add(StandardMaterial {
+        base_color: color,
+        unlit: true,
+        alpha_mode: AlphaMode::AlphaToCoverage,
+        ..Default::default()
+    });
+
+    commands
+        .spawn((
+            Mesh3d(ring_mesh),
+            MeshMaterial3d(material),
+            Transform::default(),
+            NotShadowCaster,
+            NotShadowReceiver,
+            Name::new("Gizmo Transform Screen Plane"),
+            GizmoAxis::All,
+            TransformGizmo::Screen,
             GizmoMesh,
-        ));
+            GizmoOf(root),
+            GizmoRoot(parent),
+            ChildOf(parent),
+        ))
+        .observe(super::drag::drag_transform_gizmo)
+        .observe(super::snap::snap_drag_transform_gizmo)
+        .observe(super::drag::dragstart_transform_gizmo);
+}
+
+/// Keeps the screen plane ring facing the gizmo camera
+pub fn face_screen_handle_system(
+    camera_query: Query<&GlobalTransform, With<GizmoCamera>>,
+    root_query: Query<&GlobalTransform, With<TransformGizmoParent>>,
+    mut handle_query: Query<(&mut Transform, &GizmoRoot, &TransformGizmo)>,
+) {
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+    // The torus lies flat in XZ, tip its Y axis onto the view direction
+    let facing = camera_transform.rotation() * Quat::from_rotation_x(FRAC_PI_2);
+    for (mut transform, GizmoRoot(root), gizmo) in handle_query.iter_mut() {
+        if !matches!(gizmo, TransformGizmo::Screen) {
+            continue;
+        }
+        let Ok(root_transform) = root_query.get(*root) else {
+            continue;
+        };
+        transform.rotation = root_transform.rotation().inverse() * facing;
+    }
 }
 
+// Despawning the root takes every handle with it, the center and plane handles have no children of their own
 pub fn despawn_transform_gizmo(
     commands: &mut Commands,
-    query: &mut Query<(Entity, &TransformGizmo, &Children)>,
+    query: &mut Query<Entity, With<TransformGizmoParent>>,
 ) {
-    for (entity, _, _) in query.iter() {
+    for entity in query.iter() {
         commands.entity(entity).try_despawn();
         log!(
             LogType::Editor,
@@ -265,24 +392,3 @@ pub fn despawn_transform_gizmo(
         );
     }
 }
-
-fn plane_translation(axis: GizmoAxis) -> Vec3 {
-    match axis {
-        GizmoAxis::X => Vec3::new(
-            -TRANSFORM_LINE_LENGTH * 0.33,
-            -TRANSFORM_LINE_LENGTH * 0.5,
-            TRANSFORM_LINE_LENGTH * 0.33,
-        ),
-        GizmoAxis::Y => Vec3::new(
-            TRANSFORM_LINE_LENGTH * 0.33,
-            -TRANSFORM_LINE_LENGTH * 0.5,
-            TRANSFORM_LINE_LENGTH * 0.33,
-        ),
-        GizmoAxis::Z => Vec3::new(
-            TRANSFORM_LINE_LENGTH * 0.33,
-            -TRANSFORM_LINE_LENGTH * 0.5,
-            -TRANSFORM_LINE_LENGTH * 0.33,
-        ),
-        _ => Vec3::ZERO,
-    }
-}
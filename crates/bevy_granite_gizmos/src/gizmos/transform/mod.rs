@@ -1,5 +1,7 @@
 pub mod gizmo;
 pub mod drag;
+pub mod snap;
 
 pub use gizmo::*;
-pub use drag::*;
\ No newline at end of file
+pub use drag::*;
+pub use snap::*;
\ No newline at end of file
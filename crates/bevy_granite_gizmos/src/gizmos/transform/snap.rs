@@ -0,0 +1,237 @@
+use super::{apply_world_delta, TransformDuplicationState, TransformGizmo};
+use crate::{
+    gizmos::{
+        pivot::{rotate_about, PivotSelection},
+        GizmoMesh, GizmoOf, GizmoRoot, GizmoSnap,
+    },
+    input::{GizmoAction, GizmoKeys},
+    GizmoCamera,
+};
+use bevy::{
+    asset::Assets,
+    ecs::{
+        entity::Entity,
+        hierarchy::Children,
+        observer::Trigger,
+        query::{With, Without},
+        system::{Query, Res, ResMut, SystemParam},
+    },
+    math::{Quat, Vec3},
+    picking::{
+        events::{Drag, Pointer},
+        mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings},
+        pointer::PointerButton,
+    },
+    render::{camera::Camera, mesh::Mesh, mesh::Mesh3d},
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_granite_core::{entities::RectBrushFaceMesh, EditorIgnore, IconProxy, UserInput};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+
+/// Held while dragging a move handle to place the selection against other meshes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DragSnapMode {
+    /// Alt, the origin follows the surface under the cursor
+    Surface,
+    /// V by default, the selection's closest vertex lands on the vertex under the cursor
+    Vertex,
+}
+
+/// Camera ray and scene meshes a snapping drag can land on
+#[derive(SystemParam)]
+pub struct SnapTargets<'w, 's> {
+    pub camera_query: Query<'w, 's, (&'static GlobalTransform, &'static Camera), With<GizmoCamera>>,
+    pub ray_cast: MeshRayCast<'w, 's>,
+    pub meshes: Res<'w, Assets<Mesh>>,
+    pub mesh_query: Query<'w, 's, (&'static Mesh3d, &'static GlobalTransform)>,
+    pub children: Query<'w, 's, &'static Children>,
+    pub ignored: Query<'w, 's, &'static EditorIgnore>,
+    // Brush face meshes are IconProxy too, but they are real geometry
+    pub icons: Query<'w, 's, (), (With<IconProxy>, Without<RectBrushFaceMesh>)>,
+    pub gizmo_meshes: Query<'w, 's, (), With<GizmoMesh>>,
+}
+
+/// Modifier keys and settings that decide how a snapping drag behaves
+#[derive(SystemParam)]
+pub struct DragSnapInput<'w> {
+    pub user_input: Res<'w, UserInput>,
+    pub keys: GizmoKeys<'w>,
+    pub gizmo_snap: Res<'w, GizmoSnap>,
+    pub duplication_state: ResMut<'w, TransformDuplicationState>,
+}
+
+impl DragSnapInput<'_> {
+    /// Which snap modifier is held, the vertex snap key follows the gizmo keybindings
+    pub fn mode(&self) -> Option<DragSnapMode> {
+        if self.keys.pressed(GizmoAction::VertexSnap) {
+            Some(DragSnapMode::Vertex)
+        } else if self.user_input.alt_left.pressed {
+            Some(DragSnapMode::Surface)
+        } else {
+            None
+        }
+    }
+}
+
+/// Runs alongside drag_transform_gizmo, which stands aside while a snap modifier is held
+pub fn snap_drag_transform_gizmo(
+    event: Trigger<Pointer<Drag>>,
+    mut objects: Query<&mut Transform>,
+    global_transforms: Query<&GlobalTransform>,
+    selection: PivotSelection,
+    gizmo_data: Query<(&GizmoOf, Option<&GizmoRoot>), With<TransformGizmo>>,
+    mut targets: SnapTargets,
+    mut input: DragSnapInput,
+) {
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    let Some(snap_mode) = input.mode() else {
+        return;
+    };
+    if input.duplication_state.just_duplicated {
+        input.duplication_state.just_duplicated = false;
+        return;
+    }
+    let Ok((GizmoOf(target), root)) = gizmo_data.get(event.target) else {
+        return;
+    };
+    let Ok((camera_transform, camera)) = targets.camera_query.single() else {
+        log! {
+            LogType::Editor,
+            LogLevel::Error,
+            LogCategory::Input,
+            "Gizmo camera not found",
+        };
+        return;
+    };
+    let Ok(click_ray) = camera.viewport_to_world(camera_transform, event.pointer_location.position)
+    else {
+        return;
+    };
+
+    let root_entities = selection.roots();
+    if root_entities.is_empty() {
+        return;
+    }
+
+    // The selection and everything under it can't be snapped onto
+    let mut moving = root_entities.clone();
+    for &entity in &root_entities {
+        moving.extend(targets.children.iter_descendants(entity));
+    }
+    // Gizmos and icons hang off the selection but aren't part of its shape
+    let helper = |entity: Entity| {
+        targets.gizmo_meshes.contains(entity)
+            || targets.icons.contains(entity)
+            || targets
+                .ignored
+                .get(entity)
+                .is_ok_and(|ignore| ignore.intersects(EditorIgnore::GIZMO | EditorIgnore::PICKING))
+    };
+
+    let filter = |entity: Entity| {
+        !moving.contains(&entity)
+            && !targets.icons.contains(entity)
+            && targets
+                .ignored
+                .get(entity)
+                .map_or(true, |ignore| !ignore.contains(EditorIgnore::PICKING))
+    };
+    let settings = MeshRayCastSettings::default().with_filter(&filter);
+    let Some((hit_entity, hit)) = targets
+        .ray_cast
+        .cast_ray(click_ray, &settings)
+        .first()
+        .map(|(entity, hit)| (*entity, hit.clone()))
+    else {
+        return;
+    };
+
+    let gizmo_position = root
+        .and_then(|GizmoRoot(root)| global_transforms.get(*root).ok())
+        .or_else(|| global_transforms.get(*target).ok())
+        .map(|global| global.translation());
+    let Some(gizmo_position) = gizmo_position else {
+        return;
+    };
+
+    let world_delta = match snap_mode {
+        DragSnapMode::Surface => hit.point - gizmo_position,
+        DragSnapMode::Vertex => {
+            let Some(target_vertex) = closest_vertex(
+                std::iter::once(hit_entity),
+                hit.point,
+                &targets.meshes,
+                &targets.mesh_query,
+            ) else {
+                return;
+            };
+            // Selections without a mesh, like lights, snap their gizmo instead
+            let sources = moving.iter().copied().filter(|entity| !helper(*entity));
+            let source_vertex =
+                closest_vertex(sources, target_vertex, &targets.meshes, &targets.mesh_query)
+                    .unwrap_or(gizmo_position);
+            target_vertex - source_vertex
+        }
+    };
+
+    apply_world_delta(
+        &root_entities,
+        world_delta,
+        &mut objects,
+        &selection.parents,
+        &global_transforms,
+    );
+
+    if snap_mode == DragSnapMode::Surface && input.gizmo_snap.align_to_surface {
+        let Ok(target_global) = global_transforms.get(*target) else {
+            return;
+        };
+        let up = target_global.rotation() * Vec3::Y;
+        let normal = hit.normal.normalize_or_zero();
+        if normal == Vec3::ZERO {
+            return;
+        }
+        let align = Quat::from_rotation_arc(up, normal);
+        for &entity in &root_entities {
+            let parent_global = selection
+                .parents
+                .get(entity)
+                .ok()
+                .and_then(|parent| global_transforms.get(parent.parent()).ok());
+            if let Ok(mut transform) = objects.get_mut(entity) {
+                rotate_about(&mut transform, parent_global, hit.point, align);
+            }
+        }
+    }
+}
+
+/// Closest world space vertex to point across the meshes of the given entities
+fn closest_vertex(
+    entities: impl Iterator<Item = Entity>,
+    point: Vec3,
+    meshes: &Assets<Mesh>,
+    mesh_query: &Query<(&Mesh3d, &GlobalTransform)>,
+) -> Option<Vec3> {
+    entities
+        .filter_map(|entity| mesh_query.get(entity).ok())
+        .filter_map(|(mesh, global)| Some((meshes.get(&mesh.0)?, global)))
+        .filter_map(|(mesh, global)| {
+            let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?.as_float3()?;
+            Some(
+                positions
+                    .iter()
+                    .map(|position| global.transform_point(Vec3::from_array(*position)))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .flatten()
+        .min_by(|a, b| {
+            a.distance_squared(point)
+                .total_cmp(&b.distance_squared(point))
+        })
+}
@@ -1,7 +1,8 @@
-use crate::gizmos::{NewGizmoType, GizmoType};
+use super::{GizmoAction, GizmoKeybindings};
+use crate::gizmos::{GizmoType, NewGizmoType};
 use bevy::{
     ecs::system::{Res, ResMut},
-    input::keyboard::KeyCode,
+    input::{keyboard::KeyCode, ButtonInput},
 };
 use bevy_granite_core::{InputTypes, UserInput};
 use bevy_granite_logging::{
@@ -9,47 +10,36 @@ use bevy_granite_logging::{
     log,
 };
 
-pub fn watch_gizmo_change(user_input: Res<UserInput>, mut selected_gizmo: ResMut<NewGizmoType>) {
-    // By grabbing the list of inputs, we can ensure only that key is pressed
-    let allow_transform = user_input.current_button_inputs.len() == 1
-        && user_input.current_button_inputs[0] == InputTypes::Button(KeyCode::KeyW)
-        && !user_input.mouse_over_egui;
-
-    let allow_rotate = user_input.current_button_inputs.len() == 1
-        && user_input.current_button_inputs[0] == InputTypes::Button(KeyCode::KeyE)
-        && !user_input.mouse_over_egui;
-
-    let allow_pointer = user_input.current_button_inputs.len() == 1
-        && user_input.current_button_inputs[0] == InputTypes::Button(KeyCode::KeyQ)
-        && !user_input.mouse_over_egui;
-
-    if allow_transform && !matches!(**selected_gizmo, GizmoType::Transform) {
-        **selected_gizmo = GizmoType::Transform;
-        log!(
-            LogType::Editor,
-            LogLevel::Info,
-            LogCategory::Input,
-            "(shortcut) Toggling gizmo to Transform"
-        );
-    }
-
-    if allow_rotate && !matches!(**selected_gizmo, GizmoType::Rotate) {
-        **selected_gizmo = GizmoType::Rotate;
-        log!(
-            LogType::Editor,
-            LogLevel::Info,
-            LogCategory::Input,
-            "(shortcut) Toggling gizmo to Rotate"
-        );
+pub fn watch_gizmo_change(
+    user_input: Res<UserInput>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    keybindings: Res<GizmoKeybindings>,
+    mut selected_gizmo: ResMut<NewGizmoType>,
+) {
+    // Only a lone chord switches the gizmo, not one pressed while dragging or holding other keys
+    let mouse_held = user_input
+        .current_button_inputs
+        .iter()
+        .any(|input| matches!(input, InputTypes::Mouse(_)));
+    if mouse_held || user_input.mouse_over_egui {
+        return;
     }
 
-    if allow_pointer && !matches!(**selected_gizmo, GizmoType::Pointer) {
-        **selected_gizmo = GizmoType::Pointer;
-        log!(
-            LogType::Editor,
-            LogLevel::Info,
-            LogCategory::Input,
-            "(shortcut) Toggling gizmo to Pointer"
-        );
+    for (action, gizmo) in [
+        (GizmoAction::TransformGizmo, GizmoType::Transform),
+        (GizmoAction::RotateGizmo, GizmoType::Rotate),
+        (GizmoAction::ScaleGizmo, GizmoType::Scale),
+        (GizmoAction::PointerGizmo, GizmoType::Pointer),
+    ] {
+        if keybindings.just_pressed_alone(action, &keyboard) && **selected_gizmo != gizmo {
+            **selected_gizmo = gizmo;
+            log!(
+                LogType::Editor,
+                LogLevel::Info,
+                LogCategory::Input,
+                "(shortcut) Toggling gizmo to {:?}",
+                gizmo
+            );
+        }
     }
 }
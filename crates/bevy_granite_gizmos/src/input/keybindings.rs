@@ -0,0 +1,117 @@
+use bevy::{
+    ecs::{
+        resource::Resource,
+        system::{Res, SystemParam},
+    },
+    input::{keyboard::KeyCode, ButtonInput},
+};
+use bevy_granite_core::KeyChord;
+use std::collections::HashMap;
+
+/// Keyboard shortcuts handled by the gizmos
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum GizmoAction {
+    PointerGizmo,
+    TransformGizmo,
+    RotateGizmo,
+    ScaleGizmo,
+    ModalMove,
+    ModalRotate,
+    ModalScale,
+    VertexSnap,
+}
+
+impl GizmoAction {
+    pub const ALL: [GizmoAction; 8] = [
+        GizmoAction::PointerGizmo,
+        GizmoAction::TransformGizmo,
+        GizmoAction::RotateGizmo,
+        GizmoAction::ScaleGizmo,
+        GizmoAction::ModalMove,
+        GizmoAction::ModalRotate,
+        GizmoAction::ModalScale,
+        GizmoAction::VertexSnap,
+    ];
+
+    pub fn default_chord(&self) -> KeyChord {
+        match self {
+            GizmoAction::PointerGizmo => KeyChord::new(KeyCode::KeyQ),
+            GizmoAction::TransformGizmo => KeyChord::new(KeyCode::KeyW),
+            GizmoAction::RotateGizmo => KeyChord::new(KeyCode::KeyE),
+            GizmoAction::ScaleGizmo => KeyChord::new(KeyCode::KeyR),
+            GizmoAction::ModalMove => KeyChord::new(KeyCode::KeyG),
+            GizmoAction::ModalRotate => KeyChord::new(KeyCode::KeyT),
+            GizmoAction::ModalScale => KeyChord::new(KeyCode::KeyS),
+            GizmoAction::VertexSnap => KeyChord::new(KeyCode::KeyV),
+        }
+    }
+}
+
+/// Chords for the gizmo shortcuts
+/// The editor copies its keybinding settings in here, without it the defaults are used
+#[derive(Resource, Clone, PartialEq, Debug)]
+pub struct GizmoKeybindings {
+    pub chords: HashMap<GizmoAction, Vec<KeyChord>>,
+    /// Set while keys are typed somewhere else, like when a new chord is being recorded
+    pub paused: bool,
+}
+
+impl Default for GizmoKeybindings {
+    fn default() -> Self {
+        Self {
+            chords: GizmoAction::ALL
+                .iter()
+                .map(|action| (*action, vec![action.default_chord()]))
+                .collect(),
+            paused: false,
+        }
+    }
+}
+
+impl GizmoKeybindings {
+    pub fn chords(&self, action: GizmoAction) -> &[KeyChord] {
+        self.chords.get(&action).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn just_pressed(&self, action: GizmoAction, keyboard: &ButtonInput<KeyCode>) -> bool {
+        !self.paused
+            && self
+                .chords(action)
+                .iter()
+                .any(|chord| chord.just_pressed(keyboard))
+    }
+
+    /// Like just_pressed, but no other key may be held with the chord
+    pub fn just_pressed_alone(&self, action: GizmoAction, keyboard: &ButtonInput<KeyCode>) -> bool {
+        !self.paused
+            && self
+                .chords(action)
+                .iter()
+                .any(|chord| chord.just_pressed(keyboard) && chord.held_alone(keyboard))
+    }
+
+    pub fn pressed(&self, action: GizmoAction, keyboard: &ButtonInput<KeyCode>) -> bool {
+        !self.paused
+            && self
+                .chords(action)
+                .iter()
+                .any(|chord| chord.pressed(keyboard))
+    }
+}
+
+/// Gizmo keybindings together with the keyboard they are checked against
+#[derive(SystemParam)]
+pub struct GizmoKeys<'w> {
+    pub keybindings: Res<'w, GizmoKeybindings>,
+    pub keyboard: Res<'w, ButtonInput<KeyCode>>,
+}
+
+impl GizmoKeys<'_> {
+    pub fn just_pressed(&self, action: GizmoAction) -> bool {
+        self.keybindings.just_pressed(action, &self.keyboard)
+    }
+
+    pub fn pressed(&self, action: GizmoAction) -> bool {
+        self.keybindings.pressed(action, &self.keyboard)
+    }
+}
@@ -1,7 +1,9 @@
 pub mod change_gizmo;
 pub mod drag;
+pub mod keybindings;
 pub mod plugin;
 
 pub use plugin::InputPlugin;
 pub use drag::{DragState, GizmoAxis};
-pub use change_gizmo::{watch_gizmo_change};
\ No newline at end of file
+pub use change_gizmo::{watch_gizmo_change};
+pub use keybindings::{GizmoAction, GizmoKeybindings, GizmoKeys};
@@ -1,4 +1,4 @@
-use super::{watch_gizmo_change, DragState};
+use super::{watch_gizmo_change, DragState, GizmoKeybindings};
 use crate::{is_gizmos_active, GizmoVisibilityState};
 use bevy::{
     app::{App, Plugin, Update},
@@ -14,6 +14,7 @@ impl Plugin for InputPlugin {
             //
             .insert_resource(GizmoVisibilityState::default())
             .insert_resource(DragState::default())
+            .insert_resource(GizmoKeybindings::default())
             //
             // Schedule system
             //
@@ -14,10 +14,10 @@ mod ui;
 // Re-export
 pub use camera::GizmoCamera;
 pub use gizmos::{
-    despawn_rotate_gizmo, GizmoChildren, GizmoMesh, GizmoSnap, GizmoType, RotateGizmo,
-    NewGizmoConfig, TransformGizmo,
+    despawn_rotate_gizmo, GizmoChildren, GizmoMesh, GizmoPivot, GizmoSnap, GizmoType, RotateGizmo,
+    NewGizmoConfig, PivotMode, ScaleGizmo, TransformGizmo,
 };
-pub use input::{watch_gizmo_change, DragState, GizmoAxis};
+pub use input::{watch_gizmo_change, DragState, GizmoAction, GizmoAxis, GizmoKeybindings};
 pub use selection::{
     ActiveSelection, EntityEvent, RequestDuplicateAllSelectionEvent, RequestDuplicateEntityEvent,
     Selected,
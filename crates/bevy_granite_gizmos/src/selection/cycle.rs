@@ -0,0 +1,105 @@
+use crate::GizmoCamera;
+use bevy::{
+    ecs::{
+        entity::Entity,
+        query::With,
+        resource::Resource,
+        system::{Query, ResMut, SystemParam},
+    },
+    math::Vec2,
+    picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings},
+    render::camera::Camera,
+    transform::components::GlobalTransform,
+};
+use bevy_granite_core::{EditorIgnore, IconProxy};
+
+/// How far in logical pixels a click can land from the last one and still cycle
+const CYCLE_DISTANCE: f32 = 3.0;
+
+/// Everything under the last click, so clicking the same spot again reaches what is behind
+#[derive(Resource, Default)]
+pub struct SelectionCycle {
+    pub position: Vec2,
+    pub hits: Vec<Entity>,
+    pub index: usize,
+}
+
+impl SelectionCycle {
+    /// Entity a click should select, picked is the one picking reported on top
+    pub fn next(&mut self, position: Vec2, hits: Vec<Entity>, picked: Entity) -> Entity {
+        if !hits.is_empty()
+            && self.hits == hits
+            && self.position.distance(position) <= CYCLE_DISTANCE
+        {
+            self.index = (self.index + 1) % self.hits.len();
+        } else {
+            self.index = hits.iter().position(|hit| *hit == picked).unwrap_or(0);
+            self.hits = hits;
+        }
+        self.position = position;
+        self.hits.get(self.index).copied().unwrap_or(picked)
+    }
+
+    pub fn reset(&mut self) {
+        self.hits.clear();
+        self.index = 0;
+    }
+}
+
+/// Alt click opens a list of everything under the cursor to pick from
+#[derive(Resource, Default)]
+pub struct SelectionPopup {
+    /// Where the popup opens, None while closed
+    pub position: Option<Vec2>,
+    pub hits: Vec<Entity>,
+}
+
+/// Every pickable entity under a screen position, nearest first
+#[derive(SystemParam)]
+pub struct CursorHits<'w, 's> {
+    camera: Query<'w, 's, (&'static GlobalTransform, &'static Camera), With<GizmoCamera>>,
+    ray_cast: MeshRayCast<'w, 's>,
+    pub ignored: Query<'w, 's, &'static EditorIgnore>,
+    pub icons: Query<'w, 's, &'static IconProxy>,
+}
+
+impl CursorHits<'_, '_> {
+    pub fn at(&mut self, position: Vec2) -> Vec<Entity> {
+        let Ok((camera_transform, camera)) = self.camera.single() else {
+            return Vec::new();
+        };
+        let Ok(ray) = camera.viewport_to_world(camera_transform, position) else {
+            return Vec::new();
+        };
+        let ignored = &self.ignored;
+        let filter = |entity: Entity| {
+            ignored
+                .get(entity)
+                .map_or(true, |ignore| !ignore.contains(EditorIgnore::PICKING))
+        };
+        let settings = MeshRayCastSettings::default()
+            .with_filter(&filter)
+            .never_early_exit();
+
+        let mut hits = Vec::new();
+        for (entity, _) in self.ray_cast.cast_ray(ray, &settings) {
+            // Icons stand in for their entity, same as clicking them
+            let target = self
+                .icons
+                .get(*entity)
+                .map_or(*entity, |icon_proxy| icon_proxy.target_entity);
+            if !hits.contains(&target) {
+                hits.push(target);
+            }
+        }
+        hits
+    }
+}
+
+/// What a viewport click picks from, and the cycle and pick list state it updates
+#[derive(SystemParam)]
+pub struct SelectionPicking<'w, 's> {
+    pub cursor_hits: CursorHits<'w, 's>,
+    pub cycle: ResMut<'w, SelectionCycle>,
+    pub popup: ResMut<'w, SelectionPopup>,
+}
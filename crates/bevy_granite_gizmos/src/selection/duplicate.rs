@@ -13,7 +13,10 @@ use bevy::{
     prelude::{AppTypeRegistry, ChildOf, Children, EventReader, ReflectComponent, Res, World},
     render::mesh::{Mesh, Mesh3d},
 };
-use bevy_granite_core::{entities::GraniteType, EditorIgnore, HasRuntimeData, IconProxy, IdentityData};
+use bevy_granite_core::{
+    entities::{GraniteType, UserUpdatedRectBrushEvent},
+    EditorIgnore, GraniteTypes, HasRuntimeData, IconProxy, IdentityData,
+};
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
     log,
@@ -117,6 +120,7 @@ fn duplicate_entity_recursive(
     );
 
     log_copied_components(world, new_entity);
+    rebuild_brush_faces(world, new_entity);
 
     for child_entity in entity_info.children {
         duplicate_entity_recursive(world, child_entity, Some(new_entity), registry);
@@ -139,6 +143,22 @@ fn duplicate_entity_recursive(
     Some(new_entity)
 }
 
+/// Brush face meshes are IconProxy children so they aren't copied, have the brush spawn its own
+fn rebuild_brush_faces(world: &mut World, entity: Entity) {
+    let Some(IdentityData {
+        class: GraniteTypes::RectBrush(brush),
+        ..
+    }) = world.get::<IdentityData>(entity)
+    else {
+        return;
+    };
+    if brush.face_material_paths().is_empty() {
+        return;
+    }
+    let data = brush.clone();
+    world.send_event(UserUpdatedRectBrushEvent { entity, data });
+}
+
 struct EntityInfo {
     component_type_ids: Vec<std::any::TypeId>,
     children: Vec<Entity>,
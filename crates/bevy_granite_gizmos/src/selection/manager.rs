@@ -1,13 +1,18 @@
-use crate::selection::{events::EntityEvent, ActiveSelection, Selected};
+use crate::{
+    gizmos::ModalTransformState,
+    selection::{
+        events::EntityEvent, ActiveSelection, MarqueeSelection, Selected, SelectionPicking,
+    },
+};
 use bevy::{
     ecs::{observer::Trigger, world::OnAdd},
-    prelude::{Component, Entity, Query, Res, With},
+    prelude::{Component, Entity, Query, Res, ResMut, With},
 };
 use bevy::{
     ecs::{query::QueryEntityError, system::Commands},
     picking::events::{Click, Pointer},
 };
-use bevy_granite_core::{EditorIgnore, IconProxy, UserInput};
+use bevy_granite_core::{EditorIgnore, UserInput};
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
     log,
@@ -125,14 +130,31 @@ pub fn single_active(
 pub fn handle_picking_selection(
     mut on_click: Trigger<Pointer<Click>>,
     mut commands: Commands,
-    ignored: Query<&EditorIgnore>,
-    icon_proxy_query: Query<&IconProxy>,
     user_input: Res<UserInput>,
+    mut modal: ResMut<ModalTransformState>,
+    marquee: Res<MarqueeSelection>,
+    picking: SelectionPicking,
 ) {
+    let SelectionPicking {
+        mut cursor_hits,
+        mut cycle,
+        mut popup,
+    } = picking;
     if on_click.button != bevy::picking::pointer::PointerButton::Primary {
         return;
     }
-    match ignored.get(on_click.target()) {
+    // This click confirmed a modal transform
+    if modal.is_active() || modal.swallow_click {
+        modal.swallow_click = false;
+        on_click.propagate(false);
+        return;
+    }
+    // The release of a box selection, finish_marquee_selection handles it
+    if marquee.is_dragging() {
+        on_click.propagate(false);
+        return;
+    }
+    match cursor_hits.ignored.get(on_click.target()) {
         Ok(to_ignore) => {
             if to_ignore.contains(EditorIgnore::PICKING) {
                 return;
@@ -147,6 +169,18 @@ pub fn handle_picking_selection(
     if user_input.mouse_over_egui {
         return;
     }
+
+    let position = on_click.pointer_location.position;
+    // Alt click lists everything under the cursor instead of taking the top one
+    if user_input.alt_left.any {
+        on_click.propagate(false);
+        let hits = cursor_hits.at(position);
+        if !hits.is_empty() {
+            popup.position = Some(position);
+            popup.hits = hits;
+        }
+        return;
+    }
     
     if on_click.target().index() == 0 {
         log!(
@@ -156,6 +190,7 @@ pub fn handle_picking_selection(
             "Clicked on empty space, deselecting all entities"
         );
         on_click.propagate(false);
+        cycle.reset();
         commands.trigger(EntityEvent::DeselectAll);
         return;
     }
@@ -164,7 +199,7 @@ pub fn handle_picking_selection(
     let mut entity = on_click.target();
 
     // redirect to icon target
-    if let Ok(icon_proxy) = icon_proxy_query.get(entity) {
+    if let Ok(icon_proxy) = cursor_hits.icons.get(entity) {
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -175,8 +210,16 @@ pub fn handle_picking_selection(
         entity = icon_proxy.target_entity;
     }
 
+    let additive = user_input.shift_left.any;
+    if additive {
+        cycle.reset();
+    } else {
+        // Clicking the same spot again steps to the next entity behind
+        entity = cycle.next(position, cursor_hits.at(position), entity);
+    }
+
     commands.trigger(EntityEvent::Select {
         target: entity,
-        additive: user_input.shift_left.any,
+        additive,
     });
 }
@@ -0,0 +1,272 @@
+use crate::{
+    gizmos::{GizmoMesh, ModalTransformState},
+    selection::{ActiveSelection, Selected},
+    GizmoCamera,
+};
+use bevy::{
+    ecs::{
+        entity::Entity,
+        observer::Trigger,
+        query::{With, Without},
+        resource::Resource,
+        system::{Commands, Query, Res, ResMut, SystemParam},
+    },
+    math::{Rect, Vec2, Vec3},
+    picking::{
+        events::{Drag, DragEnd, DragStart, Pointer},
+        pointer::PointerButton,
+    },
+    render::{camera::Camera, primitives::Aabb, view::ViewVisibility},
+    transform::components::GlobalTransform,
+};
+use bevy_granite_core::{EditorIgnore, IconProxy, TreeHiddenEntity, UserInput};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+
+/// Drags shorter than this in logical pixels are left to click selection
+const MARQUEE_THRESHOLD: f32 = 4.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MarqueeMode {
+    /// Selection becomes what is inside the box
+    Replace,
+    /// Shift, box is added to the selection
+    Add,
+    /// Ctrl, box is taken out of the selection
+    Subtract,
+}
+
+impl MarqueeMode {
+    pub fn from_input(user_input: &UserInput) -> Self {
+        if user_input.ctrl_left.any {
+            MarqueeMode::Subtract
+        } else if user_input.shift_left.any {
+            MarqueeMode::Add
+        } else {
+            MarqueeMode::Replace
+        }
+    }
+}
+
+/// Box being dragged out in the viewport, in logical window pixels
+#[derive(Resource, Default)]
+pub struct MarqueeSelection {
+    pub start: Option<Vec2>,
+    pub current: Vec2,
+}
+
+impl MarqueeSelection {
+    /// True once the drag is far enough to be a box rather than a click
+    pub fn is_dragging(&self) -> bool {
+        self.start
+            .is_some_and(|start| start.distance(self.current) > MARQUEE_THRESHOLD)
+    }
+
+    pub fn rect(&self) -> Option<Rect> {
+        self.start
+            .filter(|_| self.is_dragging())
+            .map(|start| Rect::from_corners(start, self.current))
+    }
+}
+
+/// Primary drags that don't start on a gizmo or editor handle draw a selection box
+pub fn start_marquee_selection(
+    event: Trigger<Pointer<DragStart>>,
+    handles: Query<(), With<GizmoMesh>>,
+    tree_hidden: Query<(), (With<TreeHiddenEntity>, Without<IconProxy>)>,
+    user_input: Res<UserInput>,
+    modal: Res<ModalTransformState>,
+    mut marquee: ResMut<MarqueeSelection>,
+) {
+    // Pointer events bubble up the hierarchy, only the first one counts
+    if event.button != PointerButton::Primary || marquee.start.is_some() {
+        return;
+    }
+    if user_input.mouse_over_egui
+        || modal.is_active()
+        || handles.contains(event.target)
+        || tree_hidden.contains(event.target)
+    {
+        return;
+    }
+    let position = event.pointer_location.position;
+    marquee.start = Some(position);
+    marquee.current = position;
+}
+
+pub fn update_marquee_selection(
+    event: Trigger<Pointer<Drag>>,
+    mut marquee: ResMut<MarqueeSelection>,
+) {
+    if event.button != PointerButton::Primary || marquee.start.is_none() {
+        return;
+    }
+    marquee.current = event.pointer_location.position;
+}
+
+/// Entities a marquee box can pick, seen through the gizmo camera
+#[derive(SystemParam)]
+pub struct MarqueeTargets<'w, 's> {
+    pub camera_query: Query<'w, 's, (&'static GlobalTransform, &'static Camera), With<GizmoCamera>>,
+    pub candidates: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static GlobalTransform,
+            &'static Aabb,
+            &'static ViewVisibility,
+        ),
+    >,
+    pub handles: Query<'w, 's, (), With<GizmoMesh>>,
+    pub icon_proxies: Query<'w, 's, &'static IconProxy>,
+    pub tree_hidden: Query<'w, 's, (), With<TreeHiddenEntity>>,
+    pub ignored: Query<'w, 's, &'static EditorIgnore>,
+}
+
+/// Selects everything whose screen bounds touch the box
+/// Goes through the Selected and ActiveSelection markers, so the node tree picks it up like any other selection
+pub fn finish_marquee_selection(
+    event: Trigger<Pointer<DragEnd>>,
+    mut commands: Commands,
+    mut marquee: ResMut<MarqueeSelection>,
+    targets: MarqueeTargets,
+    current: Query<Entity, With<Selected>>,
+    active_selection: Query<Entity, With<ActiveSelection>>,
+    user_input: Res<UserInput>,
+) {
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    let MarqueeTargets {
+        camera_query,
+        candidates,
+        handles,
+        icon_proxies,
+        tree_hidden,
+        ignored,
+    } = targets;
+    let rect = marquee.rect();
+    marquee.start = None;
+    let Some(rect) = rect else {
+        return;
+    };
+    let Ok((camera_transform, camera)) = camera_query.single() else {
+        log!(
+            LogType::Editor,
+            LogLevel::Error,
+            LogCategory::Input,
+            "Gizmo camera not found for marquee selection"
+        );
+        return;
+    };
+
+    let picking_ignored = |entity: Entity| {
+        ignored
+            .get(entity)
+            .is_ok_and(|ignore| ignore.contains(EditorIgnore::PICKING))
+    };
+
+    let mut hits: Vec<(Entity, f32)> = Vec::new();
+    for (entity, global, aabb, visibility) in candidates.iter() {
+        if !visibility.get() || handles.contains(entity) || picking_ignored(entity) {
+            continue;
+        }
+        // Icons stand in for entities without a mesh, like lights and cameras
+        let target = match icon_proxies.get(entity) {
+            Ok(icon_proxy) => icon_proxy.target_entity,
+            Err(_) => entity,
+        };
+        if tree_hidden.contains(target) || picking_ignored(target) {
+            continue;
+        }
+        let Some(bounds) = screen_bounds(aabb, global, camera, camera_transform) else {
+            continue;
+        };
+        if rect.intersect(bounds).is_empty() {
+            continue;
+        }
+        let depth = camera_transform
+            .translation()
+            .distance_squared(global.translation());
+        match hits.iter_mut().find(|(hit, _)| *hit == target) {
+            Some((_, hit_depth)) => *hit_depth = hit_depth.min(depth),
+            None => hits.push((target, depth)),
+        }
+    }
+    // Nearest first, so it becomes the active selection
+    hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let hits: Vec<Entity> = hits.into_iter().map(|(entity, _)| entity).collect();
+
+    let mode = MarqueeMode::from_input(&user_input);
+    log!(
+        LogType::Editor,
+        LogLevel::Info,
+        LogCategory::Input,
+        "Marquee {:?} selection: {} entities",
+        mode,
+        hits.len()
+    );
+
+    let active = active_selection.single().ok();
+    match mode {
+        MarqueeMode::Replace => {
+            for entity in current.iter().filter(|entity| !hits.contains(entity)) {
+                commands
+                    .entity(entity)
+                    .remove::<(ActiveSelection, Selected)>();
+            }
+            select_hits(&mut commands, &hits, active.filter(|a| hits.contains(a)));
+        }
+        MarqueeMode::Add => select_hits(&mut commands, &hits, active),
+        MarqueeMode::Subtract => {
+            for entity in hits.iter().filter(|entity| current.contains(**entity)) {
+                commands
+                    .entity(*entity)
+                    .remove::<(ActiveSelection, Selected)>();
+            }
+        }
+    }
+}
+
+/// Marks hits as selected, the nearest hit becomes active if nothing else is
+fn select_hits(commands: &mut Commands, hits: &[Entity], active: Option<Entity>) {
+    for entity in hits {
+        commands.entity(*entity).insert(Selected);
+    }
+    if active.is_none() {
+        if let Some(first) = hits.first() {
+            commands.entity(*first).insert(ActiveSelection);
+        }
+    }
+}
+
+/// Screen space rectangle around an entity's bounding box, None if it is entirely behind the camera
+fn screen_bounds(
+    aabb: &Aabb,
+    global: &GlobalTransform,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+) -> Option<Rect> {
+    let center = Vec3::from(aabb.center);
+    let half = Vec3::from(aabb.half_extents);
+    let mut bounds: Option<Rect> = None;
+    for corner in 0..8 {
+        let sign = Vec3::new(
+            if corner & 1 == 0 { -1. } else { 1. },
+            if corner & 2 == 0 { -1. } else { 1. },
+            if corner & 4 == 0 { -1. } else { 1. },
+        );
+        let world = global.transform_point(center + half * sign);
+        let Ok(point) = camera.world_to_viewport(camera_transform, world) else {
+            continue;
+        };
+        bounds = Some(match bounds {
+            Some(bounds) => bounds.union_point(point),
+            None => Rect::from_center_size(point, Vec2::ZERO),
+        });
+    }
+    bounds
+}
@@ -4,9 +4,11 @@ use bevy::ecs::{
     world::DeferredWorld,
 };
 
+pub mod cycle;
 pub mod duplicate;
 pub mod events;
 pub mod manager;
+pub mod marquee;
 pub mod plugin;
 pub mod ray;
 
@@ -42,9 +44,14 @@ impl ActiveSelection {
 #[derive(Component, Default)]
 pub struct Selected;
 
+pub use cycle::{CursorHits, SelectionCycle, SelectionPicking, SelectionPopup};
 pub use duplicate::{duplicate_all_selection_system, duplicate_entity_system};
 pub use events::{EntityEvent, RequestDuplicateAllSelectionEvent, RequestDuplicateEntityEvent};
 pub use manager::{apply_pending_parents, handle_picking_selection, select_entity};
+pub use marquee::{
+    finish_marquee_selection, start_marquee_selection, update_marquee_selection, MarqueeMode,
+    MarqueeSelection,
+};
 pub use plugin::SelectionPlugin;
 pub use ray::{RaycastCursorLast, RaycastCursorPos};
 
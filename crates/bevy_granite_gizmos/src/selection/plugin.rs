@@ -1,7 +1,8 @@
 use super::{
     apply_pending_parents, duplicate_all_selection_system, duplicate_entity_system,
-    handle_picking_selection, select_entity, RaycastCursorLast, RaycastCursorPos,
-    RequestDuplicateAllSelectionEvent, RequestDuplicateEntityEvent,
+    finish_marquee_selection, handle_picking_selection, select_entity, start_marquee_selection,
+    update_marquee_selection, MarqueeSelection, RaycastCursorLast, RaycastCursorPos,
+    RequestDuplicateAllSelectionEvent, RequestDuplicateEntityEvent, SelectionCycle, SelectionPopup,
 };
 use crate::{is_gizmos_active, selection::manager::deselect_entity};
 use bevy::{
@@ -28,6 +29,9 @@ impl Plugin for SelectionPlugin {
             .insert_resource(RaycastCursorPos {
                 position: Vec3::ZERO,
             })
+            .insert_resource(MarqueeSelection::default())
+            .insert_resource(SelectionCycle::default())
+            .insert_resource(SelectionPopup::default())
             //
             // Events
             //
@@ -46,6 +50,9 @@ impl Plugin for SelectionPlugin {
             .add_observer(handle_picking_selection)
             .add_observer(super::manager::single_active)
             .add_observer(select_entity)
-            .add_observer(deselect_entity);
+            .add_observer(deselect_entity)
+            .add_observer(start_marquee_selection)
+            .add_observer(update_marquee_selection)
+            .add_observer(finish_marquee_selection);
     }
 }
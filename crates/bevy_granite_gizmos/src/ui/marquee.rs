@@ -0,0 +1,29 @@
+use bevy::ecs::system::Res;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::selection::MarqueeSelection;
+
+pub fn marquee_selection_ui(mut contexts: EguiContexts, marquee: Res<MarqueeSelection>) {
+    let Some(rect) = marquee.rect() else {
+        return;
+    };
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let rect = egui::Rect::from_min_max(
+        egui::pos2(rect.min.x, rect.min.y),
+        egui::pos2(rect.max.x, rect.max.y),
+    );
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Foreground,
+        egui::Id::new("marquee_selection"),
+    ));
+    let color = egui::Color32::from_rgb(255, 150, 40);
+    painter.rect_filled(rect, 0.0, color.gamma_multiply(0.1));
+    painter.rect_stroke(
+        rect,
+        0.0,
+        egui::Stroke::new(1.0, color),
+        egui::StrokeKind::Inside,
+    );
+}
@@ -1,5 +1,9 @@
+pub mod marquee;
 pub mod panel;
 pub mod plugin;
+pub mod popup;
 
 pub use plugin::UIPlugin;
-pub use panel::editor_gizmos_ui;
\ No newline at end of file
+pub use marquee::marquee_selection_ui;
+pub use panel::editor_gizmos_ui;
+pub use popup::selection_popup_ui;
\ No newline at end of file
@@ -1,23 +1,54 @@
 use bevy::{
-    ecs::{query::With, system::Query},
-    prelude::ResMut,
+    ecs::{
+        query::With,
+        system::{Query, SystemParam},
+    },
+    prelude::{Res, ResMut},
+    transform::components::GlobalTransform,
 };
 use bevy_egui::{egui, EguiContexts};
 use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
 
 use crate::{
-    gizmos::{GizmoConfig, GizmoMode, GizmoSnap, GizmoType, Gizmos, NewGizmoConfig, NewGizmoType},
+    gizmos::{
+        GizmoConfig, GizmoMode, GizmoPivot, GizmoSnap, GizmoType, Gizmos, NewGizmoConfig,
+        NewGizmoType, PivotMode,
+    },
+    input::{GizmoAction, GizmoKeybindings},
     ActiveSelection,
 };
 
+/// Gizmo settings the panel edits, they apply to every new gizmo
+#[derive(SystemParam)]
+pub struct GizmoPanelSettings<'w> {
+    pub gizmo_snap: ResMut<'w, GizmoSnap>,
+    pub config: ResMut<'w, NewGizmoConfig>,
+    pub pivot: ResMut<'w, GizmoPivot>,
+}
+
 pub fn editor_gizmos_ui(
     mut contexts: EguiContexts,
     mut selected_option: ResMut<NewGizmoType>,
-    mut gizmo_snap: ResMut<GizmoSnap>,
-    mut config: ResMut<NewGizmoConfig>,
-    selected_entity: Query<&Gizmos, With<ActiveSelection>>,
+    settings: GizmoPanelSettings,
+    active_selection: Query<(Option<&Gizmos>, &GlobalTransform), With<ActiveSelection>>,
     mut gizmos: Query<&mut GizmoConfig>,
+    keybindings: Res<GizmoKeybindings>,
 ) {
+    let GizmoPanelSettings {
+        mut gizmo_snap,
+        mut config,
+        mut pivot,
+    } = settings;
+    let vertex_snap_keys: Vec<String> = keybindings
+        .chords(GizmoAction::VertexSnap)
+        .iter()
+        .map(|chord| chord.to_string())
+        .collect();
+    let vertex_snap_hint = if vertex_snap_keys.is_empty() {
+        String::new()
+    } else {
+        format!(", {} to snap to vertices", vertex_snap_keys.join(" / "))
+    };
     let small_spacing = 1.;
     let spacing = 4.;
     egui::Window::new("Gizmos")
@@ -32,7 +63,7 @@ pub fn editor_gizmos_ui(
                 let mut local = None;
                 let mut changed = false;
                 ui.vertical(|ui| {
-                    if let Ok(selected) = selected_entity.single() {
+                    if let Ok((Some(selected), _)) = active_selection.single() {
                         for entity in selected.entities() {
                             if let Ok(local_config) = gizmos.get(*entity) {
                                 active = local_config.gizmo_type();
@@ -52,6 +83,9 @@ pub fn editor_gizmos_ui(
                     changed |= ui
                         .radio_value(&mut active, GizmoType::Rotate, "Rotate")
                         .changed();
+                    changed |= ui
+                        .radio_value(&mut active, GizmoType::Scale, "Scale")
+                        .changed();
 
                     if matches!(active, GizmoType::Transform) {
                         ui.add_space(spacing);
@@ -64,20 +98,11 @@ pub fn editor_gizmos_ui(
                                     .range(0.0..=360.0),
                             )
                             .changed();
-                        ui.add_space(spacing);
-                        egui::ComboBox::new("GizmoMode", "")
-                            .selected_text(match mode {
-                                GizmoMode::Local => "Local",
-                                GizmoMode::Global => "Global",
-                            })
-                            .show_ui(ui, |ui| {
-                                changed |= ui
-                                    .selectable_value(&mut mode, GizmoMode::Local, "Local")
-                                    .changed();
-                                changed |= ui
-                                    .selectable_value(&mut mode, GizmoMode::Global, "Global")
-                                    .changed();
-                            });
+                        ui.checkbox(&mut gizmo_snap.align_to_surface, "Align")
+                            .on_hover_text(format!(
+                                "Hold Alt to snap to surfaces{}.\nAlign turns the selection up onto the surface",
+                                vertex_snap_hint
+                            ));
                     }
 
                     if matches!(active, GizmoType::Rotate) {
@@ -91,21 +116,85 @@ pub fn editor_gizmos_ui(
                                     .range(0.0..=360.0),
                             )
                             .changed();
+                    }
 
+                    if matches!(active, GizmoType::Scale) {
+                        ui.add_space(spacing);
+                        ui.label("Snap:");
+                        ui.add_space(small_spacing);
+                        changed |= ui
+                            .add(
+                                egui::DragValue::new(&mut gizmo_snap.scale_value)
+                                    .speed(0.01)
+                                    .range(0.0..=10.0),
+                            )
+                            .on_hover_text("Scale factor increment, 0 is off")
+                            .changed();
+                    }
+
+                    if matches!(
+                        active,
+                        GizmoType::Transform | GizmoType::Rotate | GizmoType::Scale
+                    ) {
                         ui.add_space(spacing);
                         egui::ComboBox::new("GizmoMode", "")
-                            .selected_text(match mode {
-                                GizmoMode::Local => "Local",
-                                GizmoMode::Global => "Global",
-                            })
+                            .selected_text(mode.name())
+                            .show_ui(ui, |ui| {
+                                for gizmo_mode in GizmoMode::all() {
+                                    changed |= ui
+                                        .selectable_value(
+                                            &mut mode,
+                                            gizmo_mode,
+                                            gizmo_mode.name(),
+                                        )
+                                        .changed();
+                                }
+                            });
+
+                        if mode == GizmoMode::Custom {
+                            ui.add_space(small_spacing);
+                            let active_rotation = active_selection
+                                .single()
+                                .ok()
+                                .map(|(_, global)| global.rotation());
+                            if ui
+                                .add_enabled(active_rotation.is_some(), egui::Button::new("Set"))
+                                .on_hover_text("Capture the active entity's rotation")
+                                .clicked()
+                            {
+                                if let Some(rotation) = active_rotation {
+                                    config.custom_orientation = rotation;
+                                }
+                            }
+                        }
+
+                        ui.add_space(spacing);
+                        ui.label("Pivot:");
+                        ui.add_space(small_spacing);
+                        egui::ComboBox::new("GizmoPivot", "")
+                            .selected_text(pivot.mode.name())
                             .show_ui(ui, |ui| {
-                                changed |= ui
-                                    .selectable_value(&mut mode, GizmoMode::Local, "Local")
-                                    .changed();
-                                changed |= ui
-                                    .selectable_value(&mut mode, GizmoMode::Global, "Global")
-                                    .changed();
+                                for pivot_mode in PivotMode::all() {
+                                    ui.selectable_value(
+                                        &mut pivot.mode,
+                                        pivot_mode,
+                                        pivot_mode.name(),
+                                    );
+                                }
                             });
+
+                        if pivot.mode == PivotMode::Cursor {
+                            ui.add_space(small_spacing);
+                            let cursor = &mut pivot.cursor;
+                            for (prefix, value) in [
+                                ("X ", &mut cursor.x),
+                                ("Y ", &mut cursor.y),
+                                ("Z ", &mut cursor.z),
+                            ] {
+                                ui.add(egui::DragValue::new(value).prefix(prefix).speed(0.05))
+                                    .on_hover_text("Shift + right click a surface to place");
+                            }
+                        }
                     }
                 });
                 if changed {
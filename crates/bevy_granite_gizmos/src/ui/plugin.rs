@@ -1,4 +1,4 @@
-use super::editor_gizmos_ui;
+use super::{editor_gizmos_ui, marquee_selection_ui, selection_popup_ui};
 use crate::is_gizmos_active;
 use bevy::{
     app::{App, Plugin},
@@ -15,7 +15,8 @@ impl Plugin for UIPlugin {
             //
             .add_systems(
                 EguiPrimaryContextPass,
-                (editor_gizmos_ui).run_if(is_gizmos_active),
+                (editor_gizmos_ui, marquee_selection_ui, selection_popup_ui)
+                    .run_if(is_gizmos_active),
             );
     }
 }
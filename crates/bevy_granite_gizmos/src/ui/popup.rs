@@ -0,0 +1,77 @@
+use bevy::{
+    ecs::{
+        entity::Entity,
+        system::{Commands, Query, Res, ResMut},
+    },
+    prelude::Name,
+};
+use bevy_egui::{egui, EguiContexts};
+use bevy_granite_core::{entities::GraniteType, IdentityData, UserInput};
+
+use crate::selection::{EntityEvent, SelectionPopup};
+
+/// Alt click list of everything under the cursor, nearest first
+pub fn selection_popup_ui(
+    mut contexts: EguiContexts,
+    mut commands: Commands,
+    mut popup: ResMut<SelectionPopup>,
+    names: Query<(Option<&Name>, Option<&IdentityData>)>,
+    user_input: Res<UserInput>,
+) {
+    let Some(position) = popup.position else {
+        return;
+    };
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let entries: Vec<(Entity, String, String)> = popup
+        .hits
+        .iter()
+        .filter_map(|entity| {
+            let (name, identity) = names.get(*entity).ok()?;
+            let name = match (name, identity) {
+                (Some(name), _) => name.to_string(),
+                (None, Some(identity)) => identity.name.clone(),
+                (None, None) => format!("Entity {}", entity.index()),
+            };
+            let class =
+                identity.map_or("Entity".to_string(), |identity| identity.class.type_name());
+            Some((*entity, name, class))
+        })
+        .collect();
+
+    let mut chosen = None;
+    let response = egui::Area::new(egui::Id::new("selection_popup"))
+        .order(egui::Order::Foreground)
+        // Opened just under the cursor, so the click that opened it isn't a click elsewhere
+        .fixed_pos(egui::pos2(position.x - 4., position.y - 4.))
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_min_width(160.);
+                for (entity, name, class) in &entries {
+                    ui.horizontal(|ui| {
+                        if ui.selectable_label(false, name.as_str()).clicked() {
+                            chosen = Some(*entity);
+                        }
+                        ui.weak(class.as_str());
+                    });
+                }
+            });
+        })
+        .response;
+
+    let cancelled = entries.is_empty()
+        || ctx.input(|input| input.key_pressed(egui::Key::Escape))
+        || response.clicked_elsewhere();
+    if let Some(entity) = chosen {
+        commands.trigger(EntityEvent::Select {
+            target: entity,
+            additive: user_input.shift_left.any,
+        });
+    }
+    if chosen.is_some() || cancelled {
+        popup.position = None;
+        popup.hits.clear();
+    }
+}
@@ -163,4 +163,10 @@ pub mod prelude {
 
     #[cfg(feature = "editor")]
     pub use crate::bevy_granite_editor::interface::tabs::events::ui::register_ui_callable_events_with_senders;
+
+    #[cfg(feature = "editor")]
+    pub use crate::bevy_granite_editor::palette::{PaletteCommand, RegisterPaletteCommand};
+
+    #[cfg(feature = "editor")]
+    pub use crate::bevy_granite_editor::play::{PlayMode, PlayState, RequestPlayEvent};
 }